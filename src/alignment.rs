@@ -0,0 +1,114 @@
+use super::StrError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Defines the horizontal alignment of text
+///
+/// Setters accepting the horizontal alignment (e.g., [crate::Text::set_align_horizontal]) take an `HAlign`;
+/// hence, invalid options are caught at compile time. The strings "center", "left", and "right" may be
+/// converted by `str::parse`, which returns an error for invalid strings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HAlign {
+    /// Centers the text horizontally at the anchor point ("center")
+    Center,
+
+    /// Places the left edge of the text at the anchor point ("left")
+    Left,
+
+    /// Places the right edge of the text at the anchor point ("right")
+    Right,
+}
+
+/// Defines the vertical alignment of text
+///
+/// Setters accepting the vertical alignment (e.g., [crate::Text::set_align_vertical]) take a `VAlign`;
+/// hence, invalid options are caught at compile time. The strings "center", "top", "bottom", "baseline",
+/// and "center_baseline" may be converted by `str::parse`, which returns an error for invalid strings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VAlign {
+    /// Centers the bounding box of the text vertically at the anchor point ("center")
+    Center,
+
+    /// Places the top edge of the text at the anchor point ("top")
+    Top,
+
+    /// Places the bottom edge of the text (including descenders) at the anchor point ("bottom")
+    Bottom,
+
+    /// Places the baseline of the first line of text at the anchor point ("baseline")
+    Baseline,
+
+    /// Centers the text between the baselines of its first and last lines ("center_baseline")
+    CenterBaseline,
+}
+
+impl fmt::Display for HAlign {
+    /// Writes the option passed to Matplotlib (without quotes)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let option = match self {
+            HAlign::Center => "center",
+            HAlign::Left => "left",
+            HAlign::Right => "right",
+        };
+        write!(f, "{}", option)
+    }
+}
+
+impl fmt::Display for VAlign {
+    /// Writes the option passed to Matplotlib (without quotes)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let option = match self {
+            VAlign::Center => "center",
+            VAlign::Top => "top",
+            VAlign::Bottom => "bottom",
+            VAlign::Baseline => "baseline",
+            VAlign::CenterBaseline => "center_baseline",
+        };
+        write!(f, "{}", option)
+    }
+}
+
+impl FromStr for HAlign {
+    type Err = StrError;
+    fn from_str(option: &str) -> Result<Self, Self::Err> {
+        match option {
+            "center" => Ok(HAlign::Center),
+            "left" => Ok(HAlign::Left),
+            "right" => Ok(HAlign::Right),
+            _ => Err("invalid horizontal alignment"),
+        }
+    }
+}
+
+impl FromStr for VAlign {
+    type Err = StrError;
+    fn from_str(option: &str) -> Result<Self, Self::Err> {
+        match option {
+            "center" => Ok(VAlign::Center),
+            "top" => Ok(VAlign::Top),
+            "bottom" => Ok(VAlign::Bottom),
+            "baseline" => Ok(VAlign::Baseline),
+            "center_baseline" => Ok(VAlign::CenterBaseline),
+            _ => Err("invalid vertical alignment"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{HAlign, VAlign};
+
+    #[test]
+    fn display_and_from_str_work() {
+        for option in ["center", "left", "right"] {
+            assert_eq!(option.parse::<HAlign>().unwrap().to_string(), option);
+        }
+        for option in ["center", "top", "bottom", "baseline", "center_baseline"] {
+            assert_eq!(option.parse::<VAlign>().unwrap().to_string(), option);
+        }
+        assert_eq!("top".parse::<HAlign>().err(), Some("invalid horizontal alignment"));
+        assert_eq!("left".parse::<VAlign>().err(), Some("invalid vertical alignment"));
+    }
+}
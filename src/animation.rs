@@ -0,0 +1,458 @@
+use super::{call_python3, write_log_file, GraphMaker, Plot, SaveOptions, StrError, TargetVersions, Theme, Typography};
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Generates animations (animated GIF or numbered PNG files) from a sequence of frames
+///
+/// Each frame is drawn on a cleared figure by the commands of a set of GraphMakers (see [Animation::add_frame]),
+/// a [Plot] (see [Animation::add_frame_plot]), or a per-frame update closure (see [Animation::add_frames]).
+///
+/// The animation is saved by [Animation::save] according to the extension of the path:
+///
+/// * `.gif` -- an animated GIF file is written using Matplotlib's `PillowWriter` (requires Pillow)
+/// * `.png` -- numbered PNG files are written by appending `_0000`, `_0001`, ... to the filename
+///
+/// As in [Plot::save], the Python script is written with the `.py` extension and, if an error occurs,
+/// a log file with the `.log` extension is written to the same directory.
+///
+/// **Note:** The figure-wide options of the frame plots (e.g., theme and typography) are ignored; use
+/// [Animation::set_theme], [Animation::set_typography], and [Animation::set_save_options] instead.
+/// Also, the figure size should not be changed by the frames.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Animation, Curve, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // data
+///     let x: Vec<f64> = (0..41).map(|i| i as f64 / 40.0).collect();
+///
+///     // animation with one frame per time step
+///     let mut animation = Animation::new();
+///     animation.set_fps(10.0).set_loop(true);
+///     animation.add_frames(20, |step, plot| {
+///         let t = step as f64 / 20.0;
+///         let y: Vec<f64> = x.iter().map(|x| f64::sin(2.0 * std::f64::consts::PI * (x - t))).collect();
+///         let mut curve = Curve::new();
+///         curve.draw(&x, &y);
+///         plot.add(&curve).set_range(0.0, 1.0, -1.1, 1.1).set_title(&format!("t = {:.2}", t));
+///         Ok(())
+///     })?;
+///
+///     // save animated GIF
+///     animation.save("/tmp/plotpy/doc_tests/doc_animation.gif")?;
+///     Ok(())
+/// }
+/// ```
+pub struct Animation {
+    fps: f64,            // Frames per second
+    loop_forever: bool,  // Repeat the GIF animation forever
+    dpi: f64,            // Resolution of the frames (0 means the dpi of the save options or 100)
+    width: f64,          // Figure width in inches (0 means default)
+    height: f64,         // Figure height in inches (0 means default)
+    show_errors: bool,   // Show python errors, if any
+    figure: Plot,        // Figure-wide options (e.g., theme), extra imports, and first error (no commands)
+    frames: Vec<String>, // Commands of each frame
+}
+
+impl Animation {
+    /// Creates a new Animation object
+    pub fn new() -> Self {
+        let mut figure = Plot::new();
+        figure.set_save_options(SaveOptions::new().set_tight_bbox(false));
+        Animation {
+            fps: 10.0,
+            loop_forever: true,
+            dpi: 0.0,
+            width: 0.0,
+            height: 0.0,
+            show_errors: false,
+            figure,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Adds a frame drawn by a set of GraphMakers (e.g., Curve, Canvas, Text)
+    pub fn add_frame(&mut self, graphs: &[&dyn GraphMaker]) -> &mut Self {
+        let mut buffer = String::new();
+        for graph in graphs {
+            self.figure.merge_imports(graph.get_imports());
+            self.figure.record_error(graph.get_error());
+            buffer.push_str(graph.get_buffer());
+        }
+        self.frames.push(buffer);
+        self
+    }
+
+    /// Adds a frame drawn by the commands of a Plot (e.g., with title, labels, and range)
+    pub fn add_frame_plot(&mut self, plot: &Plot) -> &mut Self {
+        let (imports, buffer) = plot.get_frame();
+        self.figure.merge_imports(imports);
+        self.figure.record_error(plot.get_error());
+        self.frames.push(buffer.to_string());
+        self
+    }
+
+    /// Adds `count` frames drawn by a closure
+    ///
+    /// The closure is called with the index of the frame and a new (empty) Plot for each frame.
+    /// Any error returned by the closure is forwarded.
+    pub fn add_frames<F>(&mut self, count: usize, mut update: F) -> Result<&mut Self, StrError>
+    where
+        F: FnMut(usize, &mut Plot) -> Result<(), StrError>,
+    {
+        for index in 0..count {
+            let mut plot = Plot::new();
+            update(index, &mut plot)?;
+            self.add_frame_plot(&plot);
+        }
+        Ok(self)
+    }
+
+    /// Returns the number of frames
+    pub fn get_frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Sets the number of frames per second (must be positive)
+    pub fn set_fps(&mut self, fps: f64) -> &mut Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Sets the option to repeat the GIF animation forever (otherwise, it is played once)
+    pub fn set_loop(&mut self, flag: bool) -> &mut Self {
+        self.loop_forever = flag;
+        self
+    }
+
+    /// Sets the resolution of the frames in dots per inch
+    ///
+    /// If not set, the resolution of the save options (see [Animation::set_save_options]) or 100 is used.
+    pub fn set_dpi(&mut self, dpi: f64) -> &mut Self {
+        self.dpi = dpi;
+        self
+    }
+
+    /// Sets the figure size in inches (the same for all frames)
+    pub fn set_figure_size_inches(&mut self, width: f64, height: f64) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Sets the option to show python errors, if any
+    pub fn set_show_errors(&mut self, option: bool) -> &mut Self {
+        self.show_errors = option;
+        self
+    }
+
+    /// Sets the theme applied before all frames. See [Theme]
+    pub fn set_theme(&mut self, theme: &Theme) -> &mut Self {
+        self.figure.set_theme(theme);
+        self
+    }
+
+    /// Sets the typography applied before all frames (after the theme). See [Typography]
+    pub fn set_typography(&mut self, typography: &Typography) -> &mut Self {
+        self.figure.set_typography(typography);
+        self
+    }
+
+    /// Sets the options to save the frames. See [SaveOptions]
+    ///
+    /// The numbered PNG files are saved with all options. The frames of GIF files use only the
+    /// resolution and the background options (transparency and face color) because all frames
+    /// must have the same size.
+    ///
+    /// **Note:** Without save options, the tight bounding box is not used because the size of the PNG files
+    /// may otherwise vary. Call [SaveOptions::set_tight_bbox] with `false` to keep this behavior.
+    pub fn set_save_options(&mut self, options: &SaveOptions) -> &mut Self {
+        self.figure.set_save_options(options);
+        self
+    }
+
+    /// Sets the versions of Matplotlib and NumPy targeted by the generated script. See [TargetVersions]
+    pub fn set_target_versions(&mut self, versions: &TargetVersions) -> &mut Self {
+        self.figure.set_target_versions(versions);
+        self
+    }
+
+    /// Calls python3 and saves the python script and the animation
+    ///
+    /// # Input
+    ///
+    /// * `path` -- may be a String, &str, or Path; the extension must be `.gif` (animated GIF)
+    ///   or `.png` (numbered PNG files)
+    pub fn save<S>(&self, path: &S) -> Result<(), StrError>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        // update commands
+        let commands = self.get_commands(Path::new(path))?;
+
+        // call python
+        let mut py_path = Path::new(path).to_path_buf();
+        py_path.set_extension("py");
+        let output = call_python3(&self.figure.get_header(), &commands, &py_path)?;
+
+        // handle error => write log file
+        write_log_file(&output, Path::new(path), self.show_errors)
+    }
+
+    /// Returns the commands (without the header) to save the animation
+    fn get_commands(&self, path: &Path) -> Result<String, StrError> {
+        if let Some(error) = self.figure.get_error() {
+            return Err(error);
+        }
+        if self.frames.is_empty() {
+            return Err("the animation requires at least one frame");
+        }
+        if self.fps <= 0.0 {
+            return Err("the number of frames per second must be positive");
+        }
+        let extension = path.extension().unwrap_or_default().to_string_lossy().to_lowercase();
+        let gif = match extension.as_str() {
+            "gif" => true,
+            "png" => false,
+            _ => return Err("the animation must be saved with the .gif or .png extension"),
+        };
+        self.figure.check_typography(&["png"])?;
+
+        // preamble and figure
+        let mut commands = self.figure.get_preamble();
+        commands.push_str("fig=plt.gcf()\n");
+        if self.width > 0.0 && self.height > 0.0 {
+            writeln!(&mut commands, "fig.set_size_inches({},{})", self.width, self.height).unwrap();
+        }
+        let mut options = self.figure.get_save_options().clone();
+        let dpi = if self.dpi > 0.0 {
+            self.dpi
+        } else if options.get_dpi() > 0.0 {
+            options.get_dpi()
+        } else {
+            100.0
+        };
+        options.set_dpi(dpi);
+
+        // writer
+        if gif {
+            write!(
+                &mut commands,
+                "import matplotlib.animation as ani\nfn='{}'\n",
+                path.to_string_lossy()
+            )
+            .unwrap();
+            writeln!(&mut commands, "WRITER=ani.PillowWriter(fps={})", self.fps).unwrap();
+            writeln!(&mut commands, "WRITER.setup(fig,fn,dpi={})", dpi).unwrap();
+        }
+
+        // frames
+        let stem = path.with_extension("");
+        for (index, frame) in self.frames.iter().enumerate() {
+            write!(
+                &mut commands,
+                "\n# frame {}\nplt.clf()\nAX3D=None\nEXTRA_ARTISTS.clear()\n",
+                index
+            )
+            .unwrap();
+            commands.push_str(frame);
+            if gif {
+                writeln!(&mut commands, "WRITER.grab_frame({})", options.grab_frame_options()).unwrap();
+            } else {
+                let target = format!("'{}_{:04}.png'", stem.to_string_lossy(), index);
+                commands.push_str(&options.savefig(&target, "png"));
+            }
+        }
+
+        // finish writer (PillowWriter always loops; thus, the file is rewritten to play once)
+        if gif {
+            commands.push_str("\nWRITER.finish()\n");
+            if !self.loop_forever {
+                write!(
+                    &mut commands,
+                    "from PIL import Image, ImageSequence\n\
+                     FRAMES=[f.copy() for f in ImageSequence.Iterator(Image.open(fn))]\n\
+                     FRAMES[0].save(fn,save_all=True,append_images=FRAMES[1:],duration={})\n",
+                    (1000.0 / self.fps) as usize
+                )
+                .unwrap();
+            }
+        }
+        Ok(commands)
+    }
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Animation;
+    use crate::{Curve, RawPython, SaveOptions, Typography};
+    use std::path::Path;
+
+    #[test]
+    fn new_works() {
+        let animation = Animation::new();
+        assert_eq!(animation.fps, 10.0);
+        assert!(animation.loop_forever);
+        assert_eq!(animation.dpi, 0.0);
+        assert_eq!(animation.width, 0.0);
+        assert_eq!(animation.height, 0.0);
+        assert_eq!(animation.figure.get_preamble().len(), 0);
+        assert_eq!(animation.get_frame_count(), 0);
+    }
+
+    #[test]
+    fn get_commands_captures_errors() {
+        let mut animation = Animation::new();
+        let path = Path::new("/tmp/plotpy/unit_tests/animation.gif");
+        assert_eq!(
+            animation.get_commands(path).err(),
+            Some("the animation requires at least one frame")
+        );
+        animation.add_frame(&[]).set_fps(0.0);
+        assert_eq!(
+            animation.get_commands(path).err(),
+            Some("the number of frames per second must be positive")
+        );
+        animation.set_fps(5.0);
+        assert_eq!(
+            animation
+                .get_commands(Path::new("/tmp/plotpy/unit_tests/animation.mp4"))
+                .err(),
+            Some("the animation must be saved with the .gif or .png extension")
+        );
+        let mut curve = Curve::new();
+        curve.set_line_color("redd");
+        animation.add_frame(&[&curve]);
+        assert_eq!(
+            animation.get_commands(path).err(),
+            Some("invalid color: unknown color name")
+        );
+    }
+
+    #[test]
+    fn add_frames_works() {
+        let mut raw = RawPython::new();
+        raw.add_import("import scipy.stats as sst").add_line("ax.grid()");
+        let mut curve = Curve::new();
+        curve.draw(&[0.0, 1.0], &[0.0, 1.0]);
+        let mut animation = Animation::new();
+        animation.add_frame(&[&raw, &curve]);
+        animation
+            .add_frames(2, |index, plot| {
+                plot.add(&raw).set_title(&format!("{}", index));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(animation.get_frame_count(), 3);
+        assert_eq!(animation.figure.get_frame().0, &["import scipy.stats as sst"]);
+        assert_eq!(animation.frames[1], "ax=plt.gca()\nax.grid()\nplt.title(r'0')\n");
+        assert_eq!(animation.add_frames(1, |_, _| Err("stop")).err(), Some("stop"));
+    }
+
+    #[test]
+    fn get_commands_gif_works() {
+        let mut animation = Animation::new();
+        animation
+            .set_fps(4.0)
+            .set_loop(false)
+            .set_figure_size_inches(4.0, 3.0)
+            .add_frame(&[]);
+        let commands = animation
+            .get_commands(Path::new("/tmp/plotpy/unit_tests/animation.gif"))
+            .unwrap();
+        assert_eq!(
+            commands,
+            "fig=plt.gcf()\n\
+             fig.set_size_inches(4,3)\n\
+             import matplotlib.animation as ani\n\
+             fn='/tmp/plotpy/unit_tests/animation.gif'\n\
+             WRITER=ani.PillowWriter(fps=4)\n\
+             WRITER.setup(fig,fn,dpi=100)\n\
+             \n# frame 0\n\
+             plt.clf()\n\
+             AX3D=None\n\
+             EXTRA_ARTISTS.clear()\n\
+             WRITER.grab_frame()\n\
+             \nWRITER.finish()\n\
+             from PIL import Image, ImageSequence\n\
+             FRAMES=[f.copy() for f in ImageSequence.Iterator(Image.open(fn))]\n\
+             FRAMES[0].save(fn,save_all=True,append_images=FRAMES[1:],duration=250)\n"
+        );
+    }
+
+    #[test]
+    fn get_commands_png_works() {
+        let mut animation = Animation::new();
+        animation.set_dpi(50.0).add_frame(&[]).add_frame(&[]);
+        let commands = animation
+            .get_commands(Path::new("/tmp/plotpy/unit_tests/animation.png"))
+            .unwrap();
+        assert_eq!(
+            commands,
+            "fig=plt.gcf()\n\
+             \n# frame 0\n\
+             plt.clf()\n\
+             AX3D=None\n\
+             EXTRA_ARTISTS.clear()\n\
+             plt.savefig('/tmp/plotpy/unit_tests/animation_0000.png',dpi=50)\n\
+             \n# frame 1\n\
+             plt.clf()\n\
+             AX3D=None\n\
+             EXTRA_ARTISTS.clear()\n\
+             plt.savefig('/tmp/plotpy/unit_tests/animation_0001.png',dpi=50)\n"
+        );
+    }
+
+    #[test]
+    fn get_commands_uses_figure_options() {
+        let mut typography = Typography::new();
+        typography.set_font_size(9.0);
+        let mut options = SaveOptions::new();
+        options
+            .set_tight_bbox(false)
+            .set_dpi(80.0)
+            .set_transparent(true)
+            .set_face_color("#f0f0f0");
+        let mut animation = Animation::new();
+        animation
+            .set_typography(&typography)
+            .set_save_options(&options)
+            .add_frame(&[]);
+        let gif = animation
+            .get_commands(Path::new("/tmp/plotpy/unit_tests/animation.gif"))
+            .unwrap();
+        assert!(gif.starts_with(&typography.to_python()));
+        assert!(gif.contains("WRITER.setup(fig,fn,dpi=80)\n"));
+        assert!(gif.contains("WRITER.grab_frame(transparent=True,facecolor='#f0f0f0')\n"));
+        let png = animation
+            .get_commands(Path::new("/tmp/plotpy/unit_tests/animation.png"))
+            .unwrap();
+        assert!(png.contains(
+            "plt.savefig('/tmp/plotpy/unit_tests/animation_0000.png',dpi=80,transparent=True,facecolor='#f0f0f0')\n"
+        ));
+        animation.set_dpi(50.0);
+        let png = animation
+            .get_commands(Path::new("/tmp/plotpy/unit_tests/animation.png"))
+            .unwrap();
+        assert!(png.contains("animation_0000.png',dpi=50,transparent=True"));
+        typography.set_usetex(true);
+        animation.set_typography(&typography);
+        assert_eq!(
+            animation
+                .get_commands(Path::new("/tmp/plotpy/unit_tests/animation.png"))
+                .err(),
+            typography.check_tex("png").err()
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use super::{color_to_spec, ArrowStyle, Color, ConnectionStyle, CoordSystem, GraphMaker, HAlign, StrError, VAlign};
+use std::fmt::Write;
+
+/// Creates annotations: labels tied to points by connecting arrows
+///
+/// The annotated point `(x, y)` and the position of the label `(x_text, y_text)` may be given in
+/// different coordinate systems; see [CoordSystem]. For instance, the point may be given in data
+/// coordinates and the label as an offset in points from the annotated point.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Annotation, ArrowStyle, ConnectionStyle, CoordSystem, Curve, Plot, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // draw curve
+///     let mut curve = Curve::new();
+///     curve.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+///
+///     // annotate point with offset label
+///     let mut annotation = Annotation::new();
+///     annotation
+///         .set_text_coords(CoordSystem::OffsetPoints)
+///         .set_arrow_style(ArrowStyle::CurveFilledB(0.4, 0.2))
+///         .set_connection_style(ConnectionStyle::Arc3(0.3))
+///         .set_bbox(true)
+///         .set_bbox_facecolor("#f5f5f5")
+///         .set_bbox_style("round,pad=0.3")
+///         .draw(2.0, 4.0, -60.0, 30.0, "peak stress");
+///
+///     // annotate point with label in axes coordinates
+///     annotation
+///         .set_text_coords(CoordSystem::Axes)
+///         .set_connection_style(ConnectionStyle::Angle(90.0, 0.0, 0.0))
+///         .set_bbox(false)
+///         .draw(1.0, 1.0, 0.6, 0.2, "$x = 1$");
+///
+///     // add curve and annotations to plot
+///     let mut plot = Plot::new();
+///     plot.add(&curve).add(&annotation);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_annotation.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_annotation.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_annotation.svg)
+pub struct Annotation {
+    // coordinates
+    xy_coords: CoordSystem,   // Coordinate system of the annotated point
+    text_coords: CoordSystem, // Coordinate system of the label
+
+    // text
+    color: String,            // Color
+    align_horizontal: String, // Horizontal alignment
+    align_vertical: String,   // Vertical alignment
+    fontsize: f64,            // Font size
+
+    // arrow
+    with_arrow: bool,         // Draw the connecting arrow
+    arrow_style: String,      // Arrow style
+    connection_style: String, // Connection style
+    arrow_color: String,      // Arrow color
+    arrow_line_width: f64,    // Arrow line width
+
+    // bounding box
+    bbox: bool,             // Use bounding box
+    bbox_facecolor: String, // Facecolor of bounding box
+    bbox_edgecolor: String, // Edgecolor of bounding box
+    bbox_alpha: f64,        // Alpha of bounding box
+    bbox_style: String,     // Style of bounding box; example "round,pad=0.2"
+
+    // errors
+    error: Option<StrError>, // First error found in the options (e.g., an invalid color)
+
+    // buffer
+    buffer: String,
+}
+
+impl Annotation {
+    /// Creates a new Annotation object
+    pub fn new() -> Self {
+        Annotation {
+            xy_coords: CoordSystem::Data,
+            text_coords: CoordSystem::Data,
+            color: String::new(),
+            align_horizontal: String::new(),
+            align_vertical: String::new(),
+            fontsize: 0.0,
+            with_arrow: true,
+            arrow_style: ArrowStyle::CurveB(0.4, 0.2).to_string(),
+            connection_style: String::new(),
+            arrow_color: String::new(),
+            arrow_line_width: 0.0,
+            bbox: false,
+            bbox_facecolor: String::new(),
+            bbox_edgecolor: String::new(),
+            bbox_alpha: 1.0,
+            bbox_style: String::new(),
+            error: None,
+            buffer: String::new(),
+        }
+    }
+
+    /// Draws an annotation
+    ///
+    /// # Input
+    ///
+    /// * `x`, `y` -- the annotated point (in the `xy_coords` system)
+    /// * `x_text`, `y_text` -- the position of the label (in the `text_coords` system)
+    /// * `message` -- the label
+    pub fn draw(&mut self, x: f64, y: f64, x_text: f64, y_text: f64, message: &str) {
+        let opt = self.options();
+        write!(
+            &mut self.buffer,
+            "t=plt.annotate(r'{}',xy=({},{}),xytext=({},{}){})\nadd_to_ea(t)\n",
+            message, x, y, x_text, y_text, &opt
+        )
+        .unwrap();
+    }
+
+    /// Sets the coordinate system of the annotated point
+    ///
+    /// Options: [CoordSystem::Data], [CoordSystem::Axes], [CoordSystem::Figure], [CoordSystem::XDataYAxes]
+    ///
+    /// [CoordSystem::OffsetPoints] is not allowed; in this case, the coordinate system is not changed
+    /// and an error is recorded (see [crate::Plot::add]).
+    pub fn set_xy_coords(&mut self, coords: CoordSystem) -> &mut Self {
+        if coords == CoordSystem::OffsetPoints {
+            if self.error.is_none() {
+                self.error = Some("the annotated point cannot be given in offset points");
+            }
+        } else {
+            self.xy_coords = coords;
+        }
+        self
+    }
+
+    /// Sets the coordinate system of the label
+    ///
+    /// Options: [CoordSystem::Data], [CoordSystem::Axes], [CoordSystem::Figure], [CoordSystem::XDataYAxes],
+    /// [CoordSystem::OffsetPoints] (points from the annotated point)
+    pub fn set_text_coords(&mut self, coords: CoordSystem) -> &mut Self {
+        self.text_coords = coords;
+        self
+    }
+
+    /// Sets the text color
+    pub fn set_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.color = color_to_spec(color, &mut self.error);
+        self
+    }
+
+    /// Sets the horizontal alignment of the label
+    ///
+    /// Options: [HAlign::Center], [HAlign::Left], [HAlign::Right]
+    pub fn set_align_horizontal(&mut self, option: HAlign) -> &mut Self {
+        self.align_horizontal = option.to_string();
+        self
+    }
+
+    /// Sets the vertical alignment of the label
+    ///
+    /// Options: [VAlign::Center], [VAlign::Top], [VAlign::Bottom], [VAlign::Baseline], [VAlign::CenterBaseline]
+    pub fn set_align_vertical(&mut self, option: VAlign) -> &mut Self {
+        self.align_vertical = option.to_string();
+        self
+    }
+
+    /// Sets the font size
+    pub fn set_fontsize(&mut self, fontsize: f64) -> &mut Self {
+        self.fontsize = fontsize;
+        self
+    }
+
+    /// Sets the option to draw the connecting arrow
+    pub fn set_with_arrow(&mut self, flag: bool) -> &mut Self {
+        self.with_arrow = flag;
+        self
+    }
+
+    /// Sets the arrow style
+    ///
+    /// Options: [ArrowStyle::Curve], [ArrowStyle::CurveB], [ArrowStyle::CurveFilledB], [ArrowStyle::Fancy], ...; see [ArrowStyle]
+    pub fn set_arrow_style(&mut self, style: ArrowStyle) -> &mut Self {
+        self.arrow_style = style.to_string();
+        self
+    }
+
+    /// Sets the connection style
+    ///
+    /// Options: [ConnectionStyle::Arc3], [ConnectionStyle::Angle3], [ConnectionStyle::Angle], [ConnectionStyle::Arc]
+    pub fn set_connection_style(&mut self, style: ConnectionStyle) -> &mut Self {
+        self.connection_style = style.to_string();
+        self
+    }
+
+    /// Sets the arrow color
+    pub fn set_arrow_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.arrow_color = color_to_spec(color, &mut self.error);
+        self
+    }
+
+    /// Sets the arrow line width
+    pub fn set_arrow_line_width(&mut self, width: f64) -> &mut Self {
+        self.arrow_line_width = width;
+        self
+    }
+
+    /// Sets use bounding box flag
+    pub fn set_bbox(&mut self, flag: bool) -> &mut Self {
+        self.bbox = flag;
+        self
+    }
+
+    /// Sets facecolor of bounding box
+    pub fn set_bbox_facecolor(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.bbox_facecolor = color_to_spec(color, &mut self.error);
+        self
+    }
+
+    /// Sets edgecolor of bounding box
+    pub fn set_bbox_edgecolor(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.bbox_edgecolor = color_to_spec(color, &mut self.error);
+        self
+    }
+
+    /// Sets alpha of bounding box
+    pub fn set_bbox_alpha(&mut self, value: f64) -> &mut Self {
+        self.bbox_alpha = value;
+        self
+    }
+
+    /// Sets style of bounding box
+    ///
+    /// Examples: "square,pad=0.3", "round,pad=0.3,rounding_size=0.15", "sawtooth,pad=0.3,tooth_size=0.1";
+    /// see [crate::Text::set_bbox_style]
+    pub fn set_bbox_style(&mut self, style: &str) -> &mut Self {
+        self.bbox_style = String::from(style);
+        self
+    }
+
+    /// Returns options for annotate
+    fn options(&self) -> String {
+        let mut opt = String::new();
+        if self.xy_coords != CoordSystem::Data {
+            write!(&mut opt, ",xycoords={}", self.xy_coords.annotation_coords()).unwrap();
+        }
+        if self.text_coords != self.xy_coords {
+            write!(&mut opt, ",textcoords={}", self.text_coords.annotation_coords()).unwrap();
+        }
+        if !self.color.is_empty() {
+            write!(&mut opt, ",color='{}'", self.color).unwrap();
+        }
+        if !self.align_horizontal.is_empty() {
+            write!(&mut opt, ",ha='{}'", self.align_horizontal).unwrap();
+        }
+        if !self.align_vertical.is_empty() {
+            write!(&mut opt, ",va='{}'", self.align_vertical).unwrap();
+        }
+        if self.fontsize > 0.0 {
+            write!(&mut opt, ",fontsize={}", self.fontsize).unwrap();
+        }
+        if self.with_arrow {
+            write!(&mut opt, ",arrowprops=dict({})", self.options_arrow()).unwrap();
+        }
+        if self.bbox {
+            write!(&mut opt, ",bbox=dict({})", self.options_bbox()).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for the arrow
+    fn options_arrow(&self) -> String {
+        let mut opt = String::new();
+        write!(&mut opt, "arrowstyle='{}',", self.arrow_style).unwrap();
+        if !self.connection_style.is_empty() {
+            write!(&mut opt, "connectionstyle='{}',", self.connection_style).unwrap();
+        }
+        if !self.arrow_color.is_empty() {
+            write!(&mut opt, "color='{}',", self.arrow_color).unwrap();
+        }
+        if self.arrow_line_width > 0.0 {
+            write!(&mut opt, "linewidth={},", self.arrow_line_width).unwrap();
+        }
+        opt
+    }
+
+    /// Returns options for bounding box
+    fn options_bbox(&self) -> String {
+        let mut opt = String::new();
+        if !self.bbox_facecolor.is_empty() {
+            write!(&mut opt, "facecolor='{}',", self.bbox_facecolor).unwrap();
+        }
+        if !self.bbox_edgecolor.is_empty() {
+            write!(&mut opt, "edgecolor='{}',", self.bbox_edgecolor).unwrap();
+        }
+        write!(&mut opt, "alpha={},", self.bbox_alpha).unwrap();
+        if !self.bbox_style.is_empty() {
+            write!(&mut opt, "boxstyle='{}',", self.bbox_style).unwrap();
+        }
+        opt
+    }
+}
+
+impl Default for Annotation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphMaker for Annotation {
+    fn get_buffer(&self) -> &String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+    }
+    fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Annotation;
+    use crate::{ArrowStyle, ConnectionStyle, CoordSystem, GraphMaker, HAlign, VAlign};
+
+    #[test]
+    fn new_works() {
+        let annotation = Annotation::new();
+        assert_eq!(annotation.xy_coords, CoordSystem::Data);
+        assert_eq!(annotation.text_coords, CoordSystem::Data);
+        assert_eq!(annotation.color.len(), 0);
+        assert_eq!(annotation.fontsize, 0.0);
+        assert!(annotation.with_arrow);
+        assert_eq!(annotation.arrow_style, "->");
+        assert_eq!(annotation.connection_style.len(), 0);
+        assert!(!annotation.bbox);
+        assert_eq!(annotation.buffer.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut annotation = Annotation::new();
+        assert_eq!(annotation.options(), ",arrowprops=dict(arrowstyle='->',)");
+        annotation
+            .set_xy_coords(CoordSystem::Axes)
+            .set_text_coords(CoordSystem::OffsetPoints)
+            .set_color("red")
+            .set_align_horizontal(HAlign::Center)
+            .set_align_vertical(VAlign::Top)
+            .set_fontsize(8.0)
+            .set_arrow_style(ArrowStyle::Fancy(0.6, 0.6, 0.2))
+            .set_connection_style(ConnectionStyle::Arc3(0.2))
+            .set_arrow_color("blue")
+            .set_arrow_line_width(2.0);
+        assert_eq!(
+            annotation.options(),
+            ",xycoords='axes fraction'\
+             ,textcoords='offset points'\
+             ,color='red'\
+             ,ha='center'\
+             ,va='top'\
+             ,fontsize=8\
+             ,arrowprops=dict(\
+             arrowstyle='fancy,head_length=0.6,head_width=0.6,tail_width=0.2',\
+             connectionstyle='arc3,rad=0.2',\
+             color='blue',\
+             linewidth=2,)"
+        );
+        annotation.set_text_coords(CoordSystem::Axes).set_with_arrow(false);
+        assert_eq!(
+            annotation.options(),
+            ",xycoords='axes fraction',color='red',ha='center',va='top',fontsize=8"
+        );
+    }
+
+    #[test]
+    fn options_bbox_works() {
+        let mut annotation = Annotation::new();
+        annotation
+            .set_with_arrow(false)
+            .set_bbox(true)
+            .set_bbox_facecolor("pink")
+            .set_bbox_edgecolor("black")
+            .set_bbox_alpha(0.3)
+            .set_bbox_style("round,pad=0.4");
+        assert_eq!(
+            annotation.options(),
+            ",bbox=dict(facecolor='pink',edgecolor='black',alpha=0.3,boxstyle='round,pad=0.4',)"
+        );
+    }
+
+    #[test]
+    fn draw_works() {
+        let mut annotation = Annotation::new();
+        annotation
+            .set_text_coords(CoordSystem::OffsetPoints)
+            .draw(1.0, 2.0, -30.0, 20.0, "point");
+        assert_eq!(
+            annotation.buffer,
+            "t=plt.annotate(r'point',xy=(1,2),xytext=(-30,20),textcoords='offset points',arrowprops=dict(arrowstyle='->',))\n\
+             add_to_ea(t)\n"
+        );
+        annotation.clear_buffer();
+        assert_eq!(annotation.buffer, "");
+    }
+
+    #[test]
+    fn set_xy_coords_captures_errors() {
+        let mut annotation = Annotation::new();
+        annotation
+            .set_xy_coords(CoordSystem::Axes)
+            .set_xy_coords(CoordSystem::OffsetPoints);
+        assert_eq!(annotation.xy_coords, CoordSystem::Axes);
+        assert_eq!(
+            annotation.get_error(),
+            Some("the annotated point cannot be given in offset points")
+        );
+        annotation.set_color("__not_a_color__");
+        assert_eq!(
+            annotation.get_error(),
+            Some("the annotated point cannot be given in offset points")
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use super::StrError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Defines the style of arrows (with parameters)
+///
+/// Parameters equal to the Matplotlib defaults (shown below) are omitted from the generated code.
+///
+/// Setters accepting arrow styles (e.g., [crate::Canvas::set_arrow_style]) take an `ArrowStyle`; hence,
+/// invalid styles are caught at compile time. The Matplotlib names (shown below) may be converted by
+/// `str::parse` (with the default parameters), which returns an error for invalid strings.
+///
+/// See [Matplotlib](https://matplotlib.org/stable/api/_as_gen/matplotlib.patches.ArrowStyle.html)
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{ArrowStyle, Canvas, Plot, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // draw arrows
+///     let mut canvas = Canvas::new();
+///     canvas.set_face_color("#cd0000").set_arrow_scale(20.0);
+///     canvas.set_arrow_style(ArrowStyle::Fancy(0.6, 0.6, 0.2));
+///     canvas.draw_arrow(0.0, 0.0, 1.0, 0.0);
+///     canvas.set_arrow_style(ArrowStyle::CurveFilledAB(0.8, 0.3));
+///     canvas.draw_arrow(0.0, 0.5, 1.0, 0.5);
+///     canvas.set_arrow_style(ArrowStyle::BracketAB(2.0, 0.2));
+///     canvas.draw_arrow(0.0, 1.0, 1.0, 1.0);
+///
+///     // add arrows to plot
+///     let mut plot = Plot::new();
+///     plot.add(&canvas).set_range(-0.1, 1.1, -0.1, 1.1);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_arrow_style.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_arrow_style.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_arrow_style.svg)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrowStyle {
+    /// "`-`" (no arrow heads)
+    Curve,
+
+    /// "`<-`" with (head_length = 0.4, head_width = 0.2)
+    CurveA(f64, f64),
+
+    /// "`->`" with (head_length = 0.4, head_width = 0.2)
+    CurveB(f64, f64),
+
+    /// "`<->`" with (head_length = 0.4, head_width = 0.2)
+    CurveAB(f64, f64),
+
+    /// "`<|-`" with (head_length = 0.4, head_width = 0.2)
+    CurveFilledA(f64, f64),
+
+    /// "`-|>`" with (head_length = 0.4, head_width = 0.2)
+    CurveFilledB(f64, f64),
+
+    /// "`<|-|>`" with (head_length = 0.4, head_width = 0.2)
+    CurveFilledAB(f64, f64),
+
+    /// "`]-`" with (width = 1.0, length = 0.2)
+    BracketA(f64, f64),
+
+    /// "`-[`" with (width = 1.0, length = 0.2)
+    BracketB(f64, f64),
+
+    /// "`]-[`" with (width = 1.0, length = 0.2) at both ends
+    BracketAB(f64, f64),
+
+    /// "`]->`" with (width = 1.0, length = 0.2) of the bracket at the start
+    BracketCurve(f64, f64),
+
+    /// "`<-[`" with (width = 1.0, length = 0.2) of the bracket at the end
+    CurveBracket(f64, f64),
+
+    /// "`|-|`" with (width = 1.0) at both ends
+    BarAB(f64),
+
+    /// "`fancy`" with (head_length = 0.4, head_width = 0.4, tail_width = 0.4)
+    Fancy(f64, f64, f64),
+
+    /// "`simple`" with (head_length = 0.5, head_width = 0.5, tail_width = 0.2)
+    Simple(f64, f64, f64),
+
+    /// "`wedge`" with (tail_width = 0.3, shrink_factor = 0.5)
+    Wedge(f64, f64),
+}
+
+impl fmt::Display for ArrowStyle {
+    /// Writes the option passed to Matplotlib (without quotes), e.g., "fancy,head_width=0.6"
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, params): (&str, Vec<(&str, f64, f64)>) = match *self {
+            ArrowStyle::Curve => ("-", vec![]),
+            ArrowStyle::CurveA(l, w) => ("<-", head(l, w)),
+            ArrowStyle::CurveB(l, w) => ("->", head(l, w)),
+            ArrowStyle::CurveAB(l, w) => ("<->", head(l, w)),
+            ArrowStyle::CurveFilledA(l, w) => ("<|-", head(l, w)),
+            ArrowStyle::CurveFilledB(l, w) => ("-|>", head(l, w)),
+            ArrowStyle::CurveFilledAB(l, w) => ("<|-|>", head(l, w)),
+            ArrowStyle::BracketA(w, l) => ("]-", vec![("widthA", w, 1.0), ("lengthA", l, 0.2)]),
+            ArrowStyle::BracketB(w, l) => ("-[", vec![("widthB", w, 1.0), ("lengthB", l, 0.2)]),
+            ArrowStyle::BracketAB(w, l) => (
+                "]-[",
+                vec![
+                    ("widthA", w, 1.0),
+                    ("lengthA", l, 0.2),
+                    ("widthB", w, 1.0),
+                    ("lengthB", l, 0.2),
+                ],
+            ),
+            ArrowStyle::BracketCurve(w, l) => ("]->", vec![("widthA", w, 1.0), ("lengthA", l, 0.2)]),
+            ArrowStyle::CurveBracket(w, l) => ("<-[", vec![("widthB", w, 1.0), ("lengthB", l, 0.2)]),
+            ArrowStyle::BarAB(w) => ("|-|", vec![("widthA", w, 1.0), ("widthB", w, 1.0)]),
+            ArrowStyle::Fancy(l, w, t) => (
+                "fancy",
+                vec![("head_length", l, 0.4), ("head_width", w, 0.4), ("tail_width", t, 0.4)],
+            ),
+            ArrowStyle::Simple(l, w, t) => (
+                "simple",
+                vec![("head_length", l, 0.5), ("head_width", w, 0.5), ("tail_width", t, 0.2)],
+            ),
+            ArrowStyle::Wedge(t, s) => ("wedge", vec![("tail_width", t, 0.3), ("shrink_factor", s, 0.5)]),
+        };
+        write!(f, "{}", name)?;
+        for (key, value, default) in params {
+            if value != default {
+                write!(f, ",{}={}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the parameters of the arrow heads of the Curve styles
+fn head(length: f64, width: f64) -> Vec<(&'static str, f64, f64)> {
+    vec![("head_length", length, 0.4), ("head_width", width, 0.2)]
+}
+
+impl FromStr for ArrowStyle {
+    type Err = StrError;
+    fn from_str(style: &str) -> Result<Self, Self::Err> {
+        match style {
+            "-" => Ok(ArrowStyle::Curve),
+            "<-" => Ok(ArrowStyle::CurveA(0.4, 0.2)),
+            "->" => Ok(ArrowStyle::CurveB(0.4, 0.2)),
+            "<->" => Ok(ArrowStyle::CurveAB(0.4, 0.2)),
+            "<|-" => Ok(ArrowStyle::CurveFilledA(0.4, 0.2)),
+            "-|>" => Ok(ArrowStyle::CurveFilledB(0.4, 0.2)),
+            "<|-|>" => Ok(ArrowStyle::CurveFilledAB(0.4, 0.2)),
+            "]-" => Ok(ArrowStyle::BracketA(1.0, 0.2)),
+            "-[" => Ok(ArrowStyle::BracketB(1.0, 0.2)),
+            "]-[" => Ok(ArrowStyle::BracketAB(1.0, 0.2)),
+            "]->" => Ok(ArrowStyle::BracketCurve(1.0, 0.2)),
+            "<-[" => Ok(ArrowStyle::CurveBracket(1.0, 0.2)),
+            "|-|" => Ok(ArrowStyle::BarAB(1.0)),
+            "fancy" => Ok(ArrowStyle::Fancy(0.4, 0.4, 0.4)),
+            "simple" => Ok(ArrowStyle::Simple(0.5, 0.5, 0.2)),
+            "wedge" => Ok(ArrowStyle::Wedge(0.3, 0.5)),
+            _ => Err("invalid arrow style"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ArrowStyle;
+
+    #[test]
+    fn display_works() {
+        assert_eq!(ArrowStyle::Curve.to_string(), "-");
+        assert_eq!(ArrowStyle::CurveB(0.4, 0.2).to_string(), "->");
+        assert_eq!(ArrowStyle::CurveFilledAB(0.8, 0.2).to_string(), "<|-|>,head_length=0.8");
+        assert_eq!(ArrowStyle::BracketB(2.0, 0.3).to_string(), "-[,widthB=2,lengthB=0.3");
+        assert_eq!(ArrowStyle::BracketAB(2.0, 0.2).to_string(), "]-[,widthA=2,widthB=2");
+        assert_eq!(ArrowStyle::BracketCurve(1.0, 0.4).to_string(), "]->,lengthA=0.4");
+        assert_eq!(ArrowStyle::CurveBracket(0.5, 0.2).to_string(), "<-[,widthB=0.5");
+        assert_eq!(ArrowStyle::BarAB(0.5).to_string(), "|-|,widthA=0.5,widthB=0.5");
+        assert_eq!(
+            ArrowStyle::Fancy(0.6, 0.6, 0.2).to_string(),
+            "fancy,head_length=0.6,head_width=0.6,tail_width=0.2"
+        );
+        assert_eq!(ArrowStyle::Simple(0.5, 0.5, 0.3).to_string(), "simple,tail_width=0.3");
+        assert_eq!(ArrowStyle::Wedge(0.3, 0.8).to_string(), "wedge,shrink_factor=0.8");
+    }
+
+    #[test]
+    fn from_str_works() {
+        let all = [
+            "-", "<-", "->", "<->", "<|-", "-|>", "<|-|>", "]-", "-[", "]-[", "]->", "<-[", "|-|", "fancy", "simple",
+            "wedge",
+        ];
+        for style in all {
+            assert_eq!(style.parse::<ArrowStyle>().unwrap().to_string(), style);
+        }
+        assert_eq!("=>".parse::<ArrowStyle>().err(), Some("invalid arrow style"));
+        assert_eq!("arrow".parse::<ArrowStyle>().err(), Some("invalid arrow style"));
+    }
+}
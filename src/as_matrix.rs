@@ -68,14 +68,14 @@ pub trait AsMatrix<'a, U: 'a> {
 ///
 /// # Notes
 ///
-/// * The number of columns is defined by the first row
+/// * The number of columns is defined by the first row (zero if there are no rows)
 /// * The next rows must have at least the same number of columns as the first row
 impl<'a, U: 'a> AsMatrix<'a, U> for Vec<Vec<U>>
 where
     U: 'a + Copy,
 {
     fn size(&self) -> (usize, usize) {
-        (self.len(), self[0].len())
+        (self.len(), self.first().map_or(0, |row| row.len()))
     }
     fn at(&self, i: usize, j: usize) -> U {
         self[i][j]
@@ -86,14 +86,14 @@ where
 ///
 /// # Notes
 ///
-/// * The number of columns is defined by the first row
+/// * The number of columns is defined by the first row (zero if there are no rows)
 /// * The next rows must have at least the same number of columns as the first row
 impl<'a, U> AsMatrix<'a, U> for &'a [&'a [U]]
 where
     U: 'a + Copy,
 {
     fn size(&self) -> (usize, usize) {
-        (self.len(), self[0].len())
+        (self.len(), self.first().map_or(0, |row| row.len()))
     }
     fn at(&self, i: usize, j: usize) -> U {
         self[i][j]
@@ -106,7 +106,7 @@ where
     U: 'a + Copy,
 {
     fn size(&self) -> (usize, usize) {
-        (self.len(), self[0].len())
+        (M, N)
     }
     fn at(&self, i: usize, j: usize) -> U {
         self[i][j]
@@ -190,4 +190,14 @@ mod tests {
              0.5,0.6,\n"
         );
     }
+
+    #[test]
+    fn as_matrix_handles_empty() {
+        let a: Vec<Vec<f64>> = Vec::new();
+        assert_eq!(a.size(), (0, 0));
+        let b: &[&[f64]] = &[];
+        assert_eq!(b.size(), (0, 0));
+        let c: [[f64; 2]; 0] = [];
+        assert_eq!(c.size(), (0, 2));
+    }
 }
@@ -1,11 +1,15 @@
-use super::{GraphMaker, StrError};
-use crate::AsMatrix;
+use super::{color_to_spec, ArrowStyle, Color, CoordSystem, GraphMaker, HAlign, LineStyle, StrError, VAlign};
+use crate::polygon::{oriented_ring, ring_from_matrix};
+use crate::{matrix_to_array, AsMatrix, Surface};
 use std::fmt::Write;
 
+/// Number of divisions of circles and cones in 3D
+const NDIV_CIRCLE_3D: usize = 48;
+
 /// Defines the poly-curve code
 ///
 /// Reference: [Matplotlib](https://matplotlib.org/stable/api/path_api.html)
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PolyCode {
     /// Move to coordinate (first point)
     ///
@@ -35,7 +39,7 @@ pub enum PolyCode {
 /// ## Drawing functions with polyline set by an array
 ///
 /// ```
-/// use plotpy::{Canvas, Plot};
+/// use plotpy::{ArrowStyle, Canvas, Plot};
 ///
 /// fn main() -> Result<(), &'static str> {
 ///     // canvas object and common options
@@ -46,7 +50,7 @@ pub enum PolyCode {
 ///     canvas.draw_arc(0.5, 0.5, 0.4, 195.0, -15.0);
 ///
 ///     // draw arrow
-///     canvas.set_arrow_scale(50.0).set_arrow_style("fancy");
+///     canvas.set_arrow_scale(50.0).set_arrow_style(ArrowStyle::Fancy(0.4, 0.4, 0.4));
 ///     canvas.draw_arrow(0.4, 0.3, 0.6, 0.5);
 ///
 ///     // draw circle
@@ -122,14 +126,52 @@ pub enum PolyCode {
 ///
 /// ![doc_canvas_polycurve.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_canvas_polycurve.svg)
 ///
+/// ## Shapes
+///
+/// ```
+/// use plotpy::{Canvas, Plot, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // canvas object and common options
+///     let mut canvas = Canvas::new();
+///     canvas.set_edge_color("#cd0000").set_face_color("#eeea83");
+///
+///     // draw shapes
+///     canvas.draw_rectangle(0.0, 0.0, 1.0, 0.5, 0.0, 0.1);
+///     canvas.draw_ellipse(2.0, 0.25, 1.0, 0.5, 30.0);
+///     canvas.draw_wedge(3.5, 0.0, 0.6, 0.0, 90.0);
+///     canvas.draw_annulus(0.5, 1.5, 0.25, 0.5)?;
+///     canvas.draw_regular_polygon(2.0, 1.5, 0.5, 6, 0.0);
+///     canvas.draw_fancy_box(3.25, 1.25, 0.75, 0.5, "sawtooth,pad=0.1");
+///
+///     // add canvas to plot
+///     let mut plot = Plot::new();
+///     plot.add(&canvas);
+///
+///     // save figure
+///     plot.set_range(-0.5, 4.5, -0.5, 2.5)
+///         .set_hide_axes(true)
+///         .set_equal_axes(true)
+///         .set_show_errors(true);
+///     plot.save("/tmp/plotpy/doc_tests/doc_canvas_shapes.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
 /// See also integration tests in the [tests directory](https://github.com/cpmech/plotpy/tree/main/tests)
 pub struct Canvas {
     // features
-    edge_color: String,  // Edge color (shared)
-    face_color: String,  // Face color (shared)
-    line_width: f64,     // Line width of edge (shared)
-    arrow_scale: f64,    // Arrow scale
-    arrow_style: String, // Arrow style
+    edge_color: String,     // Edge color (shared)
+    face_color: String,     // Face color (shared)
+    line_width: f64,        // Line width of edge (shared)
+    line_style: String,     // Line style of edge (shared)
+    hatch: String,          // Hatch pattern (shared)
+    alpha: f64,             // Opacity (shared)
+    zorder: Option<f64>,    // Drawing order (shared)
+    arrow_scale: f64,       // Arrow scale
+    arrow_style: String,    // Arrow style
+    arrow_head_length: f64, // Length of the cone head of 3D arrows
+    arrow_head_radius: f64, // Radius of the cone head of 3D arrows
 
     // text
     text_color: String,            // Text color
@@ -146,7 +188,11 @@ pub struct Canvas {
     alt_text_rotation: f64,            // Text rotation
 
     // options
-    stop_clip: bool, // Stop clipping features within margins
+    stop_clip: bool,     // Stop clipping features within margins
+    coords: CoordSystem, // Coordinate system of 2D features
+
+    // errors
+    error: Option<StrError>, // First error found in the options (e.g., an invalid color)
 
     // buffer
     buffer: String, // buffer
@@ -159,8 +205,14 @@ impl Canvas {
             edge_color: "#427ce5".to_string(),
             face_color: String::new(),
             line_width: 0.0,
+            line_style: String::new(),
+            hatch: String::new(),
+            alpha: 0.0,
+            zorder: None,
             arrow_scale: 0.0,
             arrow_style: String::new(),
+            arrow_head_length: 0.0,
+            arrow_head_radius: 0.0,
             // text
             text_color: "#a81414".to_string(),
             text_align_horizontal: String::new(),
@@ -175,7 +227,9 @@ impl Canvas {
             alt_text_rotation: 0.0,
             // options
             stop_clip: false,
+            coords: CoordSystem::Data,
             // buffer
+            error: None,
             buffer: String::new(),
         }
     }
@@ -201,7 +255,7 @@ impl Canvas {
         T: std::fmt::Display,
     {
         let opt_shared = self.options_shared();
-        let opt_arrow = self.options_arrow();
+        let opt_arrow = self.options_arrow("");
         write!(
             &mut self.buffer,
             "p=pat.FancyArrowPatch(({},{}),({},{})\
@@ -229,6 +283,616 @@ impl Canvas {
         .unwrap();
     }
 
+    /// Draws rectangle (2D only)
+    ///
+    /// # Input
+    ///
+    /// * `xmin, ymin` -- lower-left corner (before rotation)
+    /// * `width, height` -- dimensions
+    /// * `angle` -- rotation angle in degrees (counterclockwise) around the lower-left corner
+    /// * `corner_radius` -- radius of the rounded corners (ignored if ≤ 0)
+    pub fn draw_rectangle<T>(&mut self, xmin: T, ymin: T, width: T, height: T, angle: f64, corner_radius: f64)
+    where
+        T: std::fmt::Display,
+    {
+        let opt = self.options_shared();
+        if corner_radius > 0.0 {
+            write!(
+                &mut self.buffer,
+                "p=pat.FancyBboxPatch(({},{}),{},{},boxstyle='round,pad=0,rounding_size={}'{})\n\
+                 plt.gca().add_patch(p)\n",
+                xmin, ymin, width, height, corner_radius, &opt
+            )
+            .unwrap();
+            if angle != 0.0 {
+                writeln!(
+                    &mut self.buffer,
+                    "p.set_transform(tra.Affine2D().rotate_deg_around({},{},{})+p.get_transform())",
+                    xmin, ymin, angle
+                )
+                .unwrap();
+            }
+        } else {
+            write!(
+                &mut self.buffer,
+                "p=pat.Rectangle(({},{}),{},{},angle={}{})\n\
+                 plt.gca().add_patch(p)\n",
+                xmin, ymin, width, height, angle, &opt
+            )
+            .unwrap();
+        }
+    }
+
+    /// Draws rectangle on a plane perpendicular to the x, y, or z axis (3D)
+    ///
+    /// # Input
+    ///
+    /// * `umin, vmin` -- lower-left corner on the plane, where (u, v) are (y, z), (x, z), or (x, y)
+    ///   if `axis` is 0, 1, or 2, respectively
+    /// * `width, height` -- dimensions along u and v
+    /// * `axis` -- the axis perpendicular to the plane (0, 1, or 2)
+    /// * `position` -- the coordinate of the plane along `axis`
+    pub fn draw_rectangle_3d(
+        &mut self,
+        umin: f64,
+        vmin: f64,
+        width: f64,
+        height: f64,
+        axis: usize,
+        position: f64,
+    ) -> Result<(), StrError> {
+        let patch = format!(
+            "pat.Rectangle(({},{}),{},{}{})",
+            umin,
+            vmin,
+            width,
+            height,
+            self.options_patch()
+        );
+        self.add_patch_3d(&patch, axis, position)
+    }
+
+    /// Draws ellipse (2D only)
+    ///
+    /// # Input
+    ///
+    /// * `xc, yc` -- center
+    /// * `width, height` -- diameters along the x and y axes (before rotation)
+    /// * `angle` -- rotation angle in degrees (counterclockwise)
+    pub fn draw_ellipse<T>(&mut self, xc: T, yc: T, width: T, height: T, angle: T)
+    where
+        T: std::fmt::Display,
+    {
+        let opt = self.options_shared();
+        write!(
+            &mut self.buffer,
+            "p=pat.Ellipse(({},{}),{},{},angle={}{})\n\
+             plt.gca().add_patch(p)\n",
+            xc, yc, width, height, angle, &opt
+        )
+        .unwrap();
+    }
+
+    /// Draws ellipse on a plane perpendicular to the x, y, or z axis (3D)
+    ///
+    /// See [Canvas::draw_rectangle_3d] for the definition of (u, v), `axis`, and `position`
+    pub fn draw_ellipse_3d(
+        &mut self,
+        uc: f64,
+        vc: f64,
+        width: f64,
+        height: f64,
+        axis: usize,
+        position: f64,
+    ) -> Result<(), StrError> {
+        let patch = format!(
+            "pat.Ellipse(({},{}),{},{}{})",
+            uc,
+            vc,
+            width,
+            height,
+            self.options_patch()
+        );
+        self.add_patch_3d(&patch, axis, position)
+    }
+
+    /// Draws wedge, i.e., a circular sector (2D only)
+    ///
+    /// The angles are given in degrees (counterclockwise from the x axis).
+    pub fn draw_wedge<T>(&mut self, xc: T, yc: T, r: T, ini_angle: T, fin_angle: T)
+    where
+        T: std::fmt::Display,
+    {
+        let opt = self.options_shared();
+        write!(
+            &mut self.buffer,
+            "p=pat.Wedge(({},{}),{},{},{}{})\n\
+             plt.gca().add_patch(p)\n",
+            xc, yc, r, ini_angle, fin_angle, &opt
+        )
+        .unwrap();
+    }
+
+    /// Draws annulus, i.e., the region between two concentric circles (2D only)
+    ///
+    /// Returns an error if `r_inner > r_outer`.
+    pub fn draw_annulus<T>(&mut self, xc: T, yc: T, r_inner: T, r_outer: T) -> Result<(), StrError>
+    where
+        T: std::fmt::Display + PartialOrd,
+    {
+        if r_inner > r_outer {
+            return Err("the inner radius of the annulus must not be greater than the outer radius");
+        }
+        let opt = self.options_shared();
+        write!(
+            &mut self.buffer,
+            "p=pat.Wedge(({},{}),{},0,360,width={}-{}{})\n\
+             plt.gca().add_patch(p)\n",
+            xc, yc, r_outer, r_outer, r_inner, &opt
+        )
+        .unwrap();
+        Ok(())
+    }
+
+    /// Draws regular polygon (2D only)
+    ///
+    /// # Input
+    ///
+    /// * `xc, yc` -- center
+    /// * `r` -- distance from the center to each vertex
+    /// * `nvertex` -- number of vertices
+    /// * `angle` -- rotation angle in degrees (counterclockwise); with zero, a vertex points upwards
+    pub fn draw_regular_polygon<T>(&mut self, xc: T, yc: T, r: T, nvertex: usize, angle: f64)
+    where
+        T: std::fmt::Display,
+    {
+        let opt = self.options_shared();
+        let orientation = Canvas::orientation(angle);
+        write!(
+            &mut self.buffer,
+            "p=pat.RegularPolygon(({},{}),{},radius={}{}{})\n\
+             plt.gca().add_patch(p)\n",
+            xc, yc, nvertex, r, &orientation, &opt
+        )
+        .unwrap();
+    }
+
+    /// Draws regular polygon on a plane perpendicular to the x, y, or z axis (3D)
+    ///
+    /// See [Canvas::draw_rectangle_3d] for the definition of (u, v), `axis`, and `position`
+    pub fn draw_regular_polygon_3d(
+        &mut self,
+        uc: f64,
+        vc: f64,
+        r: f64,
+        nvertex: usize,
+        axis: usize,
+        position: f64,
+    ) -> Result<(), StrError> {
+        let patch = format!(
+            "pat.RegularPolygon(({},{}),{},radius={}{})",
+            uc,
+            vc,
+            nvertex,
+            r,
+            self.options_patch()
+        );
+        self.add_patch_3d(&patch, axis, position)
+    }
+
+    /// Draws fancy box (2D only)
+    ///
+    /// # Input
+    ///
+    /// * `xmin, ymin` -- lower-left corner
+    /// * `width, height` -- dimensions
+    /// * `style` -- the box style, e.g., "round,pad=0.1", "round4,pad=0.2", "sawtooth,pad=0.1,tooth_size=0.05",
+    ///   "roundtooth", "larrow", "rarrow", "darrow", "circle"; see [crate::Text::set_bbox_style]
+    pub fn draw_fancy_box<T>(&mut self, xmin: T, ymin: T, width: T, height: T, style: &str)
+    where
+        T: std::fmt::Display,
+    {
+        let opt = self.options_shared();
+        write!(
+            &mut self.buffer,
+            "p=pat.FancyBboxPatch(({},{}),{},{},boxstyle='{}'{})\n\
+             plt.gca().add_patch(p)\n",
+            xmin, ymin, width, height, style, &opt
+        )
+        .unwrap();
+    }
+
+    /// Draws dimension line showing the distance between two points (2D only)
+    ///
+    /// The dimension line has double-headed arrows and is drawn parallel to the segment from `a` to `b`
+    /// at a distance `offset`. Extension lines connect the points to the dimension line. The label is
+    /// centered on the dimension line and rotated along it.
+    ///
+    /// # Input
+    ///
+    /// * `xa, ya` -- first point
+    /// * `xb, yb` -- second point
+    /// * `offset` -- distance from the segment to the dimension line; positive values place the
+    ///   dimension line to the left of the direction from `a` to `b`
+    /// * `text` -- the label; e.g., "2.5 m" or "$L$"
+    ///
+    /// # Notes
+    ///
+    /// * The arrows use the shared and arrow options; the default arrow style is "<->"
+    /// * The label uses the alternative text options (see [Canvas::set_alt_text_color]); if the
+    ///   alternative text rotation is not set, the label is rotated along the dimension line
+    pub fn draw_dimension(&mut self, xa: f64, ya: f64, xb: f64, yb: f64, offset: f64, text: &str) {
+        let length = f64::sqrt((xb - xa) * (xb - xa) + (yb - ya) * (yb - ya));
+        if length == 0.0 {
+            return;
+        }
+        let (ux, uy) = ((xb - xa) / length, (yb - ya) / length);
+        let (nx, ny) = (-uy, ux);
+        let (xa_dim, ya_dim) = (xa + offset * nx, ya + offset * ny);
+        let (xb_dim, yb_dim) = (xb + offset * nx, yb + offset * ny);
+        if offset != 0.0 {
+            let overshoot = 0.1 * offset;
+            self.open_polylines(&[
+                vec![[xa, ya], [xa_dim + overshoot * nx, ya_dim + overshoot * ny]],
+                vec![[xb, yb], [xb_dim + overshoot * nx, yb_dim + overshoot * ny]],
+            ]);
+        }
+        self.arrow_patch(
+            &format!(
+                "pat.FancyArrowPatch(({},{}),({},{}),shrinkA=0,shrinkB=0",
+                xa_dim, ya_dim, xb_dim, yb_dim
+            ),
+            "<->",
+        );
+        let angle = f64::atan2(uy, ux) * 180.0 / std::f64::consts::PI;
+        self.dimension_label((xa_dim + xb_dim) / 2.0, (ya_dim + yb_dim) / 2.0, angle, text);
+    }
+
+    /// Draws angular dimension showing the angle between two segments sharing a vertex (2D only)
+    ///
+    /// The dimension arc has double-headed arrows and goes counterclockwise from the segment
+    /// `c`→`a` to the segment `c`→`b`. Extension lines are drawn if the segments are shorter than the radius.
+    ///
+    /// # Input
+    ///
+    /// * `c` -- the common vertex (center of the arc)
+    /// * `ends` -- the endpoints `a` and `b` of the first and second segments
+    /// * `radius` -- radius of the dimension arc
+    /// * `text` -- the label; e.g., "45°" or "$\theta$"
+    ///
+    /// See [Canvas::draw_dimension] for the options used by the arrows and the label.
+    ///
+    /// **Note:** Use [crate::Plot::set_equal_axes] to obtain a circular arc.
+    pub fn draw_dimension_angular(&mut self, c: &[f64; 2], ends: &[[f64; 2]; 2], radius: f64, text: &str) {
+        if radius <= 0.0 {
+            return;
+        }
+        let (xc, yc) = (c[0], c[1]);
+        let [[xa, ya], [xb, yb]] = *ends;
+        let mut ini_angle = f64::atan2(ya - yc, xa - xc) * 180.0 / std::f64::consts::PI;
+        let mut fin_angle = f64::atan2(yb - yc, xb - xc) * 180.0 / std::f64::consts::PI;
+        if ini_angle < 0.0 {
+            ini_angle += 360.0;
+        }
+        while fin_angle <= ini_angle {
+            fin_angle += 360.0;
+        }
+        let mut segments = Vec::new();
+        let overshoot = 1.1 * radius;
+        for (x, y) in [(xa, ya), (xb, yb)] {
+            let length = f64::sqrt((x - xc) * (x - xc) + (y - yc) * (y - yc));
+            if length > 0.0 && length < radius {
+                let (ux, uy) = ((x - xc) / length, (y - yc) / length);
+                segments.push(vec![[x, y], [xc + overshoot * ux, yc + overshoot * uy]]);
+            }
+        }
+        if !segments.is_empty() {
+            self.open_polylines(&segments);
+        }
+        writeln!(
+            &mut self.buffer,
+            "h=tra.Affine2D().scale({}).translate({},{}).transform_path(pth.Path.arc({},{}))",
+            radius, xc, yc, ini_angle, fin_angle
+        )
+        .unwrap();
+        self.arrow_patch("pat.FancyArrowPatch(path=h", "<->");
+        let mid_angle = (ini_angle + fin_angle) / 2.0 * std::f64::consts::PI / 180.0;
+        let (x, y) = (xc + radius * f64::cos(mid_angle), yc + radius * f64::sin(mid_angle));
+        self.dimension_label(x, y, mid_angle * 180.0 / std::f64::consts::PI - 90.0, text);
+    }
+
+    /// Draws horizontal scale bar with a round length (2D only)
+    ///
+    /// The length of the bar is the largest round number (1, 2, or 5 times a power of ten) not
+    /// greater than `max_length`. The label, e.g., "500 m", is drawn above the center of the bar.
+    ///
+    /// # Input
+    ///
+    /// * `x, y` -- left end of the bar
+    /// * `max_length` -- maximum length of the bar
+    /// * `unit` -- unit written after the length (may be empty)
+    ///
+    /// # Output
+    ///
+    /// Returns the length of the bar.
+    ///
+    /// # Notes
+    ///
+    /// * The bar uses the shared and arrow options; the default arrow style is "|-|"
+    /// * The label uses the alternative text options (see [Canvas::set_alt_text_color])
+    pub fn draw_scale_bar(&mut self, x: f64, y: f64, max_length: f64, unit: &str) -> Result<f64, StrError> {
+        if max_length <= 0.0 {
+            return Err("max_length must be positive");
+        }
+        let exponent = f64::floor(f64::log10(max_length)) as i32;
+        let power = f64::powi(10.0, exponent);
+        let mantissa = if max_length >= 5.0 * power {
+            5.0
+        } else if max_length >= 2.0 * power {
+            2.0
+        } else {
+            1.0
+        };
+        let length = mantissa * power;
+        let number = if exponent < 0 {
+            format!("{:.*}", (-exponent) as usize, length)
+        } else {
+            format!("{}", length)
+        };
+        let label = if !unit.is_empty() {
+            format!("{} {}", number, unit)
+        } else {
+            number
+        };
+        let opt_shared = self.options_shared();
+        let opt_arrow = self.options_arrow("|-|");
+        let mut opt_text = String::new();
+        if self.coords != CoordSystem::Data {
+            write!(&mut opt_text, ",xycoords={}", self.coords.transform()).unwrap();
+        }
+        opt_text.push_str(&self.options_alt_text());
+        let fontsize = if self.alt_text_fontsize > 0.0 {
+            self.alt_text_fontsize
+        } else {
+            10.0
+        };
+        write!(
+            &mut self.buffer,
+            "p=pat.FancyArrowPatch(({},{}),({},{}),shrinkA=0,shrinkB=0{}{})\n\
+             plt.gca().add_patch(p)\n\
+             t=plt.annotate(r'{}',xy=({},{}),xytext=(0,{}),textcoords='offset points'{})\n\
+             add_to_ea(t)\n",
+            x,
+            y,
+            x + length,
+            y,
+            &opt_shared,
+            &opt_arrow,
+            label,
+            x + length / 2.0,
+            y,
+            fontsize,
+            &opt_text
+        )
+        .unwrap();
+        Ok(length)
+    }
+
+    /// Draws pinned support (structural symbol) (2D only)
+    ///
+    /// The support is a triangle with its apex at the point and a hatched ground line.
+    ///
+    /// # Input
+    ///
+    /// * `x, y` -- the supported point
+    /// * `size` -- width of the triangle (in data units)
+    /// * `angle` -- orientation in degrees: 0 places the support below the point, 90 to the right,
+    ///   180 above, and -90 to the left
+    pub fn draw_support_pinned(&mut self, x: f64, y: f64, size: f64, angle: f64) {
+        let a = angle * std::f64::consts::PI / 180.0;
+        let h = 0.8 * size;
+        let triangle = [
+            Canvas::local_point(x, y, a, 0.0, 0.0),
+            Canvas::local_point(x, y, a, -0.5 * size, -h),
+            Canvas::local_point(x, y, a, 0.5 * size, -h),
+        ];
+        self.draw_polyline(&triangle, true);
+        self.ground(x, y, a, -h, size);
+    }
+
+    /// Draws roller support (structural symbol) (2D only)
+    ///
+    /// The support is a triangle with its apex at the point, two rollers, and a hatched ground line.
+    /// See [Canvas::draw_support_pinned] for the input.
+    pub fn draw_support_roller(&mut self, x: f64, y: f64, size: f64, angle: f64) {
+        let a = angle * std::f64::consts::PI / 180.0;
+        let (h, r) = (0.6 * size, 0.1 * size);
+        let triangle = [
+            Canvas::local_point(x, y, a, 0.0, 0.0),
+            Canvas::local_point(x, y, a, -0.5 * size, -h),
+            Canvas::local_point(x, y, a, 0.5 * size, -h),
+        ];
+        self.draw_polyline(&triangle, true);
+        for u in [-0.25 * size, 0.25 * size] {
+            let c = Canvas::local_point(x, y, a, u, -h - r);
+            self.draw_circle(c[0], c[1], r);
+        }
+        self.ground(x, y, a, -h - 2.0 * r, size);
+    }
+
+    /// Draws fixed support (structural symbol) (2D only)
+    ///
+    /// The support is a hatched wall passing through the point.
+    /// See [Canvas::draw_support_pinned] for the input.
+    pub fn draw_support_fixed(&mut self, x: f64, y: f64, size: f64, angle: f64) {
+        let a = angle * std::f64::consts::PI / 180.0;
+        self.ground(x, y, a, 0.0, size);
+    }
+
+    /// Draws hinge (structural symbol) (2D only)
+    ///
+    /// The hinge is a circle with white face (regardless of the face color).
+    ///
+    /// # Input
+    ///
+    /// * `x, y` -- center
+    /// * `size` -- diameter of the circle (in data units)
+    pub fn draw_hinge(&mut self, x: f64, y: f64, size: f64) {
+        let opt = self.options_shared_fill("white");
+        write!(
+            &mut self.buffer,
+            "p=pat.Circle(({},{}),{}{})\n\
+             plt.gca().add_patch(p)\n",
+            x,
+            y,
+            0.5 * size,
+            &opt
+        )
+        .unwrap();
+    }
+
+    /// Draws point load (structural symbol) (2D only)
+    ///
+    /// The load is an arrow with its tip at the point. The arrow uses the shared and arrow options;
+    /// the default arrow style is "->".
+    ///
+    /// # Input
+    ///
+    /// * `x, y` -- the loaded point
+    /// * `length` -- length of the arrow (in data units)
+    /// * `angle` -- direction of the load in degrees; e.g., -90 for a downward load
+    pub fn draw_point_load(&mut self, x: f64, y: f64, length: f64, angle: f64) {
+        let a = angle * std::f64::consts::PI / 180.0;
+        let (xt, yt) = (x - length * f64::cos(a), y - length * f64::sin(a));
+        self.arrow_patch(
+            &format!("pat.FancyArrowPatch(({},{}),({},{}),shrinkA=0,shrinkB=0", xt, yt, x, y),
+            "->",
+        );
+    }
+
+    /// Draws distributed load (structural symbol) (2D only)
+    ///
+    /// The load is drawn on the left side of the direction from `a` to `b` with arrows pointing to the
+    /// segment and a line connecting the tails of the arrows. The intensity varies linearly along the segment.
+    /// Negative intensities are drawn on the right side. The arrows use the shared and arrow options
+    /// (the default arrow style is "->").
+    ///
+    /// # Input
+    ///
+    /// * `a` -- first point of the loaded segment
+    /// * `b` -- second point of the loaded segment
+    /// * `q` -- the load profile `[qa, qb]`, i.e., the lengths of the arrows (in data units) at `a` and `b`;
+    ///   the load is uniform if `qa = qb`, triangular if `qa` or `qb` is zero, and trapezoidal otherwise
+    /// * `narrow` -- number of arrows (≥ 2)
+    pub fn draw_distributed_load(
+        &mut self,
+        a: &[f64; 2],
+        b: &[f64; 2],
+        q: &[f64; 2],
+        narrow: usize,
+    ) -> Result<(), StrError> {
+        let ([xa, ya], [xb, yb], [qa, qb]) = (*a, *b, *q);
+        if narrow < 2 {
+            return Err("narrow must be ≥ 2");
+        }
+        let length = f64::sqrt((xb - xa) * (xb - xa) + (yb - ya) * (yb - ya));
+        if length == 0.0 {
+            return Err("the loaded segment is too short");
+        }
+        let (nx, ny) = (-(yb - ya) / length, (xb - xa) / length);
+        let tol = 1e-10 * f64::max(f64::abs(qa), f64::abs(qb));
+        for i in 0..narrow {
+            let t = (i as f64) / ((narrow - 1) as f64);
+            let (x, y) = (xa + t * (xb - xa), ya + t * (yb - ya));
+            let q = qa + t * (qb - qa);
+            if f64::abs(q) > tol {
+                self.arrow_patch(
+                    &format!(
+                        "pat.FancyArrowPatch(({},{}),({},{}),shrinkA=0,shrinkB=0",
+                        x + q * nx,
+                        y + q * ny,
+                        x,
+                        y
+                    ),
+                    "->",
+                );
+            }
+        }
+        self.open_polylines(&[vec![[xa + qa * nx, ya + qa * ny], [xb + qb * nx, yb + qb * ny]]]);
+        Ok(())
+    }
+
+    /// Draws moment (structural symbol) (2D only)
+    ///
+    /// The moment is an arc with an arrow head spanning 270° around the point. The arrow uses the
+    /// shared and arrow options; the default arrow style is "->".
+    ///
+    /// # Input
+    ///
+    /// * `x, y` -- center of the arc
+    /// * `radius` -- radius of the arc (in data units)
+    /// * `angle` -- direction (in degrees) of the opening of the arc
+    /// * `clockwise` -- direction of the moment
+    ///
+    /// **Note:** Use [crate::Plot::set_equal_axes] to obtain a circular arc.
+    pub fn draw_moment(&mut self, x: f64, y: f64, radius: f64, angle: f64, clockwise: bool) {
+        writeln!(
+            &mut self.buffer,
+            "h=tra.Affine2D().scale({}).translate({},{}).transform_path(pth.Path.arc({},{}))",
+            radius,
+            x,
+            y,
+            angle + 45.0,
+            angle + 315.0
+        )
+        .unwrap();
+        if clockwise {
+            writeln!(&mut self.buffer, "h=pth.Path(h.vertices[::-1],h.codes)").unwrap();
+        }
+        self.arrow_patch("pat.FancyArrowPatch(path=h", "->");
+    }
+
+    /// Draws spring (structural symbol) (2D only)
+    ///
+    /// The spring is a zigzag line between two points with straight leads at both ends.
+    ///
+    /// # Input
+    ///
+    /// * `xa, ya` -- first point
+    /// * `xb, yb` -- second point
+    /// * `ncoil` -- number of coils (≥ 1)
+    /// * `width` -- width of the zigzag (in data units)
+    pub fn draw_spring(
+        &mut self,
+        xa: f64,
+        ya: f64,
+        xb: f64,
+        yb: f64,
+        ncoil: usize,
+        width: f64,
+    ) -> Result<(), StrError> {
+        if ncoil < 1 {
+            return Err("ncoil must be ≥ 1");
+        }
+        let length = f64::sqrt((xb - xa) * (xb - xa) + (yb - ya) * (yb - ya));
+        if length == 0.0 {
+            return Err("the spring is too short");
+        }
+        let a = f64::atan2(yb - ya, xb - xa) + std::f64::consts::PI / 2.0;
+        let lead = 0.15 * length;
+        let nzig = 2 * ncoil;
+        let mut points = vec![[xa, ya], Canvas::local_point(xa, ya, a, 0.0, -lead)];
+        for k in 0..nzig {
+            let v = lead + (length - 2.0 * lead) * ((k as f64) + 0.5) / (nzig as f64);
+            let u = if k % 2 == 0 { 0.5 * width } else { -0.5 * width };
+            points.push(Canvas::local_point(xa, ya, a, u, -v));
+        }
+        points.push(Canvas::local_point(xa, ya, a, 0.0, -(length - lead)));
+        points.push([xb, yb]);
+        self.open_polylines(&[points]);
+        Ok(())
+    }
+
     /// Begins drawing a polycurve (straight segments, quadratic Bezier, and cubic Bezier) (2D only)
     ///
     /// # Warning
@@ -287,12 +951,17 @@ impl Canvas {
     /// Draws polyline with straight segments, quadratic Bezier, or cubic Bezier (2D only)
     ///
     /// **Note:** The first and last commands are ignored.
+    ///
+    /// Nothing is drawn if `points` is empty (e.g., an empty region returned by [crate::Polygon::get_polycurve]).
     pub fn draw_polycurve<'a, T, U>(&mut self, points: &'a T, codes: &[PolyCode], closed: bool) -> Result<(), StrError>
     where
         T: AsMatrix<'a, U>,
         U: 'a + std::fmt::Display,
     {
         let (npoint, ndim) = points.size();
+        if npoint == 0 {
+            return Ok(());
+        }
         if npoint < 3 {
             return Err("npoint must be ≥ 3");
         }
@@ -379,6 +1048,198 @@ impl Canvas {
         self
     }
 
+    /// Draws filled polygon (3D)
+    ///
+    /// The polygon uses the face color, edge color, line width, line style, and alpha (shared options).
+    ///
+    /// # Input
+    ///
+    /// * `points` -- (npoint ≥ 3, 3) coordinates of the vertices (preferably on a plane)
+    ///
+    /// **Note:** The axes limits are not updated; thus, use [crate::Plot::set_range_3d] if needed.
+    pub fn draw_polygon_3d<'a, T, U>(&mut self, points: &'a T) -> Result<(), StrError>
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + std::fmt::Display,
+    {
+        let (npoint, ndim) = points.size();
+        if npoint < 3 {
+            return Err("npoint must be ≥ 3");
+        }
+        if ndim != 3 {
+            return Err("ndim must be equal to 3");
+        }
+        write!(&mut self.buffer, "maybe_create_ax3d()\nxyz=np.array([").unwrap();
+        for i in 0..npoint {
+            write!(
+                &mut self.buffer,
+                "[{},{},{}],",
+                points.at(i, 0),
+                points.at(i, 1),
+                points.at(i, 2)
+            )
+            .unwrap();
+        }
+        let opt = self.options_polygon_3d();
+        write!(
+            &mut self.buffer,
+            "])\n\
+             p=m3d.art3d.Poly3DCollection([xyz]{})\n\
+             AX3D.add_collection3d(p)\n",
+            &opt
+        )
+        .unwrap();
+        Ok(())
+    }
+
+    /// Draws arrow with a cone head (3D)
+    ///
+    /// The shaft uses the edge color, line width, and line style; the cone uses the edge color.
+    /// See [Canvas::set_arrow_head_3d] to set the size of the cone.
+    ///
+    /// # Input
+    ///
+    /// * `a` -- (len=3) tail point
+    /// * `b` -- (len=3) tip point
+    pub fn draw_arrow_3d(&mut self, a: &[f64], b: &[f64]) -> Result<(), StrError> {
+        if a.len() != 3 {
+            return Err("a.len() must equal to 3");
+        }
+        if b.len() != 3 {
+            return Err("b.len() must equal to 3");
+        }
+        let (e0, e1, e2) = Surface::aligned_system(a, b)?;
+        let length =
+            f64::sqrt((b[0] - a[0]) * (b[0] - a[0]) + (b[1] - a[1]) * (b[1] - a[1]) + (b[2] - a[2]) * (b[2] - a[2]));
+        let head_length = if self.arrow_head_length > 0.0 {
+            f64::min(self.arrow_head_length, length)
+        } else {
+            0.2 * length
+        };
+        let head_radius = if self.arrow_head_radius > 0.0 {
+            self.arrow_head_radius
+        } else {
+            0.3 * head_length
+        };
+        let m: Vec<f64> = (0..3).map(|k| b[k] - head_length * e0[k]).collect();
+
+        // shaft
+        let opt_line = self.options_line_3d();
+        write!(
+            &mut self.buffer,
+            "maybe_create_ax3d()\n\
+             AX3D.plot([{},{}],[{},{}],[{},{}]{})\n",
+            a[0], m[0], a[1], m[1], a[2], m[2], &opt_line
+        )
+        .unwrap();
+
+        // cone (rows: tip, base perimeter, base center)
+        let mut x = vec![vec![0.0; NDIV_CIRCLE_3D + 1]; 3];
+        let mut y = vec![vec![0.0; NDIV_CIRCLE_3D + 1]; 3];
+        let mut z = vec![vec![0.0; NDIV_CIRCLE_3D + 1]; 3];
+        for j in 0..(NDIV_CIRCLE_3D + 1) {
+            let p = Canvas::point_on_circle(&m, &e1, &e2, head_radius, j);
+            for (i, q) in [b, &p, &m].iter().enumerate() {
+                x[i][j] = q[0];
+                y[i][j] = q[1];
+                z[i][j] = q[2];
+            }
+        }
+        matrix_to_array(&mut self.buffer, "x", &x);
+        matrix_to_array(&mut self.buffer, "y", &y);
+        matrix_to_array(&mut self.buffer, "z", &z);
+        let mut opt = String::new();
+        if !self.edge_color.is_empty() {
+            write!(&mut opt, ",color='{}'", self.edge_color).unwrap();
+        }
+        if self.alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.alpha).unwrap();
+        }
+        writeln!(&mut self.buffer, "AX3D.plot_surface(x,y,z,linewidth=0{})", &opt).unwrap();
+        Ok(())
+    }
+
+    /// Draws circle on an arbitrary plane (3D)
+    ///
+    /// The circle uses the edge color, line width, and line style (shared options).
+    ///
+    /// # Input
+    ///
+    /// * `c` -- (len=3) center
+    /// * `n` -- (len=3) vector normal to the plane of the circle (not necessarily unitary)
+    /// * `r` -- radius
+    pub fn draw_circle_3d(&mut self, c: &[f64], n: &[f64], r: f64) -> Result<(), StrError> {
+        let (e1, e2) = Canvas::circle_system(c, n, r)?;
+        self.polyline_3d_begin();
+        for j in 0..(NDIV_CIRCLE_3D + 1) {
+            let p = Canvas::point_on_circle(c, &e1, &e2, r, j);
+            self.polyline_3d_add(p[0], p[1], p[2]);
+        }
+        self.polyline_3d_end();
+        Ok(())
+    }
+
+    /// Draws disk (filled circle) on an arbitrary plane (3D)
+    ///
+    /// The disk is drawn as in [Canvas::draw_polygon_3d]. See [Canvas::draw_circle_3d] for the input.
+    pub fn draw_disk_3d(&mut self, c: &[f64], n: &[f64], r: f64) -> Result<(), StrError> {
+        let (e1, e2) = Canvas::circle_system(c, n, r)?;
+        let points: Vec<Vec<f64>> = (0..NDIV_CIRCLE_3D)
+            .map(|j| Canvas::point_on_circle(c, &e1, &e2, r, j))
+            .collect();
+        self.draw_polygon_3d(&points)
+    }
+
+    /// Draws text (3D)
+    ///
+    /// The text always faces the viewer (billboard) and uses the text options (see [Canvas::set_text_color]).
+    pub fn draw_text_3d(&mut self, x: f64, y: f64, z: f64, text: &str) {
+        writeln!(&mut self.buffer, "maybe_create_ax3d()").unwrap();
+        self.text(3, &[x, y, z], text, false);
+    }
+
+    /// Draws polygon with holes (2D only)
+    ///
+    /// The outer boundary is drawn counterclockwise and the holes clockwise (regardless of the
+    /// orientation of the input points); thus, the holes show through the filled region.
+    ///
+    /// # Input
+    ///
+    /// * `outer` -- (npoint ≥ 3, 2) coordinates of the outer boundary
+    /// * `holes` -- list of (npoint ≥ 3, 2) coordinates of the holes (inside the outer boundary)
+    ///
+    /// **Note:** The last point of each boundary may repeat the first one. See also [crate::Polygon].
+    pub fn draw_polygon_with_holes<'a, T, U>(&mut self, outer: &'a T, holes: &'a [T]) -> Result<(), StrError>
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + Into<f64>,
+    {
+        let mut rings = vec![oriented_ring(ring_from_matrix(outer)?, true)];
+        for hole in holes {
+            rings.push(oriented_ring(ring_from_matrix(hole)?, false));
+        }
+        write!(&mut self.buffer, "dat=[").unwrap();
+        for ring in &rings {
+            write!(&mut self.buffer, "[pth.Path.MOVETO,({},{})],", ring[0][0], ring[0][1]).unwrap();
+            for p in ring.iter().skip(1) {
+                write!(&mut self.buffer, "[pth.Path.LINETO,({},{})],", p[0], p[1]).unwrap();
+            }
+            write!(&mut self.buffer, "[pth.Path.CLOSEPOLY,(None,None)],").unwrap();
+        }
+        let opt = self.options_shared();
+        write!(
+            &mut self.buffer,
+            "]\n\
+            cmd,pts=zip(*dat)\n\
+            h=pth.Path(pts,cmd)\n\
+            p=pat.PathPatch(h{})\n\
+            plt.gca().add_patch(p)\n",
+            &opt
+        )
+        .unwrap();
+        Ok(())
+    }
+
     /// Draws polyline (2D or 3D)
     pub fn draw_polyline<'a, T, U>(&mut self, points: &'a T, closed: bool)
     where
@@ -581,14 +1442,14 @@ impl Canvas {
     }
 
     /// Sets the edge color (shared among features)
-    pub fn set_edge_color(&mut self, color: &str) -> &mut Self {
-        self.edge_color = String::from(color);
+    pub fn set_edge_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.edge_color = color_to_spec(color, &mut self.error);
         self
     }
 
     /// Sets the face color (shared among features)
-    pub fn set_face_color(&mut self, color: &str) -> &mut Self {
-        self.face_color = String::from(color);
+    pub fn set_face_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.face_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -598,6 +1459,40 @@ impl Canvas {
         self
     }
 
+    /// Sets the line style of edge (shared among features)
+    pub fn set_line_style(&mut self, style: impl Into<LineStyle>) -> &mut Self {
+        self.line_style = style.into().to_option();
+        self
+    }
+
+    /// Sets the hatch pattern (shared among features)
+    ///
+    /// Options: "`/`", "`\`", "`|`", "`-`", "`+`", "`x`", "`o`", "`O`", "`.`", "`*`".
+    /// Repeating a symbol increases the density; e.g., "`///`". Combinations such as "`/o`" are also possible.
+    /// An empty string means no hatch.
+    ///
+    /// **Note:** The hatch is drawn with the edge color.
+    pub fn set_hatch(&mut self, hatch: &str) -> &mut Self {
+        self.hatch = hatch.replace('\\', "\\\\");
+        self
+    }
+
+    /// Sets the opacity in (0, 1] (shared among features)
+    ///
+    /// A zero value means that the opacity is not set (i.e., the Matplotlib default is used).
+    pub fn set_alpha(&mut self, alpha: f64) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Sets the drawing order (shared among 2D features)
+    ///
+    /// Features with higher zorder are drawn on top of features with lower zorder.
+    pub fn set_zorder(&mut self, zorder: f64) -> &mut Self {
+        self.zorder = Some(zorder);
+        self
+    }
+
     /// Sets the arrow scale
     pub fn set_arrow_scale(&mut self, scale: f64) -> &mut Self {
         self.arrow_scale = scale;
@@ -606,7 +1501,7 @@ impl Canvas {
 
     /// Sets the arrow style
     ///
-    /// Options:
+    /// Options (Matplotlib name -- [ArrowStyle] variant : default parameters):
     ///
     /// * "`-`"      -- Curve         : None
     /// * "`->`"     -- CurveB        : head_length=0.4,head_width=0.2
@@ -618,35 +1513,46 @@ impl Canvas {
     /// * "`<|-|>`"  -- CurveFilledAB : head_length=0.4,head_width=0.2
     /// * "`]-`"     -- BracketA      : widthA=1.0,lengthA=0.2,angleA=None
     /// * "`]-[`"    -- BracketAB     : widthA=1.0,lengthA=0.2,angleA=None,widthB=1.0,lengthB=0.2,angleB=None
+    /// * "`]->`"    -- BracketCurve  : widthA=1.0,lengthA=0.2,angleA=None
+    /// * "`<-[`"    -- CurveBracket  : widthB=1.0,lengthB=0.2,angleB=None
     /// * "`fancy`"  -- Fancy         : head_length=0.4,head_width=0.4,tail_width=0.4
     /// * "`simple`" -- Simple        : head_length=0.5,head_width=0.5,tail_width=0.2
     /// * "`wedge`"  -- Wedge         : tail_width=0.3,shrink_factor=0.5
     /// * "`|-|`"    -- BarAB         : widthA=1.0,angleA=None,widthB=1.0,angleB=None
     /// * As defined in <https://matplotlib.org/stable/api/_as_gen/matplotlib.patches.FancyArrowPatch.html>
-    pub fn set_arrow_style(&mut self, style: &str) -> &mut Self {
-        self.arrow_style = String::from(style);
+    pub fn set_arrow_style(&mut self, style: ArrowStyle) -> &mut Self {
+        self.arrow_style = style.to_string();
+        self
+    }
+
+    /// Sets the length and radius of the cone head of 3D arrows
+    ///
+    /// A zero length means 20% of the arrow length and a zero radius means 30% of the head length.
+    pub fn set_arrow_head_3d(&mut self, length: f64, radius: f64) -> &mut Self {
+        self.arrow_head_length = length;
+        self.arrow_head_radius = radius;
         self
     }
 
     /// Sets the text color
-    pub fn set_text_color(&mut self, color: &str) -> &mut Self {
-        self.text_color = String::from(color);
+    pub fn set_text_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.text_color = color_to_spec(color, &mut self.error);
         self
     }
 
     /// Sets the text horizontal alignment
     ///
-    /// Options: "center", "left", "right"
-    pub fn set_text_align_horizontal(&mut self, option: &str) -> &mut Self {
-        self.text_align_horizontal = String::from(option);
+    /// Options: [HAlign::Center], [HAlign::Left], [HAlign::Right]
+    pub fn set_text_align_horizontal(&mut self, option: HAlign) -> &mut Self {
+        self.text_align_horizontal = option.to_string();
         self
     }
 
     /// Sets the text vertical alignment
     ///
-    /// Options: "center", "top", "bottom", "baseline", "center_baseline"
-    pub fn set_text_align_vertical(&mut self, option: &str) -> &mut Self {
-        self.text_align_vertical = String::from(option);
+    /// Options: [VAlign::Center], [VAlign::Top], [VAlign::Bottom], [VAlign::Baseline], [VAlign::CenterBaseline]
+    pub fn set_text_align_vertical(&mut self, option: VAlign) -> &mut Self {
+        self.text_align_vertical = option.to_string();
         self
     }
 
@@ -663,24 +1569,24 @@ impl Canvas {
     }
 
     /// Sets the alternative text color
-    pub fn set_alt_text_color(&mut self, color: &str) -> &mut Self {
-        self.alt_text_color = String::from(color);
+    pub fn set_alt_text_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.alt_text_color = color_to_spec(color, &mut self.error);
         self
     }
 
     /// Sets the alternative text horizontal alignment
     ///
-    /// Options: "center", "left", "right"
-    pub fn set_alt_text_align_horizontal(&mut self, option: &str) -> &mut Self {
-        self.alt_text_align_horizontal = String::from(option);
+    /// Options: [HAlign::Center], [HAlign::Left], [HAlign::Right]
+    pub fn set_alt_text_align_horizontal(&mut self, option: HAlign) -> &mut Self {
+        self.alt_text_align_horizontal = option.to_string();
         self
     }
 
     /// Sets the alternative text vertical alignment
     ///
-    /// Options: "center", "top", "bottom", "baseline", "center_baseline"
-    pub fn set_alt_text_align_vertical(&mut self, option: &str) -> &mut Self {
-        self.alt_text_align_vertical = String::from(option);
+    /// Options: [VAlign::Center], [VAlign::Top], [VAlign::Bottom], [VAlign::Baseline], [VAlign::CenterBaseline]
+    pub fn set_alt_text_align_vertical(&mut self, option: VAlign) -> &mut Self {
+        self.alt_text_align_vertical = option.to_string();
         self
     }
 
@@ -702,32 +1608,168 @@ impl Canvas {
         self
     }
 
+    /// Sets the coordinate system of 2D features (e.g., arc, arrow, circle, polycurve, and polyline)
+    ///
+    /// Options: [CoordSystem::Data], [CoordSystem::Axes], [CoordSystem::Figure], [CoordSystem::XDataYAxes], [CoordSystem::OffsetPoints]
+    ///
+    /// For instance, a frame around the whole axes may be drawn as a polyline in axes coordinates.
+    ///
+    /// **Note:** The limits of the axes are not adjusted by features given in other than data coordinates.
+    pub fn set_coords(&mut self, coords: CoordSystem) -> &mut Self {
+        self.coords = coords;
+        self
+    }
+
     /// Returns shared options
     fn options_shared(&self) -> String {
+        self.options_shared_fill(&self.face_color)
+    }
+
+    /// Returns the shared options with the given face color (e.g., for symbols with a fixed fill)
+    fn options_shared_fill(&self, face_color: &str) -> String {
+        let mut opt = self.options_patch_fill(face_color);
+        if self.coords != CoordSystem::Data {
+            write!(&mut opt, ",transform={}", self.coords.transform()).unwrap();
+        }
+        opt
+    }
+
+    /// Returns the shared options, except the coordinate system (e.g., for 3D patches)
+    fn options_patch(&self) -> String {
+        self.options_patch_fill(&self.face_color)
+    }
+
+    /// Returns the shared options with the given face color, except the coordinate system
+    fn options_patch_fill(&self, face_color: &str) -> String {
         let mut opt = String::new();
         if self.edge_color != "" {
             write!(&mut opt, ",edgecolor='{}'", self.edge_color).unwrap();
         }
-        if self.face_color != "" {
-            write!(&mut opt, ",facecolor='{}'", self.face_color).unwrap();
+        if !face_color.is_empty() {
+            write!(&mut opt, ",facecolor='{}'", face_color).unwrap();
         }
         if self.line_width > 0.0 {
             write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
         }
+        if !self.line_style.is_empty() {
+            write!(&mut opt, ",linestyle={}", self.line_style).unwrap();
+        }
+        if !self.hatch.is_empty() {
+            write!(&mut opt, ",hatch='{}'", self.hatch).unwrap();
+        }
+        if self.alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.alpha).unwrap();
+        }
+        if let Some(zorder) = self.zorder {
+            write!(&mut opt, ",zorder={}", zorder).unwrap();
+        }
         if self.stop_clip {
             write!(&mut opt, ",clip_on=False").unwrap();
         }
         opt
     }
 
+    /// Returns the global coordinates of a point given in a local system centered at (x, y) and rotated by `angle` (radians)
+    fn local_point(x: f64, y: f64, angle: f64, u: f64, v: f64) -> [f64; 2] {
+        let (cos, sin) = (f64::cos(angle), f64::sin(angle));
+        [x + u * cos - v * sin, y + u * sin + v * cos]
+    }
+
+    /// Draws a hatched ground line (in the local system) at the vertical coordinate v with the hatch below it
+    fn ground(&mut self, x: f64, y: f64, angle: f64, v: f64, size: f64) {
+        const NTICK: usize = 7;
+        let (w, t) = (0.75 * size, 0.2 * size);
+        let mut polylines = vec![vec![
+            Canvas::local_point(x, y, angle, -w, v),
+            Canvas::local_point(x, y, angle, w, v),
+        ]];
+        for i in 0..NTICK {
+            let u = -w + t + (i as f64) * (2.0 * w - t) / ((NTICK - 1) as f64);
+            polylines.push(vec![
+                Canvas::local_point(x, y, angle, u, v),
+                Canvas::local_point(x, y, angle, u - t, v - t),
+            ]);
+        }
+        self.open_polylines(&polylines);
+    }
+
+    /// Draws open polylines without filling (e.g., extension lines)
+    fn open_polylines(&mut self, polylines: &[Vec<[f64; 2]>]) {
+        write!(&mut self.buffer, "dat=[").unwrap();
+        for polyline in polylines {
+            for (i, p) in polyline.iter().enumerate() {
+                let keyword = if i == 0 { "MOVETO" } else { "LINETO" };
+                write!(&mut self.buffer, "[pth.Path.{},({},{})],", keyword, p[0], p[1]).unwrap();
+            }
+        }
+        let opt = self.options_shared();
+        write!(
+            &mut self.buffer,
+            "]\n\
+            cmd,pts=zip(*dat)\n\
+            h=pth.Path(pts,cmd)\n\
+            p=pat.PathPatch(h,fill=False{})\n\
+            plt.gca().add_patch(p)\n",
+            &opt
+        )
+        .unwrap();
+    }
+
+    /// Completes an arrow patch given its first arguments and the default arrow style
+    fn arrow_patch(&mut self, patch: &str, default_style: &str) {
+        let opt_shared = self.options_shared();
+        let opt_arrow = self.options_arrow(default_style);
+        write!(
+            &mut self.buffer,
+            "p={}{}{})\n\
+             plt.gca().add_patch(p)\n",
+            patch, &opt_shared, &opt_arrow
+        )
+        .unwrap();
+    }
+
+    /// Draws the label of a dimension centered at a point and rotated by an angle (in degrees)
+    ///
+    /// The angle is adjusted such that the label is never upside down. A box with the color of the
+    /// axes background hides the dimension line behind the label.
+    fn dimension_label(&mut self, x: f64, y: f64, angle: f64, text: &str) {
+        let mut opt = self.options_alt_text();
+        if self.alt_text_rotation <= 0.0 {
+            let mut rotation = angle % 360.0;
+            if rotation > 180.0 {
+                rotation -= 360.0;
+            } else if rotation <= -180.0 {
+                rotation += 360.0;
+            }
+            if rotation > 90.0 {
+                rotation -= 180.0;
+            } else if rotation <= -90.0 {
+                rotation += 180.0;
+            }
+            write!(&mut opt, ",rotation={},rotation_mode='anchor'", rotation).unwrap();
+        }
+        if self.coords != CoordSystem::Data {
+            write!(&mut opt, ",transform={}", self.coords.transform()).unwrap();
+        }
+        write!(
+            &mut self.buffer,
+            "t=plt.text({},{},r'{}'{},bbox=dict(boxstyle='square,pad=0.1',facecolor=plt.gca().get_facecolor(),edgecolor='none'))\n\
+             add_to_ea(t)\n",
+            x, y, text, &opt
+        )
+        .unwrap();
+    }
+
     /// Returns options for arrows
-    fn options_arrow(&self) -> String {
+    fn options_arrow(&self, default_style: &str) -> String {
         let mut opt = String::new();
         if self.arrow_scale > 0.0 {
             write!(&mut opt, ",mutation_scale={}", self.arrow_scale).unwrap();
         }
         if self.arrow_style != "" {
             write!(&mut opt, ",arrowstyle='{}'", self.arrow_style).unwrap();
+        } else if !default_style.is_empty() {
+            write!(&mut opt, ",arrowstyle='{}'", default_style).unwrap();
         }
         opt
     }
@@ -774,6 +1816,50 @@ impl Canvas {
         opt
     }
 
+    /// Returns options for 3D polygons
+    fn options_polygon_3d(&self) -> String {
+        let mut opt = String::new();
+        if !self.face_color.is_empty() {
+            write!(&mut opt, ",facecolor='{}'", self.face_color).unwrap();
+        }
+        if !self.edge_color.is_empty() {
+            write!(&mut opt, ",edgecolor='{}'", self.edge_color).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
+        }
+        if !self.line_style.is_empty() {
+            write!(&mut opt, ",linestyle={}", self.line_style).unwrap();
+        }
+        if self.alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.alpha).unwrap();
+        }
+        opt
+    }
+
+    /// Returns the unit vectors (e1, e2) on the plane of a 3D circle
+    fn circle_system(c: &[f64], n: &[f64], r: f64) -> Result<(Vec<f64>, Vec<f64>), StrError> {
+        if c.len() != 3 {
+            return Err("c.len() must equal to 3");
+        }
+        if n.len() != 3 {
+            return Err("n.len() must equal to 3");
+        }
+        if r <= 0.0 {
+            return Err("the radius must be positive");
+        }
+        let b = vec![c[0] + n[0], c[1] + n[1], c[2] + n[2]];
+        let (_, e1, e2) = Surface::aligned_system(c, &b).map_err(|_| "the normal vector must not be zero")?;
+        Ok((e1, e2))
+    }
+
+    /// Returns the j-th point on a 3D circle with center c on the plane spanned by (e1, e2)
+    fn point_on_circle(c: &[f64], e1: &[f64], e2: &[f64], r: f64, j: usize) -> Vec<f64> {
+        let alpha = (j as f64) * 2.0 * std::f64::consts::PI / (NDIV_CIRCLE_3D as f64);
+        let (cos, sin) = (f64::cos(alpha), f64::sin(alpha));
+        (0..3).map(|k| c[k] + r * cos * e1[k] + r * sin * e2[k]).collect()
+    }
+
     /// Returns options for 3D line
     fn options_line_3d(&self) -> String {
         let mut opt = String::new();
@@ -783,6 +1869,12 @@ impl Canvas {
         if self.line_width > 0.0 {
             write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
         }
+        if !self.line_style.is_empty() {
+            write!(&mut opt, ",linestyle={}", self.line_style).unwrap();
+        }
+        if self.alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.alpha).unwrap();
+        }
         opt
     }
 
@@ -825,6 +1917,35 @@ impl Canvas {
         }
     }
 
+    /// Returns the orientation option of regular polygons given the rotation angle in degrees
+    fn orientation(angle: f64) -> String {
+        if angle != 0.0 {
+            format!(",orientation=np.radians({})", angle)
+        } else {
+            String::new()
+        }
+    }
+
+    /// Adds a 2D patch to a plane perpendicular to the `axis` at `position` (3D)
+    fn add_patch_3d(&mut self, patch: &str, axis: usize, position: f64) -> Result<(), StrError> {
+        let zdir = match axis {
+            0 => "x",
+            1 => "y",
+            2 => "z",
+            _ => return Err("axis must be 0, 1, or 2"),
+        };
+        write!(
+            &mut self.buffer,
+            "maybe_create_ax3d()\n\
+             p={}\n\
+             AX3D.add_patch(p)\n\
+             m3d.art3d.pathpatch_2d_to_3d(p,z={},zdir='{}')\n",
+            patch, position, zdir
+        )
+        .unwrap();
+        Ok(())
+    }
+
     /// Adjust 2D or 3D limits
     fn limits(&mut self, ndim: usize, xmin: &[f64], xmax: &[f64]) {
         const FACTOR: f64 = 0.1;
@@ -867,6 +1988,9 @@ impl GraphMaker for Canvas {
     fn clear_buffer(&mut self) {
         self.buffer.clear();
     }
+    fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -874,7 +1998,7 @@ impl GraphMaker for Canvas {
 #[cfg(test)]
 mod tests {
     use super::{Canvas, StrError};
-    use crate::{GraphMaker, PolyCode};
+    use crate::{ArrowStyle, CoordSystem, GraphMaker, HAlign, LineStyle, PolyCode, VAlign};
 
     #[test]
     fn derive_works() {
@@ -891,6 +2015,10 @@ mod tests {
         assert_eq!(canvas.edge_color.len(), 7);
         assert_eq!(canvas.face_color.len(), 0);
         assert_eq!(canvas.line_width, 0.0);
+        assert_eq!(canvas.line_style.len(), 0);
+        assert_eq!(canvas.hatch.len(), 0);
+        assert_eq!(canvas.alpha, 0.0);
+        assert_eq!(canvas.zorder, None);
         assert_eq!(canvas.arrow_scale, 0.0);
         assert_eq!(canvas.arrow_style.len(), 0);
         assert_eq!(canvas.text_color.len(), 7);
@@ -917,18 +2045,40 @@ mod tests {
              ,linewidth=2.5\
              ,clip_on=False"
         );
+        canvas.set_coords(CoordSystem::Figure);
+        assert_eq!(
+            canvas.options_shared(),
+            ",edgecolor='red',facecolor='blue',linewidth=2.5,clip_on=False,transform=plt.gcf().transFigure"
+        );
+        canvas
+            .set_coords(CoordSystem::Data)
+            .set_stop_clip(false)
+            .set_line_style(LineStyle::Dashed)
+            .set_hatch("\\\\")
+            .set_alpha(0.5)
+            .set_zorder(-1.0);
+        assert_eq!(
+            canvas.options_shared(),
+            ",edgecolor='red',facecolor='blue',linewidth=2.5,linestyle='--',hatch='\\\\\\\\',alpha=0.5,zorder=-1"
+        );
     }
 
     #[test]
     fn options_arrow_works() {
         let mut canvas = Canvas::new();
-        canvas.set_arrow_scale(25.0).set_arrow_style("fancy");
-        let opt = canvas.options_arrow();
+        canvas
+            .set_arrow_scale(25.0)
+            .set_arrow_style(ArrowStyle::Fancy(0.4, 0.4, 0.4));
+        let opt = canvas.options_arrow("");
         assert_eq!(
             opt,
             ",mutation_scale=25\
              ,arrowstyle='fancy'"
         );
+        assert_eq!(canvas.options_arrow("<->"), ",mutation_scale=25,arrowstyle='fancy'");
+        let canvas = Canvas::new();
+        assert_eq!(canvas.options_arrow(""), "");
+        assert_eq!(canvas.options_arrow("<->"), ",arrowstyle='<->'");
     }
 
     #[test]
@@ -936,8 +2086,8 @@ mod tests {
         let mut canvas = Canvas::new();
         canvas
             .set_text_color("red")
-            .set_text_align_horizontal("center")
-            .set_text_align_vertical("center")
+            .set_text_align_horizontal(HAlign::Center)
+            .set_text_align_vertical(VAlign::Center)
             .set_text_fontsize(8.0)
             .set_text_rotation(45.0);
         let opt = canvas.options_text();
@@ -956,8 +2106,8 @@ mod tests {
         let mut canvas = Canvas::new();
         canvas
             .set_alt_text_color("blue")
-            .set_alt_text_align_horizontal("right")
-            .set_alt_text_align_vertical("bottom")
+            .set_alt_text_align_horizontal(HAlign::Right)
+            .set_alt_text_align_vertical(VAlign::Bottom)
             .set_alt_text_fontsize(10.0)
             .set_alt_text_rotation(30.0);
         let opt = canvas.options_alt_text();
@@ -1057,6 +2207,417 @@ mod tests {
         assert_eq!(canvas.buffer, b);
     }
 
+    #[test]
+    fn rectangle_works() {
+        let mut canvas = Canvas::new();
+        canvas.draw_rectangle(0.0, 0.0, 2.0, 1.0, 30.0, 0.0);
+        let b: &str = "p=pat.Rectangle((0,0),2,1,angle=30,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n";
+        assert_eq!(canvas.buffer, b);
+        canvas.clear_buffer();
+        canvas.draw_rectangle(1.0, 2.0, 2.0, 1.0, 45.0, 0.25);
+        let b: &str = "p=pat.FancyBboxPatch((1,2),2,1,boxstyle='round,pad=0,rounding_size=0.25',edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p.set_transform(tra.Affine2D().rotate_deg_around(1,2,45)+p.get_transform())\n";
+        assert_eq!(canvas.buffer, b);
+    }
+
+    #[test]
+    fn ellipse_wedge_annulus_work() {
+        let mut canvas = Canvas::new();
+        canvas.draw_ellipse(0.0, 0.0, 2.0, 1.0, 15.0);
+        canvas.draw_wedge(0.0, 0.0, 1.0, 30.0, 60.0);
+        canvas.draw_annulus(0.0, 0.0, 0.5, 1.0).unwrap();
+        let b: &str = "p=pat.Ellipse((0,0),2,1,angle=15,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.Wedge((0,0),1,30,60,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.Wedge((0,0),1,0,360,width=1-0.5,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n";
+        assert_eq!(canvas.buffer, b);
+        canvas.clear_buffer();
+        assert_eq!(
+            canvas.draw_annulus(0.0, 0.0, 1.0, 0.5).err(),
+            Some("the inner radius of the annulus must not be greater than the outer radius")
+        );
+        assert_eq!(canvas.buffer, "");
+        canvas.draw_rectangle(0, 0, 2, 1, 0.0, 0.0);
+        canvas.draw_ellipse(0, 0, 2, 1, 15);
+        canvas.draw_wedge(0, 0, 1, 30, 60);
+        canvas.draw_annulus(0, 0, 1, 2).unwrap();
+        canvas.draw_regular_polygon(0, 0, 1, 6, 0.0);
+        canvas.draw_fancy_box(0, 0, 2, 1, "round");
+        let b: &str = "p=pat.Rectangle((0,0),2,1,angle=0,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.Ellipse((0,0),2,1,angle=15,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.Wedge((0,0),1,30,60,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.Wedge((0,0),2,0,360,width=2-1,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.RegularPolygon((0,0),6,radius=1,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.FancyBboxPatch((0,0),2,1,boxstyle='round',edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n";
+        assert_eq!(canvas.buffer, b);
+    }
+
+    #[test]
+    fn regular_polygon_and_fancy_box_work() {
+        let mut canvas = Canvas::new();
+        canvas.draw_regular_polygon(0.0, 0.0, 1.0, 6, 0.0);
+        canvas.draw_regular_polygon(0.0, 0.0, 1.0, 5, 90.0);
+        canvas.draw_fancy_box(0.0, 0.0, 2.0, 1.0, "sawtooth,pad=0.1");
+        let b: &str = "p=pat.RegularPolygon((0,0),6,radius=1,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.RegularPolygon((0,0),5,radius=1,orientation=np.radians(90),edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.FancyBboxPatch((0,0),2,1,boxstyle='sawtooth,pad=0.1',edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n";
+        assert_eq!(canvas.buffer, b);
+    }
+
+    #[test]
+    fn shapes_3d_work() {
+        let mut canvas = Canvas::new();
+        canvas.set_coords(CoordSystem::Axes);
+        canvas.draw_rectangle_3d(0.0, 0.0, 2.0, 1.0, 0, 0.5).unwrap();
+        canvas.draw_ellipse_3d(0.0, 0.0, 2.0, 1.0, 1, 1.0).unwrap();
+        canvas.draw_regular_polygon_3d(0.0, 0.0, 1.0, 3, 2, -1.0).unwrap();
+        let b: &str = "maybe_create_ax3d()\n\
+                       p=pat.Rectangle((0,0),2,1,edgecolor='#427ce5')\n\
+                       AX3D.add_patch(p)\n\
+                       m3d.art3d.pathpatch_2d_to_3d(p,z=0.5,zdir='x')\n\
+                       maybe_create_ax3d()\n\
+                       p=pat.Ellipse((0,0),2,1,edgecolor='#427ce5')\n\
+                       AX3D.add_patch(p)\n\
+                       m3d.art3d.pathpatch_2d_to_3d(p,z=1,zdir='y')\n\
+                       maybe_create_ax3d()\n\
+                       p=pat.RegularPolygon((0,0),3,radius=1,edgecolor='#427ce5')\n\
+                       AX3D.add_patch(p)\n\
+                       m3d.art3d.pathpatch_2d_to_3d(p,z=-1,zdir='z')\n";
+        assert_eq!(canvas.buffer, b);
+        assert_eq!(
+            canvas.draw_rectangle_3d(0.0, 0.0, 1.0, 1.0, 3, 0.0).err(),
+            Some("axis must be 0, 1, or 2")
+        );
+    }
+
+    #[test]
+    fn polygon_with_holes_works() {
+        let mut canvas = Canvas::new();
+        let outer = vec![vec![0.0, 0.0], vec![0.0, 3.0], vec![3.0, 3.0], vec![3.0, 0.0]]; // clockwise
+        let holes = [vec![vec![1.0, 1.0], vec![2.0, 1.0], vec![2.0, 2.0], vec![1.0, 1.0]]]; // counterclockwise
+        canvas.draw_polygon_with_holes(&outer, &holes).unwrap();
+        let b: &str = "dat=[[pth.Path.MOVETO,(3,0)],[pth.Path.LINETO,(3,3)],[pth.Path.LINETO,(0,3)],[pth.Path.LINETO,(0,0)],[pth.Path.CLOSEPOLY,(None,None)],\
+                       [pth.Path.MOVETO,(2,2)],[pth.Path.LINETO,(2,1)],[pth.Path.LINETO,(1,1)],[pth.Path.CLOSEPOLY,(None,None)],]\n\
+                       cmd,pts=zip(*dat)\n\
+                       h=pth.Path(pts,cmd)\n\
+                       p=pat.PathPatch(h,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n";
+        assert_eq!(canvas.buffer, b);
+        let bad = [vec![vec![1.0, 1.0], vec![2.0, 1.0]]];
+        assert_eq!(
+            canvas.draw_polygon_with_holes(&outer, &bad).err(),
+            Some("polygon must have at least 3 points")
+        );
+    }
+
+    #[test]
+    fn dimension_works() {
+        let mut canvas = Canvas::new();
+        canvas.draw_dimension(0.0, 0.0, 0.0, 2.0, 1.0, "2 m");
+        let b: &str = "dat=[[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(-1.1,0)],[pth.Path.MOVETO,(0,2)],[pth.Path.LINETO,(-1.1,2)],]\n\
+                       cmd,pts=zip(*dat)\n\
+                       h=pth.Path(pts,cmd)\n\
+                       p=pat.PathPatch(h,fill=False,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.FancyArrowPatch((-1,0),(-1,2),shrinkA=0,shrinkB=0,edgecolor='#427ce5',arrowstyle='<->')\n\
+                       plt.gca().add_patch(p)\n\
+                       t=plt.text(-1,1,r'2 m',color='#343434',ha='center',va='center',fontsize=10,rotation=90,rotation_mode='anchor',bbox=dict(boxstyle='square,pad=0.1',facecolor=plt.gca().get_facecolor(),edgecolor='none'))\n\
+                       add_to_ea(t)\n";
+        assert_eq!(canvas.buffer, b);
+
+        // label is never upside down; no extension lines without offset
+        canvas.clear_buffer();
+        canvas
+            .set_arrow_style(ArrowStyle::BarAB(1.0))
+            .set_alt_text_rotation(30.0)
+            .draw_dimension(1.0, 0.0, 0.0, 0.0, 0.0, "L");
+        let b: &str = "p=pat.FancyArrowPatch((1,0),(0,0),shrinkA=0,shrinkB=0,edgecolor='#427ce5',arrowstyle='|-|')\n\
+                       plt.gca().add_patch(p)\n\
+                       t=plt.text(0.5,0,r'L',color='#343434',ha='center',va='center',fontsize=10,rotation=30,bbox=dict(boxstyle='square,pad=0.1',facecolor=plt.gca().get_facecolor(),edgecolor='none'))\n\
+                       add_to_ea(t)\n";
+        assert_eq!(canvas.buffer, b);
+        canvas.clear_buffer();
+        canvas
+            .set_alt_text_rotation(0.0)
+            .draw_dimension(1.0, 0.0, 0.0, 0.0, 0.0, "L");
+        assert!(canvas.buffer.contains(",rotation=0,rotation_mode='anchor'"));
+    }
+
+    #[test]
+    fn dimension_angular_works() {
+        let mut canvas = Canvas::new();
+        canvas.draw_dimension_angular(&[0.0, 0.0], &[[2.0, 0.0], [0.0, 0.5]], 1.0, "90°");
+        let b: &str = "dat=[[pth.Path.MOVETO,(0,0.5)],[pth.Path.LINETO,(0,1.1)],]\n\
+                       cmd,pts=zip(*dat)\n\
+                       h=pth.Path(pts,cmd)\n\
+                       p=pat.PathPatch(h,fill=False,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n\
+                       h=tra.Affine2D().scale(1).translate(0,0).transform_path(pth.Path.arc(0,90))\n\
+                       p=pat.FancyArrowPatch(path=h,edgecolor='#427ce5',arrowstyle='<->')\n\
+                       plt.gca().add_patch(p)\n";
+        assert!(canvas.buffer.starts_with(b));
+        assert!(canvas.buffer.contains(",rotation=-45,rotation_mode='anchor'"));
+    }
+
+    #[test]
+    fn scale_bar_works() {
+        let mut canvas = Canvas::new();
+        assert_eq!(
+            canvas.draw_scale_bar(0.0, 0.0, 0.0, "m").err(),
+            Some("max_length must be positive")
+        );
+        assert_eq!(canvas.draw_scale_bar(0.0, 0.0, 7.3, "m"), Ok(5.0));
+        let b: &str = "p=pat.FancyArrowPatch((0,0),(5,0),shrinkA=0,shrinkB=0,edgecolor='#427ce5',arrowstyle='|-|')\n\
+                       plt.gca().add_patch(p)\n\
+                       t=plt.annotate(r'5 m',xy=(2.5,0),xytext=(0,10),textcoords='offset points',color='#343434',ha='center',va='center',fontsize=10)\n\
+                       add_to_ea(t)\n";
+        assert_eq!(canvas.buffer, b);
+        assert_eq!(canvas.draw_scale_bar(0.0, 0.0, 2.5, ""), Ok(2.0));
+        assert_eq!(canvas.draw_scale_bar(0.0, 0.0, 1999.0, ""), Ok(1000.0));
+        canvas.clear_buffer();
+        assert_eq!(canvas.draw_scale_bar(0.0, 0.0, 0.06, "mm"), Ok(0.05));
+        assert!(canvas.buffer.contains("r'0.05 mm'"));
+    }
+
+    #[test]
+    fn dimension_and_scale_bar_labels_use_coords() {
+        let mut canvas = Canvas::new();
+        canvas
+            .set_coords(CoordSystem::Axes)
+            .draw_dimension(0.1, 0.1, 0.9, 0.1, 0.0, "L");
+        let b: &str = "p=pat.FancyArrowPatch((0.1,0.1),(0.9,0.1),shrinkA=0,shrinkB=0,edgecolor='#427ce5',transform=plt.gca().transAxes,arrowstyle='<->')\n\
+                       plt.gca().add_patch(p)\n\
+                       t=plt.text(0.5,0.1,r'L',color='#343434',ha='center',va='center',fontsize=10,rotation=0,rotation_mode='anchor',transform=plt.gca().transAxes,bbox=dict(boxstyle='square,pad=0.1',facecolor=plt.gca().get_facecolor(),edgecolor='none'))\n\
+                       add_to_ea(t)\n";
+        assert_eq!(canvas.buffer, b);
+        canvas.clear_buffer();
+        canvas.draw_scale_bar(0.25, 0.25, 0.6, "").unwrap();
+        let b: &str = "p=pat.FancyArrowPatch((0.25,0.25),(0.75,0.25),shrinkA=0,shrinkB=0,edgecolor='#427ce5',transform=plt.gca().transAxes,arrowstyle='|-|')\n\
+                       plt.gca().add_patch(p)\n\
+                       t=plt.annotate(r'0.5',xy=(0.5,0.25),xytext=(0,10),textcoords='offset points',xycoords=plt.gca().transAxes,color='#343434',ha='center',va='center',fontsize=10)\n\
+                       add_to_ea(t)\n";
+        assert_eq!(canvas.buffer, b);
+    }
+
+    #[test]
+    fn polygon_3d_works() {
+        let mut canvas = Canvas::new();
+        assert_eq!(
+            canvas.draw_polygon_3d(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]).err(),
+            Some("npoint must be ≥ 3")
+        );
+        assert_eq!(
+            canvas.draw_polygon_3d(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]).err(),
+            Some("ndim must be equal to 3")
+        );
+        canvas
+            .set_face_color("red")
+            .set_line_style(LineStyle::Dashed)
+            .set_alpha(0.5);
+        canvas
+            .draw_polygon_3d(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 1.0]])
+            .unwrap();
+        let b: &str = "maybe_create_ax3d()\n\
+                       xyz=np.array([[0,0,0],[1,0,0],[0,1,1],])\n\
+                       p=m3d.art3d.Poly3DCollection([xyz],facecolor='red',edgecolor='#427ce5',linestyle='--',alpha=0.5)\n\
+                       AX3D.add_collection3d(p)\n";
+        assert_eq!(canvas.buffer, b);
+    }
+
+    #[test]
+    fn arrow_3d_works() {
+        let mut canvas = Canvas::new();
+        assert_eq!(
+            canvas.draw_arrow_3d(&[0.0, 0.0], &[1.0, 0.0, 0.0]).err(),
+            Some("a.len() must equal to 3")
+        );
+        assert_eq!(
+            canvas.draw_arrow_3d(&[0.0, 0.0, 0.0], &[0.0, 0.0, 0.0]).err(),
+            Some("a-to-b segment is too short")
+        );
+        canvas.set_arrow_head_3d(0.5, 0.1);
+        canvas.draw_arrow_3d(&[0.0, 0.0, 0.0], &[2.0, 0.0, 0.0]).unwrap();
+        assert!(canvas.buffer.starts_with(
+            "maybe_create_ax3d()\n\
+             AX3D.plot([0,1.5],[0,0],[0,0],color='#427ce5')\n\
+             x=np.array([[2,2,"
+        ));
+        assert!(canvas
+            .buffer
+            .ends_with("AX3D.plot_surface(x,y,z,linewidth=0,color='#427ce5')\n"));
+    }
+
+    #[test]
+    fn circle_and_disk_3d_work() {
+        let mut canvas = Canvas::new();
+        assert_eq!(
+            canvas.draw_circle_3d(&[0.0, 0.0, 0.0], &[0.0, 0.0, 0.0], 1.0).err(),
+            Some("the normal vector must not be zero")
+        );
+        assert_eq!(
+            canvas.draw_disk_3d(&[0.0, 0.0, 0.0], &[0.0, 0.0, 1.0], 0.0).err(),
+            Some("the radius must be positive")
+        );
+        canvas.draw_circle_3d(&[0.0, 0.0, 1.0], &[0.0, 0.0, 2.0], 1.0).unwrap();
+        assert!(canvas.buffer.starts_with("maybe_create_ax3d()\nxyz=np.array(["));
+        assert!(canvas
+            .buffer
+            .ends_with("])\nAX3D.plot(xyz[:,0],xyz[:,1],xyz[:,2],color='#427ce5')\n"));
+        assert_eq!(canvas.buffer.matches("],[").count(), 48);
+        canvas.clear_buffer();
+        canvas.draw_disk_3d(&[0.0, 0.0, 1.0], &[1.0, 1.0, 0.0], 1.0).unwrap();
+        assert!(canvas
+            .buffer
+            .ends_with("])\np=m3d.art3d.Poly3DCollection([xyz],edgecolor='#427ce5')\nAX3D.add_collection3d(p)\n"));
+        assert_eq!(canvas.buffer.matches("],[").count(), 47);
+        // points lie on the plane and on the circle
+        let start = canvas.buffer.find("[[").unwrap() + 2;
+        let end = canvas.buffer.find("],])").unwrap();
+        for point in canvas.buffer[start..end].split("],[") {
+            let x: Vec<f64> = point.split(',').map(|v| v.parse().unwrap()).collect();
+            assert!(f64::abs(x[0] + x[1]) < 1e-14);
+            assert!(f64::abs(x[0] * x[0] + x[1] * x[1] + (x[2] - 1.0) * (x[2] - 1.0) - 1.0) < 1e-14);
+        }
+    }
+
+    #[test]
+    fn text_3d_works() {
+        let mut canvas = Canvas::new();
+        canvas.set_text_rotation(0.0).draw_text_3d(1.0, 2.0, 3.0, "P");
+        assert_eq!(
+            canvas.buffer,
+            "maybe_create_ax3d()\nAX3D.text(1,2,3,'P',color='#a81414',fontsize=8)\n"
+        );
+    }
+
+    #[test]
+    fn supports_and_hinge_work() {
+        let mut canvas = Canvas::new();
+        canvas.draw_support_pinned(0.0, 0.0, 1.0, 0.0);
+        assert!(canvas
+            .buffer
+            .starts_with("dat=[[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(-0.5,-0.8)],[pth.Path.LINETO,(0.5,-0.8)],[pth.Path.CLOSEPOLY,(None,None)]]\n"));
+        assert!(canvas
+            .buffer
+            .contains("dat=[[pth.Path.MOVETO,(-0.75,-0.8)],[pth.Path.LINETO,(0.75,-0.8)],[pth.Path.MOVETO,(-0.55,-0.8)],[pth.Path.LINETO,(-0.75,-1)],"));
+        assert_eq!(canvas.buffer.matches("pth.Path.MOVETO").count(), 1 + 8);
+        canvas.clear_buffer();
+        canvas.draw_support_roller(0.0, 0.0, 1.0, 0.0);
+        assert_eq!(canvas.buffer.matches("pat.Circle").count(), 2);
+        assert!(canvas.buffer.contains("pat.Circle((-0.25,-0.7),0.1"));
+        canvas.clear_buffer();
+        canvas.draw_support_fixed(0.0, 0.0, 1.0, 0.0);
+        assert_eq!(canvas.buffer.matches("pth.Path.MOVETO").count(), 8);
+        assert!(canvas
+            .buffer
+            .starts_with("dat=[[pth.Path.MOVETO,(-0.75,0)],[pth.Path.LINETO,(0.75,0)],"));
+        canvas.clear_buffer();
+        canvas.set_face_color("red").draw_hinge(1.0, 2.0, 0.5);
+        assert_eq!(
+            canvas.buffer,
+            "p=pat.Circle((1,2),0.25,edgecolor='#427ce5',facecolor='white')\nplt.gca().add_patch(p)\n"
+        );
+        assert_eq!(canvas.face_color, "red");
+    }
+
+    #[test]
+    fn loads_work() {
+        let mut canvas = Canvas::new();
+        canvas.draw_point_load(1.0, 0.0, 2.0, 0.0);
+        assert_eq!(
+            canvas.buffer,
+            "p=pat.FancyArrowPatch((-1,0),(1,0),shrinkA=0,shrinkB=0,edgecolor='#427ce5',arrowstyle='->')\n\
+             plt.gca().add_patch(p)\n"
+        );
+        canvas.clear_buffer();
+        assert_eq!(
+            canvas
+                .draw_distributed_load(&[0.0, 0.0], &[1.0, 0.0], &[1.0, 1.0], 1)
+                .err(),
+            Some("narrow must be ≥ 2")
+        );
+        assert_eq!(
+            canvas
+                .draw_distributed_load(&[0.0, 0.0], &[0.0, 0.0], &[1.0, 1.0], 2)
+                .err(),
+            Some("the loaded segment is too short")
+        );
+        canvas
+            .draw_distributed_load(&[0.0, 0.0], &[2.0, 0.0], &[0.0, 1.0], 3)
+            .unwrap();
+        let b: &str = "p=pat.FancyArrowPatch((1,0.5),(1,0),shrinkA=0,shrinkB=0,edgecolor='#427ce5',arrowstyle='->')\n\
+                       plt.gca().add_patch(p)\n\
+                       p=pat.FancyArrowPatch((2,1),(2,0),shrinkA=0,shrinkB=0,edgecolor='#427ce5',arrowstyle='->')\n\
+                       plt.gca().add_patch(p)\n\
+                       dat=[[pth.Path.MOVETO,(0,0)],[pth.Path.LINETO,(2,1)],]\n\
+                       cmd,pts=zip(*dat)\n\
+                       h=pth.Path(pts,cmd)\n\
+                       p=pat.PathPatch(h,fill=False,edgecolor='#427ce5')\n\
+                       plt.gca().add_patch(p)\n";
+        assert_eq!(canvas.buffer, b);
+    }
+
+    #[test]
+    fn moment_works() {
+        let mut canvas = Canvas::new();
+        canvas.draw_moment(1.0, 2.0, 0.5, 0.0, false);
+        let b: &str = "h=tra.Affine2D().scale(0.5).translate(1,2).transform_path(pth.Path.arc(45,315))\n\
+                       p=pat.FancyArrowPatch(path=h,edgecolor='#427ce5',arrowstyle='->')\n\
+                       plt.gca().add_patch(p)\n";
+        assert_eq!(canvas.buffer, b);
+        canvas.clear_buffer();
+        canvas
+            .set_arrow_style(ArrowStyle::CurveFilledB(0.4, 0.2))
+            .draw_moment(0.0, 0.0, 1.0, 90.0, true);
+        let b: &str = "h=tra.Affine2D().scale(1).translate(0,0).transform_path(pth.Path.arc(135,405))\n\
+                       h=pth.Path(h.vertices[::-1],h.codes)\n\
+                       p=pat.FancyArrowPatch(path=h,edgecolor='#427ce5',arrowstyle='-|>')\n\
+                       plt.gca().add_patch(p)\n";
+        assert_eq!(canvas.buffer, b);
+    }
+
+    #[test]
+    fn spring_works() {
+        let mut canvas = Canvas::new();
+        assert_eq!(
+            canvas.draw_spring(0.0, 0.0, 1.0, 0.0, 0, 0.2).err(),
+            Some("ncoil must be ≥ 1")
+        );
+        assert_eq!(
+            canvas.draw_spring(1.0, 1.0, 1.0, 1.0, 2, 0.2).err(),
+            Some("the spring is too short")
+        );
+        canvas.draw_spring(0.0, 0.0, 2.0, 0.0, 3, 0.4).unwrap();
+        assert!(canvas.buffer.starts_with("dat=[[pth.Path.MOVETO,(0,0)],"));
+        assert!(canvas.buffer.contains(",[pth.Path.LINETO,(2,0)],]\n"));
+        assert_eq!(canvas.buffer.matches("pth.Path.LINETO").count(), 2 * 3 + 3);
+        // zigzag points alternate between both sides of the axis
+        let start = canvas.buffer.find("[[").unwrap() + 2;
+        let end = canvas.buffer.find("],]").unwrap();
+        let y: Vec<f64> = canvas.buffer[start..end]
+            .split("],[")
+            .map(|item| item.trim_end_matches(')').rsplit(',').next().unwrap().parse().unwrap())
+            .collect();
+        assert_eq!(y.len(), 2 * 3 + 4);
+        for k in 0..6 {
+            let side = if k % 2 == 0 { 1.0 } else { -1.0 };
+            assert!(f64::abs(y[2 + k] - side * 0.2) < 1e-15);
+        }
+    }
+
     #[test]
     fn polycurve_methods_work() {
         // note the following sequence of codes won't work in Matplotlib because Curve3 and Curve4 are wrong
@@ -1091,6 +2652,9 @@ mod tests {
     #[test]
     fn polycurve_capture_errors() {
         let mut canvas = Canvas::new();
+        let empty: Vec<Vec<f64>> = Vec::new();
+        assert_eq!(canvas.draw_polycurve(&empty, &[], true), Ok(()));
+        assert_eq!(canvas.buffer, "");
         assert_eq!(
             canvas.draw_polycurve(&[[0, 0]], &[PolyCode::MoveTo], true).err(),
             Some("npoint must be ≥ 3")
@@ -0,0 +1,712 @@
+use super::StrError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Holds a color and its specification as understood by Matplotlib
+///
+/// A color may be given by:
+///
+/// * hex codes: `"#rgb"`, `"#rgba"`, `"#rrggbb"`, or `"#rrggbbaa"`
+/// * CSS/Matplotlib names: e.g., `"red"`, `"gold"`, `"darkslategray"`, `"none"` (case-insensitive)
+/// * Matplotlib single letters: `"b"`, `"g"`, `"r"`, `"c"`, `"m"`, `"y"`, `"k"`, `"w"`
+/// * Tableau colors: e.g., `"tab:blue"`, `"tab:orange"`
+/// * Cycle colors: `"C0"`, `"C1"`, ..., `"C9"` (default property cycle)
+/// * Gray levels: e.g., `"0.75"` (a number in \[0, 1\] given as a string)
+/// * CSS functions: e.g., `"rgb(255,128,0)"`, `"rgba(255,128,0,0.5)"`, `"rgb(100%,50%,0%)"`
+/// * XKCD colors: e.g., `"xkcd:sky blue"` (passed to Matplotlib as is)
+///
+/// An empty string means the automatic (default) color. All setters accepting colors (e.g.,
+/// [crate::Curve::set_line_color]) take `impl Into<Color>`; hence, a `&str` may be given directly.
+/// In this case, an invalid color is recorded (see [Color::get_error]) and reported by [crate::Plot::save]
+/// before python3 is called, instead of as a Python error in the log file. Use [Color::parse] to handle
+/// invalid colors immediately.
+///
+/// Unless the color is modified (e.g., by [Color::lighten]), the original specification is
+/// passed to Python as is. Otherwise, the hex code `"#rrggbb"` or `"#rrggbbaa"` is used.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Color, Curve, Marker, Plot, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // colors
+///     let base = Color::parse("rgb(24,98,171)")?;
+///     let light = base.lighten(0.5);
+///     let other = Color::from("tab:orange");
+///
+///     // draw curves
+///     let mut curve = Curve::new();
+///     for i in 0..5 {
+///         let t = i as f64 / 4.0;
+///         let c = i as f64;
+///         curve
+///             .set_line_color(base.interpolate(&other, t))
+///             .set_marker_style(Marker::Circle)
+///             .set_marker_color(&light)
+///             .set_marker_line_color(base.with_alpha(0.5));
+///         curve.draw(&[0.0, 1.0], &[c, c + 1.0]);
+///     }
+///
+///     // add curves to plot
+///     let mut plot = Plot::new();
+///     plot.add(&curve);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_color.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_color.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_color.svg)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Color {
+    spec: String,            // Specification passed to Matplotlib (empty means automatic)
+    rgba: Option<[f64; 4]>,  // Red, green, blue, and alpha components in [0,1] (None if unknown)
+    error: Option<StrError>, // Error found when converting from an invalid string (None if valid)
+}
+
+impl Color {
+    /// Parses a color (see the [Color] documentation for the accepted specifications)
+    pub fn parse(color: &str) -> Result<Self, StrError> {
+        let rgba = parse_rgba(color)?;
+        let spec = if color.to_lowercase().starts_with("rgb") {
+            to_hex(&rgba.unwrap())
+        } else {
+            String::from(color)
+        };
+        Ok(Color {
+            spec,
+            rgba,
+            error: None,
+        })
+    }
+
+    /// Creates a new opaque color from the red, green, and blue components in \[0, 1\]
+    ///
+    /// **Note:** The components are clamped to \[0, 1\].
+    pub fn rgb(red: f64, green: f64, blue: f64) -> Self {
+        Color::rgba(red, green, blue, 1.0)
+    }
+
+    /// Creates a new color from the red, green, blue, and alpha components in \[0, 1\]
+    ///
+    /// **Note:** The components are clamped to \[0, 1\].
+    pub fn rgba(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Color::from_rgba([red, green, blue, alpha])
+    }
+
+    /// Returns the red, green, blue, and alpha components in \[0, 1\]
+    ///
+    /// Returns `None` for the automatic color and for colors that are passed to Matplotlib as is (XKCD colors).
+    pub fn get_rgba(&self) -> Option<[f64; 4]> {
+        self.rgba
+    }
+
+    /// Returns the error found when converting this color from an invalid string (None if valid)
+    ///
+    /// **Note:** The setters accepting colors record this error, which is then returned by [crate::Plot::save].
+    pub fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
+
+    /// Returns true if this is the automatic (default) color
+    pub fn is_automatic(&self) -> bool {
+        self.spec.is_empty()
+    }
+
+    /// Returns a copy of this color with a new alpha (opacity) in \[0, 1\]
+    ///
+    /// **Note:** Colors without known components (see [Color::get_rgba]) are returned unchanged.
+    pub fn with_alpha(&self, alpha: f64) -> Self {
+        match self.rgba {
+            Some(c) => Color::from_rgba([c[0], c[1], c[2], alpha]),
+            None => self.clone(),
+        }
+    }
+
+    /// Returns a lighter copy of this color by mixing it with white
+    ///
+    /// The `amount` in \[0, 1\] is the fraction of white; e.g., 0 returns the same color and 1 returns white.
+    ///
+    /// **Note:** Colors without known components (see [Color::get_rgba]) are returned unchanged.
+    pub fn lighten(&self, amount: f64) -> Self {
+        self.interpolate(&Color::rgb(1.0, 1.0, 1.0).with_alpha(self.alpha()), amount)
+    }
+
+    /// Returns a darker copy of this color by mixing it with black
+    ///
+    /// The `amount` in \[0, 1\] is the fraction of black; e.g., 0 returns the same color and 1 returns black.
+    ///
+    /// **Note:** Colors without known components (see [Color::get_rgba]) are returned unchanged.
+    pub fn darken(&self, amount: f64) -> Self {
+        self.interpolate(&Color::rgb(0.0, 0.0, 0.0).with_alpha(self.alpha()), amount)
+    }
+
+    /// Interpolates linearly (including the alpha component) between this color and another one
+    ///
+    /// The parameter `t` in \[0, 1\] yields this color when 0 and the other color when 1.
+    ///
+    /// **Note:** If any color has unknown components (see [Color::get_rgba]), this color is returned unchanged.
+    pub fn interpolate(&self, other: &Color, t: f64) -> Self {
+        match (self.rgba, other.rgba) {
+            (Some(a), Some(b)) => {
+                let t = t.clamp(0.0, 1.0);
+                Color::from_rgba([
+                    a[0] + t * (b[0] - a[0]),
+                    a[1] + t * (b[1] - a[1]),
+                    a[2] + t * (b[2] - a[2]),
+                    a[3] + t * (b[3] - a[3]),
+                ])
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the Python literal representing this color (`None` for the automatic color)
+    pub fn to_python(&self) -> String {
+        if self.spec.is_empty() {
+            "None".to_string()
+        } else {
+            format!("'{}'", self.spec)
+        }
+    }
+
+    /// Creates a new color from clamped components and sets the hex code as the specification
+    fn from_rgba(rgba: [f64; 4]) -> Self {
+        let rgba = rgba.map(|v| v.clamp(0.0, 1.0));
+        Color {
+            spec: to_hex(&rgba),
+            rgba: Some(rgba),
+            error: None,
+        }
+    }
+
+    /// Returns the alpha component (1 if unknown)
+    fn alpha(&self) -> f64 {
+        match self.rgba {
+            Some(c) => c[3],
+            None => 1.0,
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    /// Writes the specification passed to Matplotlib (without quotes)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.spec)
+    }
+}
+
+impl FromStr for Color {
+    type Err = StrError;
+    fn from_str(color: &str) -> Result<Self, Self::Err> {
+        Color::parse(color)
+    }
+}
+
+impl From<&str> for Color {
+    /// Converts a string into a color
+    ///
+    /// If the color is invalid, the string is kept as is and the error is recorded (see [Color::get_error]).
+    /// Use [Color::parse] to get the error immediately instead.
+    fn from(color: &str) -> Self {
+        match Color::parse(color) {
+            Ok(c) => c,
+            Err(e) => Color {
+                spec: String::from(color),
+                rgba: None,
+                error: Some(e),
+            },
+        }
+    }
+}
+
+impl From<&String> for Color {
+    /// Converts a string into a color (see `From<&str>`)
+    fn from(color: &String) -> Self {
+        Color::from(color.as_str())
+    }
+}
+
+impl From<String> for Color {
+    /// Converts a string into a color (see `From<&str>`)
+    fn from(color: String) -> Self {
+        Color::from(color.as_str())
+    }
+}
+
+impl From<&Color> for Color {
+    fn from(color: &Color) -> Self {
+        color.clone()
+    }
+}
+
+/// Converts the color into the specification passed to Matplotlib and records the first error (if the color is invalid)
+pub(crate) fn color_to_spec(color: impl Into<Color>, error: &mut Option<StrError>) -> String {
+    let color = color.into();
+    if error.is_none() {
+        *error = color.error;
+    }
+    color.spec
+}
+
+/// Returns the hex code "#rrggbb" (opaque) or "#rrggbbaa" corresponding to the components in [0,1]
+fn to_hex(rgba: &[f64; 4]) -> String {
+    let byte = |v: f64| (v * 255.0).round() as u8;
+    if rgba[3] < 1.0 {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            byte(rgba[0]),
+            byte(rgba[1]),
+            byte(rgba[2]),
+            byte(rgba[3])
+        )
+    } else {
+        format!("#{:02x}{:02x}{:02x}", byte(rgba[0]), byte(rgba[1]), byte(rgba[2]))
+    }
+}
+
+/// Parses the color and returns its components (None means automatic or unknown)
+fn parse_rgba(color: &str) -> Result<Option<[f64; 4]>, StrError> {
+    if color.is_empty() {
+        return Ok(None);
+    }
+    if let Some(digits) = color.strip_prefix('#') {
+        return parse_hex(digits).map(Some);
+    }
+    let lower = color.to_lowercase();
+    if lower == "none" {
+        return Ok(Some([0.0, 0.0, 0.0, 0.0]));
+    }
+    if lower.starts_with("xkcd:") {
+        return Ok(None);
+    }
+    if lower.starts_with("rgb") {
+        return parse_css_function(&lower).map(Some);
+    }
+    if let Some(index) = lower.strip_prefix('c') {
+        if let Ok(i) = index.parse::<usize>() {
+            return parse_hex(&TABLEAU_COLORS[i % TABLEAU_COLORS.len()].1[1..]).map(Some);
+        }
+    }
+    if let Ok(gray) = lower.parse::<f64>() {
+        if !(0.0..=1.0).contains(&gray) {
+            return Err("invalid color: the gray level must be in [0, 1]");
+        }
+        return Ok(Some([gray, gray, gray, 1.0]));
+    }
+    if let Some(name) = lower.strip_prefix("tab:") {
+        let name = if name == "grey" { "gray" } else { name };
+        return match TABLEAU_COLORS.iter().find(|(n, _)| *n == name) {
+            Some((_, hex)) => parse_hex(&hex[1..]).map(Some),
+            None => Err("invalid color: unknown Tableau color name"),
+        };
+    }
+    let base = match lower.as_str() {
+        "b" => Some([0.0, 0.0, 1.0, 1.0]),
+        "g" => Some([0.0, 0.5, 0.0, 1.0]),
+        "r" => Some([1.0, 0.0, 0.0, 1.0]),
+        "c" => Some([0.0, 0.75, 0.75, 1.0]),
+        "m" => Some([0.75, 0.0, 0.75, 1.0]),
+        "y" => Some([0.75, 0.75, 0.0, 1.0]),
+        "k" => Some([0.0, 0.0, 0.0, 1.0]),
+        "w" => Some([1.0, 1.0, 1.0, 1.0]),
+        _ => None,
+    };
+    if base.is_some() {
+        return Ok(base);
+    }
+    match CSS4_COLORS.binary_search_by(|(n, _)| n.cmp(&lower.as_str())) {
+        Ok(i) => parse_hex(&CSS4_COLORS[i].1[1..]).map(Some),
+        Err(_) => Err("invalid color: unknown color name"),
+    }
+}
+
+/// Parses the digits of a hex code with 3, 4, 6, or 8 digits
+fn parse_hex(digits: &str) -> Result<[f64; 4], StrError> {
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("invalid color: the hex code must contain hexadecimal digits only");
+    }
+    let short = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).unwrap() as f64 / 15.0;
+    let long = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).unwrap() as f64 / 255.0;
+    match digits.len() {
+        3 => Ok([short(0), short(1), short(2), 1.0]),
+        4 => Ok([short(0), short(1), short(2), short(3)]),
+        6 => Ok([long(0), long(2), long(4), 1.0]),
+        8 => Ok([long(0), long(2), long(4), long(6)]),
+        _ => Err("invalid color: the hex code must have 3, 4, 6, or 8 digits"),
+    }
+}
+
+/// Parses the CSS functions rgb(r,g,b) and rgba(r,g,b,a) (already in lowercase)
+fn parse_css_function(color: &str) -> Result<[f64; 4], StrError> {
+    let (args, n) = if let Some(args) = color.strip_prefix("rgba(") {
+        (args, 4)
+    } else if let Some(args) = color.strip_prefix("rgb(") {
+        (args, 3)
+    } else {
+        return Err("invalid color: unknown color name");
+    };
+    let args = match args.strip_suffix(')') {
+        Some(args) => args,
+        None => return Err("invalid color: the closing parenthesis is missing"),
+    };
+    let values: Vec<&str> = args.split(',').map(|v| v.trim()).collect();
+    if values.len() != n {
+        return Err("invalid color: rgb() requires 3 components and rgba() requires 4 components");
+    }
+    let mut rgba = [1.0; 4];
+    for i in 0..n {
+        let (value, max) = match values[i].strip_suffix('%') {
+            Some(v) => (v, 100.0),
+            None => (values[i], if i < 3 { 255.0 } else { 1.0 }),
+        };
+        let value: f64 = value
+            .parse()
+            .map_err(|_| "invalid color: the components must be numbers")?;
+        if value < 0.0 || value > max {
+            return Err("invalid color: the components must be in [0, 255] (or [0, 1] for alpha) or [0%, 100%]");
+        }
+        rgba[i] = value / max;
+    }
+    Ok(rgba)
+}
+
+/// Tableau colors (tab10); also the default property cycle (C0, C1, ...)
+const TABLEAU_COLORS: [(&str, &str); 10] = [
+    ("blue", "#1f77b4"),
+    ("orange", "#ff7f0e"),
+    ("green", "#2ca02c"),
+    ("red", "#d62728"),
+    ("purple", "#9467bd"),
+    ("brown", "#8c564b"),
+    ("pink", "#e377c2"),
+    ("gray", "#7f7f7f"),
+    ("olive", "#bcbd22"),
+    ("cyan", "#17becf"),
+];
+
+/// CSS4 colors (sorted by name)
+const CSS4_COLORS: [(&str, &str); 148] = [
+    ("aliceblue", "#f0f8ff"),
+    ("antiquewhite", "#faebd7"),
+    ("aqua", "#00ffff"),
+    ("aquamarine", "#7fffd4"),
+    ("azure", "#f0ffff"),
+    ("beige", "#f5f5dc"),
+    ("bisque", "#ffe4c4"),
+    ("black", "#000000"),
+    ("blanchedalmond", "#ffebcd"),
+    ("blue", "#0000ff"),
+    ("blueviolet", "#8a2be2"),
+    ("brown", "#a52a2a"),
+    ("burlywood", "#deb887"),
+    ("cadetblue", "#5f9ea0"),
+    ("chartreuse", "#7fff00"),
+    ("chocolate", "#d2691e"),
+    ("coral", "#ff7f50"),
+    ("cornflowerblue", "#6495ed"),
+    ("cornsilk", "#fff8dc"),
+    ("crimson", "#dc143c"),
+    ("cyan", "#00ffff"),
+    ("darkblue", "#00008b"),
+    ("darkcyan", "#008b8b"),
+    ("darkgoldenrod", "#b8860b"),
+    ("darkgray", "#a9a9a9"),
+    ("darkgreen", "#006400"),
+    ("darkgrey", "#a9a9a9"),
+    ("darkkhaki", "#bdb76b"),
+    ("darkmagenta", "#8b008b"),
+    ("darkolivegreen", "#556b2f"),
+    ("darkorange", "#ff8c00"),
+    ("darkorchid", "#9932cc"),
+    ("darkred", "#8b0000"),
+    ("darksalmon", "#e9967a"),
+    ("darkseagreen", "#8fbc8f"),
+    ("darkslateblue", "#483d8b"),
+    ("darkslategray", "#2f4f4f"),
+    ("darkslategrey", "#2f4f4f"),
+    ("darkturquoise", "#00ced1"),
+    ("darkviolet", "#9400d3"),
+    ("deeppink", "#ff1493"),
+    ("deepskyblue", "#00bfff"),
+    ("dimgray", "#696969"),
+    ("dimgrey", "#696969"),
+    ("dodgerblue", "#1e90ff"),
+    ("firebrick", "#b22222"),
+    ("floralwhite", "#fffaf0"),
+    ("forestgreen", "#228b22"),
+    ("fuchsia", "#ff00ff"),
+    ("gainsboro", "#dcdcdc"),
+    ("ghostwhite", "#f8f8ff"),
+    ("gold", "#ffd700"),
+    ("goldenrod", "#daa520"),
+    ("gray", "#808080"),
+    ("green", "#008000"),
+    ("greenyellow", "#adff2f"),
+    ("grey", "#808080"),
+    ("honeydew", "#f0fff0"),
+    ("hotpink", "#ff69b4"),
+    ("indianred", "#cd5c5c"),
+    ("indigo", "#4b0082"),
+    ("ivory", "#fffff0"),
+    ("khaki", "#f0e68c"),
+    ("lavender", "#e6e6fa"),
+    ("lavenderblush", "#fff0f5"),
+    ("lawngreen", "#7cfc00"),
+    ("lemonchiffon", "#fffacd"),
+    ("lightblue", "#add8e6"),
+    ("lightcoral", "#f08080"),
+    ("lightcyan", "#e0ffff"),
+    ("lightgoldenrodyellow", "#fafad2"),
+    ("lightgray", "#d3d3d3"),
+    ("lightgreen", "#90ee90"),
+    ("lightgrey", "#d3d3d3"),
+    ("lightpink", "#ffb6c1"),
+    ("lightsalmon", "#ffa07a"),
+    ("lightseagreen", "#20b2aa"),
+    ("lightskyblue", "#87cefa"),
+    ("lightslategray", "#778899"),
+    ("lightslategrey", "#778899"),
+    ("lightsteelblue", "#b0c4de"),
+    ("lightyellow", "#ffffe0"),
+    ("lime", "#00ff00"),
+    ("limegreen", "#32cd32"),
+    ("linen", "#faf0e6"),
+    ("magenta", "#ff00ff"),
+    ("maroon", "#800000"),
+    ("mediumaquamarine", "#66cdaa"),
+    ("mediumblue", "#0000cd"),
+    ("mediumorchid", "#ba55d3"),
+    ("mediumpurple", "#9370db"),
+    ("mediumseagreen", "#3cb371"),
+    ("mediumslateblue", "#7b68ee"),
+    ("mediumspringgreen", "#00fa9a"),
+    ("mediumturquoise", "#48d1cc"),
+    ("mediumvioletred", "#c71585"),
+    ("midnightblue", "#191970"),
+    ("mintcream", "#f5fffa"),
+    ("mistyrose", "#ffe4e1"),
+    ("moccasin", "#ffe4b5"),
+    ("navajowhite", "#ffdead"),
+    ("navy", "#000080"),
+    ("oldlace", "#fdf5e6"),
+    ("olive", "#808000"),
+    ("olivedrab", "#6b8e23"),
+    ("orange", "#ffa500"),
+    ("orangered", "#ff4500"),
+    ("orchid", "#da70d6"),
+    ("palegoldenrod", "#eee8aa"),
+    ("palegreen", "#98fb98"),
+    ("paleturquoise", "#afeeee"),
+    ("palevioletred", "#db7093"),
+    ("papayawhip", "#ffefd5"),
+    ("peachpuff", "#ffdab9"),
+    ("peru", "#cd853f"),
+    ("pink", "#ffc0cb"),
+    ("plum", "#dda0dd"),
+    ("powderblue", "#b0e0e6"),
+    ("purple", "#800080"),
+    ("rebeccapurple", "#663399"),
+    ("red", "#ff0000"),
+    ("rosybrown", "#bc8f8f"),
+    ("royalblue", "#4169e1"),
+    ("saddlebrown", "#8b4513"),
+    ("salmon", "#fa8072"),
+    ("sandybrown", "#f4a460"),
+    ("seagreen", "#2e8b57"),
+    ("seashell", "#fff5ee"),
+    ("sienna", "#a0522d"),
+    ("silver", "#c0c0c0"),
+    ("skyblue", "#87ceeb"),
+    ("slateblue", "#6a5acd"),
+    ("slategray", "#708090"),
+    ("slategrey", "#708090"),
+    ("snow", "#fffafa"),
+    ("springgreen", "#00ff7f"),
+    ("steelblue", "#4682b4"),
+    ("tan", "#d2b48c"),
+    ("teal", "#008080"),
+    ("thistle", "#d8bfd8"),
+    ("tomato", "#ff6347"),
+    ("turquoise", "#40e0d0"),
+    ("violet", "#ee82ee"),
+    ("wheat", "#f5deb3"),
+    ("white", "#ffffff"),
+    ("whitesmoke", "#f5f5f5"),
+    ("yellow", "#ffff00"),
+    ("yellowgreen", "#9acd32"),
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{color_to_spec, Color, CSS4_COLORS};
+
+    #[test]
+    fn css4_colors_are_sorted() {
+        for i in 1..CSS4_COLORS.len() {
+            assert!(CSS4_COLORS[i - 1].0 < CSS4_COLORS[i].0);
+        }
+    }
+
+    #[test]
+    fn parse_works() {
+        let auto = Color::parse("").unwrap();
+        assert!(auto.is_automatic());
+        assert_eq!(auto.get_rgba(), None);
+        assert_eq!(auto, Color::default());
+        assert_eq!(Color::parse("red").unwrap().get_rgba(), Some([1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(
+            Color::parse("DarkBlue").unwrap().get_rgba(),
+            Some([0.0, 0.0, 139.0 / 255.0, 1.0])
+        );
+        assert_eq!(Color::parse("None").unwrap().get_rgba(), Some([0.0, 0.0, 0.0, 0.0]));
+        assert_eq!(Color::parse("g").unwrap().get_rgba(), Some([0.0, 0.5, 0.0, 1.0]));
+        assert_eq!(Color::parse("0.25").unwrap().get_rgba(), Some([0.25, 0.25, 0.25, 1.0]));
+        assert_eq!(Color::parse("#f00").unwrap().get_rgba(), Some([1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(Color::parse("#f00f").unwrap().get_rgba(), Some([1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(Color::parse("#00ff00").unwrap().get_rgba(), Some([0.0, 1.0, 0.0, 1.0]));
+        assert_eq!(
+            Color::parse("#0000ff00").unwrap().get_rgba(),
+            Some([0.0, 0.0, 1.0, 0.0])
+        );
+        assert_eq!(Color::parse("tab:grey").unwrap(), Color::parse("tab:grey").unwrap());
+        assert_eq!(
+            Color::parse("tab:blue").unwrap().get_rgba(),
+            Color::parse("C0").unwrap().get_rgba()
+        );
+        assert_eq!(
+            Color::parse("C11").unwrap().get_rgba(),
+            Color::parse("C1").unwrap().get_rgba()
+        );
+        assert_eq!(Color::parse("xkcd:sky blue").unwrap().get_rgba(), None);
+        let c = Color::parse("rgb(255, 0, 0)").unwrap();
+        assert_eq!(c.get_rgba(), Some([1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(c.to_string(), "#ff0000");
+        let c = Color::parse("rgba(0,0,255,0.5)").unwrap();
+        assert_eq!(c.get_rgba(), Some([0.0, 0.0, 1.0, 0.5]));
+        assert_eq!(c.to_string(), "#0000ff80");
+        let c = Color::parse("rgb(100%,50%,0%)").unwrap();
+        assert_eq!(c.get_rgba(), Some([1.0, 0.5, 0.0, 1.0]));
+        let c: Color = "gold".parse().unwrap();
+        assert_eq!(c.to_string(), "gold");
+    }
+
+    #[test]
+    fn parse_captures_errors() {
+        assert_eq!(Color::parse("redd").err(), Some("invalid color: unknown color name"));
+        assert_eq!(
+            Color::parse("tab:violet").err(),
+            Some("invalid color: unknown Tableau color name")
+        );
+        assert_eq!(
+            Color::parse("1.5").err(),
+            Some("invalid color: the gray level must be in [0, 1]")
+        );
+        assert_eq!(
+            Color::parse("#12345").err(),
+            Some("invalid color: the hex code must have 3, 4, 6, or 8 digits")
+        );
+        assert_eq!(
+            Color::parse("#12345g").err(),
+            Some("invalid color: the hex code must contain hexadecimal digits only")
+        );
+        assert_eq!(
+            Color::parse("rgb(1,2,3").err(),
+            Some("invalid color: the closing parenthesis is missing")
+        );
+        assert_eq!(
+            Color::parse("rgb(1,2)").err(),
+            Some("invalid color: rgb() requires 3 components and rgba() requires 4 components")
+        );
+        assert_eq!(
+            Color::parse("rgb(1,2,a)").err(),
+            Some("invalid color: the components must be numbers")
+        );
+        assert_eq!(
+            Color::parse("rgba(1,2,3,2)").err(),
+            Some("invalid color: the components must be in [0, 255] (or [0, 1] for alpha) or [0%, 100%]")
+        );
+        assert_eq!(
+            Color::parse("rgbx(1,2,3)").err(),
+            Some("invalid color: unknown color name")
+        );
+    }
+
+    #[test]
+    fn from_records_invalid_color() {
+        let c = Color::from("blu");
+        assert_eq!(c.get_error(), Some("invalid color: unknown color name"));
+        assert_eq!(c.get_rgba(), None);
+        assert_eq!(c.to_string(), "blu");
+        assert_eq!(c.lighten(0.5), c);
+        assert_eq!(Color::from("blue").get_error(), None);
+    }
+
+    #[test]
+    fn color_to_spec_works() {
+        let mut error = None;
+        assert_eq!(color_to_spec("red", &mut error), "red");
+        assert_eq!(error, None);
+        assert_eq!(color_to_spec("#12345", &mut error), "#12345");
+        assert_eq!(
+            error,
+            Some("invalid color: the hex code must have 3, 4, 6, or 8 digits")
+        );
+        assert_eq!(color_to_spec("redd", &mut error), "redd");
+        assert_eq!(
+            error,
+            Some("invalid color: the hex code must have 3, 4, 6, or 8 digits")
+        );
+    }
+
+    #[test]
+    fn from_works() {
+        let s = String::from("#1862ab");
+        assert_eq!(Color::from(&s).to_string(), "#1862ab");
+        assert_eq!(Color::from(s).to_string(), "#1862ab");
+        let c = Color::from("blue");
+        assert_eq!(Color::from(&c), c);
+    }
+
+    #[test]
+    fn rgb_and_rgba_work() {
+        let c = Color::rgb(1.0, 0.5, 2.0);
+        assert_eq!(c.get_rgba(), Some([1.0, 0.5, 1.0, 1.0]));
+        assert_eq!(c.to_string(), "#ff80ff");
+        let c = Color::rgba(0.0, 0.0, 0.0, 0.25);
+        assert_eq!(c.to_string(), "#00000040");
+    }
+
+    #[test]
+    fn modifiers_work() {
+        let red = Color::from("red");
+        assert_eq!(red.with_alpha(0.5).to_string(), "#ff000080");
+        assert_eq!(red.lighten(0.5).to_string(), "#ff8080");
+        assert_eq!(red.darken(0.5).to_string(), "#800000");
+        assert_eq!(red.with_alpha(0.5).lighten(1.0).to_string(), "#ffffff80");
+        let blue = Color::from("blue");
+        assert_eq!(red.interpolate(&blue, 0.0).to_string(), "#ff0000");
+        assert_eq!(red.interpolate(&blue, 0.5).to_string(), "#800080");
+        assert_eq!(red.interpolate(&blue, 2.0).to_string(), "#0000ff");
+        let auto = Color::default();
+        assert_eq!(auto.lighten(0.5), auto);
+        assert_eq!(red.interpolate(&auto, 0.5), red);
+        let xkcd = Color::from("xkcd:sky blue");
+        assert_eq!(xkcd.with_alpha(0.5).to_string(), "xkcd:sky blue");
+    }
+
+    #[test]
+    fn to_python_works() {
+        assert_eq!(Color::default().to_python(), "None");
+        assert_eq!(Color::from("red").to_python(), "'red'");
+        assert_eq!(Color::from("rgb(0,0,255)").to_python(), "'#0000ff'");
+        assert_eq!(Color::from("k").to_python(), "'k'");
+        assert_eq!(format!("{}", Color::from("k")), "k");
+        assert_eq!(format!("{}", Color::rgba(1.0, 0.0, 0.0, 0.5)), "#ff000080");
+        assert_eq!(format!("{}", Color::default()), "");
+    }
+}
@@ -0,0 +1,341 @@
+use super::{vector_to_array, Colormap, GraphMaker, Norm};
+use std::fmt::Write;
+
+/// Generates a standalone colorbar that may be shared by a group of axes (e.g., subplots)
+///
+/// The colorbar is defined by a colormap and a norm (or simply the vmin and vmax limits)
+/// and hence does not depend on a specific [crate::Contour] or [crate::Surface]. Thus, to share
+/// the color range among several graphs, use the same limits (or norm) and colormap in all of them
+/// and skip their own colorbars.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Colorbar, Colormap, Contour, GraphMaker, Plot, StrError};
+/// use russell_lab::generate3d;
+///
+/// fn main() -> Result<(), StrError> {
+///     // configure contours with shared limits
+///     let colormap = Colormap::from_name("viridis");
+///     let mut contour = Contour::new();
+///     contour
+///         .set_colormap(&colormap)
+///         .set_color_limits(0.0, 8.0)
+///         .set_no_colorbar(true);
+///
+///     // add contours to subplots
+///     let mut plot = Plot::new();
+///     for i in 0..4 {
+///         let a = (i + 1) as f64;
+///         let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, 21, 21, |x, y| a * (x * x + y * y) / 4.0);
+///         contour.draw(&x, &y, &z);
+///         plot.set_subplot(2, 2, i + 1).add(&contour);
+///         contour.clear_buffer();
+///     }
+///
+///     // configure and draw a colorbar shared by all subplots
+///     let mut colorbar = Colorbar::new();
+///     colorbar
+///         .set_colormap(&colormap)
+///         .set_limits(0.0, 8.0)
+///         .set_extend("max")
+///         .set_label("temperature");
+///     colorbar.draw();
+///     plot.add(&colorbar);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_colorbar.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_colorbar.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_colorbar.svg)
+///
+/// See also integration tests in the [tests directory](https://github.com/cpmech/plotpy/tree/main/tests)
+pub struct Colorbar {
+    colormap: Colormap,    // Colormap
+    norm: Option<Norm>,    // Normalization of data values into the colormap
+    axes: Vec<usize>,      // Indices of the axes sharing the colorbar (empty means all)
+    orientation: String,   // Orientation: "vertical" or "horizontal"
+    location: String,      // Location: "left", "right", "top", or "bottom"
+    extend: String,        // Extend arrows: "neither", "both", "min", or "max"
+    ticks: Vec<f64>,       // Tick values
+    number_format: String, // Number format for the tick labels
+    label: String,         // Label
+    shrink: f64,           // Fraction by which to multiply the size of the colorbar
+    pad: f64,              // Fraction of original axes between colorbar and the axes
+    buffer: String,        // buffer
+}
+
+impl Colorbar {
+    /// Creates a new Colorbar object
+    pub fn new() -> Self {
+        Colorbar {
+            colormap: Colormap::from_index(0),
+            norm: None,
+            axes: Vec::new(),
+            orientation: String::new(),
+            location: String::new(),
+            extend: String::new(),
+            ticks: Vec::new(),
+            number_format: String::new(),
+            label: String::new(),
+            shrink: 0.0,
+            pad: -1.0,
+            buffer: String::new(),
+        }
+    }
+
+    /// Draws colorbar
+    pub fn draw(&mut self) {
+        writeln!(&mut self.buffer, "cmap={}", self.colormap.to_python()).unwrap();
+        let norm = match &self.norm {
+            Some(norm) => norm.to_python("cmap.N"),
+            None => Norm::Linear(None, None).to_python("cmap.N"),
+        };
+        writeln!(&mut self.buffer, "norm={}", norm).unwrap();
+        writeln!(&mut self.buffer, "sm=plt.cm.ScalarMappable(norm=norm,cmap=cmap)").unwrap();
+        writeln!(&mut self.buffer, "sm.set_array([])").unwrap();
+        if !self.ticks.is_empty() {
+            vector_to_array(&mut self.buffer, "ticks", &self.ticks);
+        }
+        let opt = self.options();
+        if !self.axes.is_empty() {
+            writeln!(&mut self.buffer, "axs=plt.gcf().axes").unwrap();
+            write!(&mut self.buffer, "cb=plt.colorbar(sm,ax=[").unwrap();
+            for index in &self.axes {
+                write!(&mut self.buffer, "axs[{}],", index).unwrap();
+            }
+            writeln!(&mut self.buffer, "]{})", opt).unwrap();
+        } else {
+            writeln!(&mut self.buffer, "cb=plt.colorbar(sm,ax=plt.gcf().axes{})", opt).unwrap();
+        }
+        if !self.label.is_empty() {
+            writeln!(&mut self.buffer, "cb.set_label(r'{}')", self.label).unwrap();
+        }
+    }
+
+    /// Sets the colormap
+    ///
+    /// See [Colormap]
+    pub fn set_colormap(&mut self, colormap: &Colormap) -> &mut Self {
+        self.colormap = colormap.clone();
+        self
+    }
+
+    /// Sets the normalization of data values into the colormap
+    ///
+    /// See [Norm]
+    pub fn set_norm(&mut self, norm: &Norm) -> &mut Self {
+        self.norm = Some(norm.clone());
+        self
+    }
+
+    /// Sets the limits (vmin, vmax) of the data values (linear norm)
+    ///
+    /// **Note:** This function replaces the norm by `Norm::Linear(Some(vmin), Some(vmax))`.
+    pub fn set_limits(&mut self, vmin: f64, vmax: f64) -> &mut Self {
+        self.norm = Some(Norm::Linear(Some(vmin), Some(vmax)));
+        self
+    }
+
+    /// Sets the indices of the axes sharing the colorbar
+    ///
+    /// The indices (0-based) correspond to the order in which the axes (e.g., subplots) have been created.
+    /// An empty list means that all axes of the current figure share the colorbar (default).
+    pub fn set_axes(&mut self, indices: &[usize]) -> &mut Self {
+        self.axes = indices.to_vec();
+        self
+    }
+
+    /// Sets the orientation
+    ///
+    /// Options:
+    ///
+    /// * "vertical", "horizontal"
+    pub fn set_orientation(&mut self, orientation: &str) -> &mut Self {
+        self.orientation = String::from(orientation);
+        self
+    }
+
+    /// Sets the location of the colorbar with respect to the axes
+    ///
+    /// Options:
+    ///
+    /// * "left", "right", "top", "bottom"
+    /// * Note: The location defines the orientation; e.g., "top" implies "horizontal"
+    pub fn set_location(&mut self, location: &str) -> &mut Self {
+        self.location = String::from(location);
+        self
+    }
+
+    /// Sets the option to draw extend arrows for out-of-range values
+    ///
+    /// Options:
+    ///
+    /// * "neither", "both", "min", "max"
+    pub fn set_extend(&mut self, extend: &str) -> &mut Self {
+        self.extend = String::from(extend);
+        self
+    }
+
+    /// Sets the tick values
+    pub fn set_ticks(&mut self, ticks: &[f64]) -> &mut Self {
+        self.ticks = ticks.to_vec();
+        self
+    }
+
+    /// Sets the number format for the tick labels
+    ///
+    /// Example: "%.2f"
+    pub fn set_number_format(&mut self, format: &str) -> &mut Self {
+        self.number_format = String::from(format);
+        self
+    }
+
+    /// Sets the label
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = String::from(label);
+        self
+    }
+
+    /// Sets the fraction by which to multiply the size of the colorbar
+    pub fn set_shrink(&mut self, shrink: f64) -> &mut Self {
+        self.shrink = shrink;
+        self
+    }
+
+    /// Sets the fraction of the original axes between the colorbar and the axes
+    pub fn set_pad(&mut self, pad: f64) -> &mut Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Returns options for colorbar
+    fn options(&self) -> String {
+        let mut opt = String::new();
+        if !self.orientation.is_empty() {
+            write!(&mut opt, ",orientation='{}'", self.orientation).unwrap();
+        }
+        if !self.location.is_empty() {
+            write!(&mut opt, ",location='{}'", self.location).unwrap();
+        }
+        if !self.extend.is_empty() {
+            write!(&mut opt, ",extend='{}'", self.extend).unwrap();
+        }
+        if !self.ticks.is_empty() {
+            write!(&mut opt, ",ticks=ticks").unwrap();
+        }
+        if !self.number_format.is_empty() {
+            write!(&mut opt, ",format='{}'", self.number_format).unwrap();
+        }
+        if self.shrink > 0.0 {
+            write!(&mut opt, ",shrink={}", self.shrink).unwrap();
+        }
+        if self.pad >= 0.0 {
+            write!(&mut opt, ",pad={}", self.pad).unwrap();
+        }
+        opt
+    }
+}
+
+impl Default for Colorbar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GraphMaker for Colorbar {
+    fn get_buffer(&self) -> &String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Colorbar;
+    use crate::{Colormap, GraphMaker, Norm};
+
+    #[test]
+    fn new_works() {
+        let colorbar = Colorbar::new();
+        assert_eq!(colorbar.colormap.to_python(), "get_colormap(0)");
+        assert!(colorbar.norm.is_none());
+        assert_eq!(colorbar.axes.len(), 0);
+        assert_eq!(colorbar.orientation.len(), 0);
+        assert_eq!(colorbar.location.len(), 0);
+        assert_eq!(colorbar.extend.len(), 0);
+        assert_eq!(colorbar.ticks.len(), 0);
+        assert_eq!(colorbar.number_format.len(), 0);
+        assert_eq!(colorbar.label.len(), 0);
+        assert_eq!(colorbar.shrink, 0.0);
+        assert_eq!(colorbar.pad, -1.0);
+        assert_eq!(colorbar.buffer.len(), 0);
+    }
+
+    #[test]
+    fn options_works() {
+        let mut colorbar = Colorbar::new();
+        colorbar
+            .set_orientation("horizontal")
+            .set_location("bottom")
+            .set_extend("both")
+            .set_ticks(&[0.0, 1.0])
+            .set_number_format("%.1f")
+            .set_shrink(0.8)
+            .set_pad(0.05);
+        let opt = colorbar.options();
+        assert_eq!(
+            opt,
+            ",orientation='horizontal'\
+             ,location='bottom'\
+             ,extend='both'\
+             ,ticks=ticks\
+             ,format='%.1f'\
+             ,shrink=0.8\
+             ,pad=0.05"
+        );
+    }
+
+    #[test]
+    fn draw_works() {
+        let mut colorbar = Colorbar::new();
+        colorbar.draw();
+        let b: &str = "cmap=get_colormap(0)\n\
+                       norm=mcl.Normalize(vmin=None,vmax=None)\n\
+                       sm=plt.cm.ScalarMappable(norm=norm,cmap=cmap)\n\
+                       sm.set_array([])\n\
+                       cb=plt.colorbar(sm,ax=plt.gcf().axes)\n";
+        assert_eq!(colorbar.buffer, b);
+        colorbar.clear_buffer();
+        assert_eq!(colorbar.buffer, "");
+    }
+
+    #[test]
+    fn draw_with_axes_and_label_works() {
+        let mut colorbar = Colorbar::new();
+        colorbar
+            .set_colormap(&Colormap::from_name("viridis"))
+            .set_norm(&Norm::Boundary(vec![0.0, 1.0, 2.0]))
+            .set_axes(&[0, 2])
+            .set_ticks(&[0.0, 2.0])
+            .set_label("temperature");
+        colorbar.draw();
+        let b: &str = "cmap=get_cmap('viridis')\n\
+                       norm=mcl.BoundaryNorm([0,1,2,],cmap.N)\n\
+                       sm=plt.cm.ScalarMappable(norm=norm,cmap=cmap)\n\
+                       sm.set_array([])\n\
+                       ticks=np.array([0,2,],dtype=float)\n\
+                       axs=plt.gcf().axes\n\
+                       cb=plt.colorbar(sm,ax=[axs[0],axs[2],],ticks=ticks)\n\
+                       cb.set_label(r'temperature')\n";
+        assert_eq!(colorbar.buffer, b);
+        colorbar.set_limits(-1.0, 1.0);
+        assert_eq!(format!("{:?}", colorbar.norm), "Some(Linear(Some(-1.0), Some(1.0)))");
+    }
+}
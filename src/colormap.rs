@@ -0,0 +1,334 @@
+use super::{Color, StrError};
+use std::fmt::Write;
+
+/// Holds the definition of a colormap
+///
+/// A colormap may be one of the pre-defined colormaps in [crate::PYTHON_HEADER] (selected by index),
+/// a Matplotlib colormap (selected by name), or a custom colormap built from a list of color stops.
+/// Afterwards, the colormap may be truncated, reversed, and discretized into a number of bins
+/// (in this order).
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Colormap, Contour, Norm, Plot, StrError};
+/// use russell_lab::generate3d;
+///
+/// fn main() -> Result<(), StrError> {
+///     // generate (x,y,z) matrices
+///     let n = 21;
+///     let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x - y * y);
+///
+///     // colormap from color stops, discretized into 8 bins
+///     let mut colormap = Colormap::from_stops(&[(0.0, "#2166ac"), (0.5, "#f7f7f7"), (1.0, "#b2182b")])?;
+///     colormap.set_bins(8);
+///
+///     // configure contour
+///     let mut contour = Contour::new();
+///     contour
+///         .set_colormap(&colormap)
+///         .set_norm(&Norm::TwoSlope(0.0, None, None));
+///
+///     // draw contour
+///     contour.draw(&x, &y, &z);
+///
+///     // add contour to plot
+///     let mut plot = Plot::new();
+///     plot.add(&contour).set_labels("x", "y");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_colormap.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_colormap.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_colormap.svg)
+#[derive(Clone, Debug)]
+pub struct Colormap {
+    index: usize,                  // Index of a pre-defined colormap (if name and stops are empty)
+    name: String,                  // Name of a Matplotlib colormap (if stops is empty)
+    stops: Vec<(f64, String)>,     // Color stops (value in [0,1], color)
+    truncated: Option<(f64, f64)>, // Truncate the colormap to this range within [0,1]
+    reversed: bool,                // Reverse the colormap
+    bins: usize,                   // Number of bins (discrete colors); 0 means continuous
+}
+
+impl Colormap {
+    /// Creates a new Colormap using the index of one of the pre-defined colormaps
+    ///
+    /// Options:
+    ///
+    /// * 0 -- bwr
+    /// * 1 -- RdBu
+    /// * 2 -- hsv
+    /// * 3 -- jet
+    /// * 4 -- terrain
+    /// * 5 -- pink
+    /// * 6 -- Greys
+    /// * `>`6 -- starts over from 0
+    pub fn from_index(index: usize) -> Self {
+        Colormap {
+            index,
+            name: String::new(),
+            stops: Vec::new(),
+            truncated: None,
+            reversed: false,
+            bins: 0,
+        }
+    }
+
+    /// Creates a new Colormap using the name of a Matplotlib colormap
+    ///
+    /// Colormap names:
+    ///
+    /// * see <https://matplotlib.org/stable/tutorials/colors/colormaps.html>
+    pub fn from_name(name: &str) -> Self {
+        let mut colormap = Colormap::from_index(0);
+        colormap.name = String::from(name);
+        colormap
+    }
+
+    /// Creates a new Colormap by interpolating evenly spaced colors
+    ///
+    /// The first color corresponds to the minimum value and the last color to the maximum value.
+    /// The colors are validated as explained in [Color].
+    pub fn from_colors(colors: &[&str]) -> Result<Self, StrError> {
+        if colors.len() < 2 {
+            return Err("the number of colors must be ≥ 2");
+        }
+        let den = (colors.len() - 1) as f64;
+        let stops: Vec<_> = colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| ((i as f64) / den, *color))
+            .collect();
+        Colormap::from_stops(&stops)
+    }
+
+    /// Creates a new Colormap by interpolating colors given at stops (values in \[0, 1\])
+    ///
+    /// The stops must be increasing, the first stop must be 0.0 and the last stop must be 1.0.
+    /// The colors are validated as explained in [Color].
+    pub fn from_stops(stops: &[(f64, &str)]) -> Result<Self, StrError> {
+        if stops.len() < 2 {
+            return Err("the number of stops must be ≥ 2");
+        }
+        if stops[0].0 != 0.0 {
+            return Err("the first stop must be 0.0");
+        }
+        if stops[stops.len() - 1].0 != 1.0 {
+            return Err("the last stop must be 1.0");
+        }
+        for i in 1..stops.len() {
+            if stops[i].0 < stops[i - 1].0 {
+                return Err("the stops must be increasing");
+            }
+        }
+        let mut colormap = Colormap::from_index(0);
+        for (value, color) in stops {
+            colormap.stops.push((*value, Color::parse(color)?.to_string()));
+        }
+        Ok(colormap)
+    }
+
+    /// Sets option to reverse the colormap
+    pub fn set_reversed(&mut self, flag: bool) -> &mut Self {
+        self.reversed = flag;
+        self
+    }
+
+    /// Truncates the colormap to a range within \[0, 1\]
+    ///
+    /// For example, `set_truncated(0.2, 0.8)` removes the first and last 20% of the colors.
+    pub fn set_truncated(&mut self, min: f64, max: f64) -> Result<&mut Self, StrError> {
+        if min < 0.0 || max > 1.0 || min >= max {
+            return Err("the truncation range must satisfy 0 ≤ min < max ≤ 1");
+        }
+        self.truncated = Some((min, max));
+        Ok(self)
+    }
+
+    /// Sets the number of bins to discretize the colormap (0 means continuous)
+    pub fn set_bins(&mut self, bins: usize) -> &mut Self {
+        self.bins = bins;
+        self
+    }
+
+    /// Returns the Python expression that generates this colormap
+    pub fn to_python(&self) -> String {
+        let mut cmap = String::new();
+        if !self.stops.is_empty() {
+            cmap.push_str("mcl.LinearSegmentedColormap.from_list('plotpy',[");
+            for (value, color) in &self.stops {
+                write!(&mut cmap, "({},'{}'),", value, color).unwrap();
+            }
+            cmap.push_str("])");
+        } else if !self.name.is_empty() {
+            write!(&mut cmap, "get_cmap('{}')", self.name).unwrap();
+        } else {
+            write!(&mut cmap, "get_colormap({})", self.index).unwrap();
+        }
+        if let Some((min, max)) = self.truncated {
+            cmap = format!(
+                "mcl.LinearSegmentedColormap.from_list('plotpy',{}(np.linspace({},{},256)))",
+                cmap, min, max
+            );
+        }
+        if self.reversed {
+            cmap.push_str(".reversed()");
+        }
+        if self.bins > 0 {
+            cmap = format!("mcl.ListedColormap({}(np.linspace(0,1,{})))", cmap, self.bins);
+        }
+        cmap
+    }
+}
+
+/// Defines how data values are mapped to the [0, 1] range of a colormap
+///
+/// The limits `vmin` and `vmax` are automatically computed from data if `None`.
+///
+/// Reference: [Matplotlib](https://matplotlib.org/stable/tutorials/colors/colormapnorms.html)
+#[derive(Clone, Debug)]
+pub enum Norm {
+    /// Linear mapping from (vmin, vmax) to [0, 1]
+    Linear(Option<f64>, Option<f64>),
+
+    /// Logarithmic mapping from (vmin, vmax) to [0, 1]; all values must be positive
+    Log(Option<f64>, Option<f64>),
+
+    /// Two linear mappings with different slopes around a center value; (vcenter, vmin, vmax)
+    ///
+    /// Useful to center a diverging colormap at zero, e.g., `Norm::TwoSlope(0.0, None, None)`
+    TwoSlope(f64, Option<f64>, Option<f64>),
+
+    /// Maps values to discrete colors according to the boundaries of each level
+    Boundary(Vec<f64>),
+}
+
+impl Norm {
+    /// Returns the Python expression that generates this norm
+    ///
+    /// # Input
+    ///
+    /// * `ncolors` -- Python expression with the number of colors in the colormap (used by the Boundary norm only)
+    pub(crate) fn to_python(&self, ncolors: &str) -> String {
+        let limit = |v: &Option<f64>| match v {
+            Some(value) => format!("{}", value),
+            None => "None".to_string(),
+        };
+        match self {
+            Norm::Linear(vmin, vmax) => format!("mcl.Normalize(vmin={},vmax={})", limit(vmin), limit(vmax)),
+            Norm::Log(vmin, vmax) => format!("mcl.LogNorm(vmin={},vmax={})", limit(vmin), limit(vmax)),
+            Norm::TwoSlope(vcenter, vmin, vmax) => format!(
+                "mcl.TwoSlopeNorm({},vmin={},vmax={})",
+                vcenter,
+                limit(vmin),
+                limit(vmax)
+            ),
+            Norm::Boundary(boundaries) => {
+                let mut norm = String::from("mcl.BoundaryNorm([");
+                for value in boundaries {
+                    write!(&mut norm, "{},", value).unwrap();
+                }
+                write!(&mut norm, "],{})", ncolors).unwrap();
+                norm
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Colormap, Norm};
+
+    #[test]
+    fn derive_works() {
+        let colormap = Colormap::from_name("jet");
+        let clone = colormap.clone();
+        assert_eq!(clone.to_python(), "get_cmap('jet')");
+        assert!(format!("{:?}", colormap).starts_with("Colormap"));
+        let norm = Norm::Log(Some(1.0), None);
+        let clone = norm.clone();
+        assert_eq!(format!("{:?}", clone), "Log(Some(1.0), None)");
+    }
+
+    #[test]
+    fn from_functions_capture_errors() {
+        assert_eq!(
+            Colormap::from_colors(&["red"]).err(),
+            Some("the number of colors must be ≥ 2")
+        );
+        assert_eq!(
+            Colormap::from_stops(&[(0.0, "red")]).err(),
+            Some("the number of stops must be ≥ 2")
+        );
+        assert_eq!(
+            Colormap::from_stops(&[(0.1, "red"), (1.0, "blue")]).err(),
+            Some("the first stop must be 0.0")
+        );
+        assert_eq!(
+            Colormap::from_stops(&[(0.0, "red"), (0.9, "blue")]).err(),
+            Some("the last stop must be 1.0")
+        );
+        assert_eq!(
+            Colormap::from_stops(&[(0.0, "red"), (0.6, "white"), (0.5, "white"), (1.0, "blue")]).err(),
+            Some("the stops must be increasing")
+        );
+        assert_eq!(
+            Colormap::from_colors(&["red", "bluee"]).err(),
+            Some("invalid color: unknown color name")
+        );
+        let mut colormap = Colormap::from_index(0);
+        assert_eq!(
+            colormap.set_truncated(0.5, 0.5).err(),
+            Some("the truncation range must satisfy 0 ≤ min < max ≤ 1")
+        );
+    }
+
+    #[test]
+    fn to_python_works() {
+        assert_eq!(Colormap::from_index(3).to_python(), "get_colormap(3)");
+        assert_eq!(Colormap::from_name("viridis").to_python(), "get_cmap('viridis')");
+        let colormap = Colormap::from_colors(&["red", "white", "blue"]).unwrap();
+        assert_eq!(
+            colormap.to_python(),
+            "mcl.LinearSegmentedColormap.from_list('plotpy',[(0,'red'),(0.5,'white'),(1,'blue'),])"
+        );
+        let colormap = Colormap::from_stops(&[(0.0, "rgb(255,0,0)"), (1.0, "C0")]).unwrap();
+        assert_eq!(
+            colormap.to_python(),
+            "mcl.LinearSegmentedColormap.from_list('plotpy',[(0,'#ff0000'),(1,'C0'),])"
+        );
+        let mut colormap = Colormap::from_name("terrain");
+        colormap.set_truncated(0.2, 0.8).unwrap().set_reversed(true).set_bins(5);
+        assert_eq!(
+            colormap.to_python(),
+            "mcl.ListedColormap(\
+             mcl.LinearSegmentedColormap.from_list('plotpy',get_cmap('terrain')(np.linspace(0.2,0.8,256)))\
+             .reversed()(np.linspace(0,1,5)))"
+        );
+    }
+
+    #[test]
+    fn norm_to_python_works() {
+        assert_eq!(
+            Norm::Linear(None, Some(2.0)).to_python("cmap.N"),
+            "mcl.Normalize(vmin=None,vmax=2)"
+        );
+        assert_eq!(
+            Norm::Log(Some(0.1), Some(10.0)).to_python("cmap.N"),
+            "mcl.LogNorm(vmin=0.1,vmax=10)"
+        );
+        assert_eq!(
+            Norm::TwoSlope(0.0, Some(-1.0), None).to_python("cmap.N"),
+            "mcl.TwoSlopeNorm(0,vmin=-1,vmax=None)"
+        );
+        assert_eq!(
+            Norm::Boundary(vec![0.0, 0.5, 1.0]).to_python("cmap.N"),
+            "mcl.BoundaryNorm([0,0.5,1,],cmap.N)"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use super::StrError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Defines how the connecting line of an annotation is drawn (with parameters)
+///
+/// Parameters equal to the Matplotlib defaults (shown below) are omitted from the generated code.
+///
+/// Setters accepting connection styles (e.g., [crate::Annotation::set_connection_style]) take a
+/// `ConnectionStyle`; hence, invalid styles are caught at compile time. The Matplotlib names "arc3",
+/// "angle3", "angle", and "arc" may be converted by `str::parse` (with the default parameters),
+/// which returns an error for invalid strings.
+///
+/// See [Matplotlib](https://matplotlib.org/stable/api/_as_gen/matplotlib.patches.ConnectionStyle.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConnectionStyle {
+    /// "`arc3`" (straight line or simple curve) with (rad = 0.0), the curvature
+    Arc3(f64),
+
+    /// "`angle3`" (quadratic Bézier curve) with (angleA = 90.0, angleB = 0.0)
+    Angle3(f64, f64),
+
+    /// "`angle`" (two straight segments) with (angleA = 90.0, angleB = 0.0, rad = 0.0)
+    Angle(f64, f64, f64),
+
+    /// "`arc`" (arms and arcs) with (angleA = 0.0, angleB = 0.0, armA = 0.0, armB = 0.0, rad = 0.0)
+    ///
+    /// A zero arm length means no arm.
+    Arc(f64, f64, f64, f64, f64),
+}
+
+impl fmt::Display for ConnectionStyle {
+    /// Writes the option passed to Matplotlib (without quotes), e.g., "arc3,rad=0.2"
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, params): (&str, Vec<(&str, f64, f64)>) = match *self {
+            ConnectionStyle::Arc3(r) => ("arc3", vec![("rad", r, 0.0)]),
+            ConnectionStyle::Angle3(a, b) => ("angle3", vec![("angleA", a, 90.0), ("angleB", b, 0.0)]),
+            ConnectionStyle::Angle(a, b, r) => {
+                ("angle", vec![("angleA", a, 90.0), ("angleB", b, 0.0), ("rad", r, 0.0)])
+            }
+            ConnectionStyle::Arc(a, b, arm_a, arm_b, r) => (
+                "arc",
+                vec![
+                    ("angleA", a, 0.0),
+                    ("angleB", b, 0.0),
+                    ("armA", arm_a, 0.0),
+                    ("armB", arm_b, 0.0),
+                    ("rad", r, 0.0),
+                ],
+            ),
+        };
+        write!(f, "{}", name)?;
+        for (key, value, default) in params {
+            if value != default {
+                write!(f, ",{}={}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ConnectionStyle {
+    type Err = StrError;
+    fn from_str(style: &str) -> Result<Self, Self::Err> {
+        match style {
+            "arc3" => Ok(ConnectionStyle::Arc3(0.0)),
+            "angle3" => Ok(ConnectionStyle::Angle3(90.0, 0.0)),
+            "angle" => Ok(ConnectionStyle::Angle(90.0, 0.0, 0.0)),
+            "arc" => Ok(ConnectionStyle::Arc(0.0, 0.0, 0.0, 0.0, 0.0)),
+            _ => Err("invalid connection style"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ConnectionStyle;
+
+    #[test]
+    fn display_works() {
+        assert_eq!(ConnectionStyle::Arc3(0.0).to_string(), "arc3");
+        assert_eq!(ConnectionStyle::Arc3(-0.3).to_string(), "arc3,rad=-0.3");
+        assert_eq!(
+            ConnectionStyle::Angle3(0.0, 90.0).to_string(),
+            "angle3,angleA=0,angleB=90"
+        );
+        assert_eq!(ConnectionStyle::Angle(90.0, 0.0, 5.0).to_string(), "angle,rad=5");
+        assert_eq!(
+            ConnectionStyle::Arc(-90.0, 0.0, 30.0, 0.0, 10.0).to_string(),
+            "arc,angleA=-90,armA=30,rad=10"
+        );
+    }
+
+    #[test]
+    fn from_str_works() {
+        for style in ["arc3", "angle3", "angle", "arc"] {
+            assert_eq!(style.parse::<ConnectionStyle>().unwrap().to_string(), style);
+        }
+        assert_eq!("bar".parse::<ConnectionStyle>().err(), Some("invalid connection style"));
+    }
+}
@@ -11,6 +11,8 @@
 /// * `add_to_ea` -- Adds an entity to the EXTRA_ARTISTS list to prevent them being ignored
 ///    when Matplotlib decides to calculate the bounding boxes. The Legend is an example of entity that could
 ///    be ignored during by the savefig command (this is issue is prevented here).
+/// * `get_cmap` -- Returns a colormap given its name. The registry `matplotlib.colormaps` is used if available
+///   (Matplotlib ≥ 3.5); otherwise, `plt.get_cmap` is used. See also [crate::TargetVersions]
 /// * `maybe_create_ax3d` -- If AX3D is None, allocates a new mplot3d (Matplotlib's 3D plotting capability)
 /// * `data_to_axis` -- Transforms data limits to axis limits
 /// * `axis_to_data` -- Transforms axis limits to data limits
@@ -21,19 +23,24 @@
 /// * TODO: find a way to pass down the option `proj_type = 'ortho'` to AX3D
 pub const PYTHON_HEADER: &str = "### file generated by plotpy
 import numpy as np
+import matplotlib as mpl
 import matplotlib.pyplot as plt
 import matplotlib.ticker as tck
+import matplotlib.colors as mcl
 import matplotlib.patches as pat
 import matplotlib.path as pth
 import matplotlib.patheffects as pff
 import matplotlib.lines as lns
 import matplotlib.transforms as tra
 import mpl_toolkits.mplot3d as m3d
-NaN = np.NaN
+NaN = np.nan
 EXTRA_ARTISTS = []
 def add_to_ea(obj):
     if obj!=None: EXTRA_ARTISTS.append(obj)
-COLORMAPS = [plt.cm.bwr, plt.cm.RdBu, plt.cm.hsv, plt.cm.jet, plt.cm.terrain, plt.cm.pink, plt.cm.Greys]
+def get_cmap(name):
+    if hasattr(mpl, 'colormaps'): return mpl.colormaps[name]
+    return plt.get_cmap(name)
+COLORMAPS = [get_cmap(n) for n in ['bwr', 'RdBu', 'hsv', 'jet', 'terrain', 'pink', 'Greys']]
 def get_colormap(idx): return COLORMAPS[idx % len(COLORMAPS)]
 AX3D = None
 def maybe_create_ax3d():
@@ -70,14 +77,21 @@ def set_equal_axes():
         print('ERROR: set_box_aspect is missing in this version of Matplotlib')
 ";
 
+/// Definition of `get_cmap` in [PYTHON_HEADER] (selecting the implementation at runtime)
+pub(crate) const GET_CMAP_AUTO: &str = "def get_cmap(name):
+    if hasattr(mpl, 'colormaps'): return mpl.colormaps[name]
+    return plt.get_cmap(name)
+";
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::PYTHON_HEADER;
+    use super::{GET_CMAP_AUTO, PYTHON_HEADER};
 
     #[test]
     fn constants_are_correct() {
-        assert_eq!(PYTHON_HEADER.len(), 1768);
+        assert_eq!(PYTHON_HEADER.len(), 1924);
+        assert!(PYTHON_HEADER.contains(GET_CMAP_AUTO));
     }
 }
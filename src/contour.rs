@@ -1,4 +1,7 @@
-use super::{matrix_to_array, vector_to_array, vector_to_strings, AsMatrix, GraphMaker};
+use super::{
+    color_to_spec, matrix_to_array, vector_to_array, vector_to_strings, AsMatrix, Color, Colormap, GraphMaker,
+    LineStyle, Norm, StrError,
+};
 use std::fmt::Write;
 
 /// Generates a contour plot
@@ -45,26 +48,33 @@ use std::fmt::Write;
 ///
 /// ![integ_contour.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_contour.svg)
 pub struct Contour {
-    colors: Vec<String>,         // Colors to be used instead of colormap
-    levels: Vec<f64>,            // Pre-defined levels
-    colormap_index: usize,       // Colormap index
-    colormap_name: String,       // Colormap name
-    no_lines: bool,              // Skip drawing a lines contour
-    no_labels: bool,             // Skip adding labels to the lines contour
-    no_inline_labels: bool,      // Do not draw labels inline
-    no_colorbar: bool,           // Skip drawing a colorbar
-    colorbar_label: String,      // Colorbar label
-    number_format_cb: String,    // Number format for the labels in lines contour
-    line_color: String,          // Line color for the lines contour
-    line_style: String,          // Line style for the lines contour
-    line_width: f64,             // Line width for the lines contour
-    fontsize_labels: f64,        // Font size for labels
-    with_selected: bool,         // Draw a line contour with a selected level
-    selected_level: f64,         // Selected level (e.g., 0.0)
-    selected_line_color: String, // Color to mark the selected level
-    selected_line_style: String, // Line style for the selected level
-    selected_line_width: f64,    // Line width for the selected level
-    buffer: String,              // buffer
+    colors: Vec<String>,              // Colors to be used instead of colormap
+    levels: Vec<f64>,                 // Pre-defined levels
+    colormap_index: usize,            // Colormap index
+    colormap_name: String,            // Colormap name
+    colormap: Option<Colormap>,       // Custom colormap (overrides colormap index and name)
+    norm: Option<Norm>,               // Normalization of data values into the colormap
+    color_limits: Option<(f64, f64)>, // Limits (vmin, vmax) of data values mapped into the colormap
+    hatches: Vec<String>,             // Hatch patterns of the filled levels
+    fill_alpha: f64,                  // Opacity of the filled contour
+    zorder: Option<f64>,              // Drawing order
+    no_lines: bool,                   // Skip drawing a lines contour
+    no_labels: bool,                  // Skip adding labels to the lines contour
+    no_inline_labels: bool,           // Do not draw labels inline
+    no_colorbar: bool,                // Skip drawing a colorbar
+    colorbar_label: String,           // Colorbar label
+    number_format_cb: String,         // Number format for the labels in lines contour
+    line_color: String,               // Line color for the lines contour
+    line_style: String,               // Line style for the lines contour
+    line_width: f64,                  // Line width for the lines contour
+    fontsize_labels: f64,             // Font size for labels
+    with_selected: bool,              // Draw a line contour with a selected level
+    selected_level: f64,              // Selected level (e.g., 0.0)
+    selected_line_color: String,      // Color to mark the selected level
+    selected_line_style: String,      // Line style for the selected level
+    selected_line_width: f64,         // Line width for the selected level
+    error: Option<StrError>,          // First error found in the options (e.g., an invalid color)
+    buffer: String,                   // buffer
 }
 
 impl Contour {
@@ -75,6 +85,12 @@ impl Contour {
             levels: Vec::new(),
             colormap_index: 0,
             colormap_name: String::new(),
+            colormap: None,
+            norm: None,
+            color_limits: None,
+            hatches: Vec::new(),
+            fill_alpha: 0.0,
+            zorder: None,
             no_lines: false,
             no_labels: false,
             no_inline_labels: false,
@@ -88,8 +104,9 @@ impl Contour {
             with_selected: false,
             selected_level: 0.0,
             selected_line_color: "yellow".to_string(),
-            selected_line_style: "-".to_string(),
+            selected_line_style: "'-'".to_string(),
             selected_line_width: 2.0,
+            error: None,
             buffer: String::new(),
         }
     }
@@ -125,6 +142,17 @@ impl Contour {
         matrix_to_array(&mut self.buffer, "z", z);
         if self.colors.len() > 0 {
             vector_to_strings(&mut self.buffer, "colors", &self.colors);
+        } else if self.with_cmap_variable() {
+            let cmap = self.colormap_expr();
+            writeln!(&mut self.buffer, "cmap={}", cmap).unwrap();
+        }
+        if let Some(norm) = &self.norm {
+            let ncolors = if self.colors.is_empty() {
+                "cmap.N"
+            } else {
+                "len(colors)"
+            };
+            writeln!(&mut self.buffer, "norm={}", norm.to_python(ncolors)).unwrap();
         }
         if self.levels.len() > 0 {
             vector_to_array(&mut self.buffer, "levels", &self.levels);
@@ -155,8 +183,14 @@ impl Contour {
     /// Sets the colors to be used instead of a pre-defined colormap
     ///
     /// Will use `colormap_index` instead if its empty.
-    pub fn set_colors(&mut self, colors: &[&str]) -> &mut Self {
-        self.colors = colors.iter().map(|color| color.to_string()).collect();
+    pub fn set_colors<C>(&mut self, colors: &[C]) -> &mut Self
+    where
+        C: Clone + Into<Color>,
+    {
+        self.colors = colors
+            .iter()
+            .map(|color| color_to_spec(color.clone(), &mut self.error))
+            .collect();
         self
     }
 
@@ -182,6 +216,7 @@ impl Contour {
         self.colors = Vec::new();
         self.colormap_index = index;
         self.colormap_name = String::new();
+        self.colormap = None;
         self
     }
 
@@ -194,6 +229,59 @@ impl Contour {
     /// Will use `colormap_index` instead if `colormap_name` is empty.
     pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
         self.colormap_name = String::from(name);
+        self.colormap = None;
+        self
+    }
+
+    /// Sets a custom colormap (overrides the colormap index and name)
+    ///
+    /// See [Colormap]
+    pub fn set_colormap(&mut self, colormap: &Colormap) -> &mut Self {
+        self.colormap = Some(colormap.clone());
+        self
+    }
+
+    /// Sets the normalization of data values into the colormap
+    ///
+    /// See [Norm]
+    pub fn set_norm(&mut self, norm: &Norm) -> &mut Self {
+        self.norm = Some(norm.clone());
+        self
+    }
+
+    /// Sets the limits (vmin, vmax) of data values mapped into the colormap
+    ///
+    /// Use the same limits in several contours (e.g., in subplots) to share the color range.
+    /// See also [crate::Colorbar].
+    ///
+    /// **Note:** This option is ignored if a norm is set.
+    pub fn set_color_limits(&mut self, vmin: f64, vmax: f64) -> &mut Self {
+        self.color_limits = Some((vmin, vmax));
+        self
+    }
+
+    /// Sets the hatch patterns of the filled levels
+    ///
+    /// The patterns are cycled if there are more levels than patterns. An empty string means no hatch.
+    /// See [crate::Canvas::set_hatch] for the options.
+    ///
+    /// For grayscale figures, combine the hatches with light colors (see [Contour::set_colors]).
+    pub fn set_hatches(&mut self, hatches: &[&str]) -> &mut Self {
+        self.hatches = hatches.iter().map(|hatch| hatch.replace('\\', "\\\\")).collect();
+        self
+    }
+
+    /// Sets the opacity of the filled contour in (0, 1]
+    ///
+    /// A zero value means that the opacity is not set (i.e., the Matplotlib default is used).
+    pub fn set_fill_alpha(&mut self, alpha: f64) -> &mut Self {
+        self.fill_alpha = alpha;
+        self
+    }
+
+    /// Sets the drawing order of the filled, line, and selected contours
+    pub fn set_zorder(&mut self, zorder: f64) -> &mut Self {
+        self.zorder = Some(zorder);
         self
     }
 
@@ -234,8 +322,8 @@ impl Contour {
     }
 
     /// Sets the line color for the lines contour (default is black)
-    pub fn set_line_color(&mut self, color: &str) -> &mut Self {
-        self.line_color = String::from(color);
+    pub fn set_line_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.line_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -243,9 +331,10 @@ impl Contour {
     ///
     /// Options:
     ///
-    /// * "`-`", "`:`", "`--`", "`-.`"
-    pub fn set_line_style(&mut self, style: &str) -> &mut Self {
-        self.line_style = String::from(style);
+    /// * [LineStyle::Solid], [LineStyle::Dotted], [LineStyle::Dashed], [LineStyle::DashDot]
+    /// * See [LineStyle] (including custom dashes and the default style)
+    pub fn set_line_style(&mut self, style: impl Into<LineStyle>) -> &mut Self {
+        self.line_style = style.into().to_option();
         self
     }
 
@@ -271,8 +360,8 @@ impl Contour {
     }
 
     /// Sets the color to mark the selected level
-    pub fn set_selected_line_color(&mut self, color: &str) -> &mut Self {
-        self.selected_line_color = String::from(color);
+    pub fn set_selected_line_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.selected_line_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -280,9 +369,10 @@ impl Contour {
     ///
     /// Options:
     ///
-    /// * "`-`", "`:`", "`--`", "`-.`"
-    pub fn set_selected_line_style(&mut self, style: &str) -> &mut Self {
-        self.selected_line_style = String::from(style);
+    /// * [LineStyle::Solid], [LineStyle::Dotted], [LineStyle::Dashed], [LineStyle::DashDot]
+    /// * See [LineStyle] (including custom dashes and the default style)
+    pub fn set_selected_line_style(&mut self, style: impl Into<LineStyle>) -> &mut Self {
+        self.selected_line_style = style.into().to_option();
         self
     }
 
@@ -292,24 +382,68 @@ impl Contour {
         self
     }
 
+    /// Returns true if the colormap must be written to the "cmap" variable (custom colormap or norm)
+    fn with_cmap_variable(&self) -> bool {
+        self.colormap.is_some() || self.norm.is_some()
+    }
+
+    /// Returns the Python expression of the colormap
+    fn colormap_expr(&self) -> String {
+        match &self.colormap {
+            Some(colormap) => colormap.to_python(),
+            None => {
+                if !self.colormap_name.is_empty() {
+                    format!("get_cmap('{}')", self.colormap_name)
+                } else {
+                    format!("get_colormap({})", self.colormap_index)
+                }
+            }
+        }
+    }
+
     /// Returns options for filled contour
     fn options_filled(&self) -> String {
         let mut opt = String::new();
         if self.colors.len() > 0 {
             write!(&mut opt, ",colors=colors",).unwrap();
+        } else if self.with_cmap_variable() {
+            write!(&mut opt, ",cmap=cmap").unwrap();
         } else {
-            if self.colormap_name != "" {
-                write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
-            } else {
-                write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
-            }
+            write!(&mut opt, ",cmap={}", self.colormap_expr()).unwrap();
+        }
+        if self.norm.is_some() {
+            write!(&mut opt, ",norm=norm").unwrap();
+        } else if let Some((vmin, vmax)) = self.color_limits {
+            write!(&mut opt, ",vmin={},vmax={}", vmin, vmax).unwrap();
         }
         if self.levels.len() > 0 {
             write!(&mut opt, ",levels=levels").unwrap();
         }
+        if !self.hatches.is_empty() {
+            opt.push_str(",hatches=[");
+            for hatch in &self.hatches {
+                if hatch.is_empty() {
+                    opt.push_str("None,");
+                } else {
+                    write!(&mut opt, "'{}',", hatch).unwrap();
+                }
+            }
+            opt.push(']');
+        }
+        if self.fill_alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.fill_alpha).unwrap();
+        }
+        self.option_zorder(&mut opt);
         opt
     }
 
+    /// Writes the zorder option (if set)
+    fn option_zorder(&self, opt: &mut String) {
+        if let Some(zorder) = self.zorder {
+            write!(opt, ",zorder={}", zorder).unwrap();
+        }
+    }
+
     /// Returns options for line contour
     fn options_line(&self) -> String {
         let mut opt = String::new();
@@ -320,11 +454,12 @@ impl Contour {
             write!(&mut opt, ",levels=levels").unwrap();
         }
         if self.line_style != "" {
-            write!(&mut opt, ",linestyles=['{}']", self.line_style).unwrap();
+            write!(&mut opt, ",linestyles=[{}]", self.line_style).unwrap();
         }
         if self.line_width > 0.0 {
             write!(&mut opt, ",linewidths=[{}]", self.line_width).unwrap();
         }
+        self.option_zorder(&mut opt);
         opt
     }
 
@@ -351,6 +486,34 @@ impl Contour {
         opt
     }
 
+    /// Returns the pre-defined levels (may be empty)
+    pub(crate) fn get_levels(&self) -> &[f64] {
+        &self.levels
+    }
+
+    /// Returns options (without levels) for contours projected onto the walls of a 3D plot
+    ///
+    /// **Note:** The line color is not used because each projected level is colored by the colormap (or colors).
+    pub(crate) fn options_projected(&self) -> String {
+        let mut opt = String::new();
+        if !self.colors.is_empty() {
+            opt.push_str(",colors=[");
+            for color in &self.colors {
+                write!(&mut opt, "'{}',", color).unwrap();
+            }
+            opt.push(']');
+        } else {
+            write!(&mut opt, ",cmap={}", self.colormap_expr()).unwrap();
+        }
+        if !self.line_style.is_empty() {
+            write!(&mut opt, ",linestyles=[{}]", self.line_style).unwrap();
+        }
+        if self.line_width > 0.0 {
+            write!(&mut opt, ",linewidths=[{}]", self.line_width).unwrap();
+        }
+        opt
+    }
+
     /// Returns options for selected line contour
     fn options_selected(&self) -> String {
         let mut opt = String::new();
@@ -359,11 +522,12 @@ impl Contour {
         }
         write!(&mut opt, ",levels=[{}]", self.selected_level).unwrap();
         if self.selected_line_style != "" {
-            write!(&mut opt, ",linestyles=['{}']", self.selected_line_style).unwrap();
+            write!(&mut opt, ",linestyles=[{}]", self.selected_line_style).unwrap();
         }
         if self.selected_line_width > 0.0 {
             write!(&mut opt, ",linewidths=[{}]", self.selected_line_width).unwrap();
         }
+        self.option_zorder(&mut opt);
         opt
     }
 }
@@ -375,6 +539,9 @@ impl GraphMaker for Contour {
     fn clear_buffer(&mut self) {
         self.buffer.clear();
     }
+    fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -382,7 +549,7 @@ impl GraphMaker for Contour {
 #[cfg(test)]
 mod tests {
     use super::Contour;
-    use crate::GraphMaker;
+    use crate::{Colormap, GraphMaker, LineStyle, Norm};
     use russell_lab::Matrix;
 
     #[test]
@@ -392,6 +559,12 @@ mod tests {
         assert_eq!(contour.levels.len(), 0);
         assert_eq!(contour.colormap_index, 0);
         assert_eq!(contour.colormap_name.len(), 0);
+        assert!(contour.colormap.is_none());
+        assert!(contour.norm.is_none());
+        assert_eq!(contour.color_limits, None);
+        assert_eq!(contour.hatches.len(), 0);
+        assert_eq!(contour.fill_alpha, 0.0);
+        assert_eq!(contour.zorder, None);
         assert_eq!(contour.no_lines, false);
         assert_eq!(contour.no_labels, false);
         assert_eq!(contour.no_inline_labels, false);
@@ -405,7 +578,7 @@ mod tests {
         assert_eq!(contour.with_selected, false);
         assert_eq!(contour.selected_level, 0.0);
         assert_eq!(contour.selected_line_color, "yellow".to_string());
-        assert_eq!(contour.selected_line_style, "-".to_string());
+        assert_eq!(contour.selected_line_style, "'-'".to_string());
         assert_eq!(contour.selected_line_width, 2.0);
         assert_eq!(contour.buffer.len(), 0);
     }
@@ -431,13 +604,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn options_filled_with_hatches_works() {
+        let mut contour = Contour::new();
+        contour
+            .set_colors(&["white"])
+            .set_hatches(&["", "//", "\\", "x"])
+            .set_fill_alpha(0.5)
+            .set_zorder(-1.0);
+        assert_eq!(
+            contour.options_filled(),
+            ",colors=colors\
+             ,hatches=[None,'//','\\\\','x',]\
+             ,alpha=0.5\
+             ,zorder=-1"
+        );
+        assert_eq!(contour.options_line(), ",colors=['black'],zorder=-1");
+        assert_eq!(
+            contour.options_selected(),
+            ",colors=['yellow'],levels=[0],linestyles=['-'],linewidths=[2],zorder=-1"
+        );
+    }
+
+    #[test]
+    fn options_filled_with_colormap_and_norm_works() {
+        let mut contour = Contour::new();
+        contour.set_colormap(&Colormap::from_name("viridis"));
+        assert_eq!(contour.colormap_expr(), "get_cmap('viridis')");
+        let opt = contour.options_filled();
+        assert_eq!(opt, ",cmap=cmap");
+        contour.set_norm(&Norm::Log(None, None));
+        let opt = contour.options_filled();
+        assert_eq!(opt, ",cmap=cmap,norm=norm");
+        contour.set_colormap_name("terrain");
+        assert_eq!(contour.colormap_expr(), "get_cmap('terrain')");
+    }
+
+    #[test]
+    fn options_filled_with_color_limits_works() {
+        let mut contour = Contour::new();
+        contour.set_color_limits(-1.0, 2.5);
+        let opt = contour.options_filled();
+        assert_eq!(opt, ",cmap=get_colormap(0),vmin=-1,vmax=2.5");
+        contour.set_norm(&Norm::Linear(None, None));
+        let opt = contour.options_filled();
+        assert_eq!(opt, ",cmap=cmap,norm=norm");
+    }
+
     #[test]
     fn options_line_works() {
         let mut contour = Contour::new();
         contour
             .set_levels(&vec![0.25, 0.5, 1.0])
             .set_line_color("red")
-            .set_line_style(":")
+            .set_line_style(LineStyle::Dotted)
             .set_line_width(3.0);
         let opt = contour.options_line();
         assert_eq!(
@@ -482,7 +702,7 @@ mod tests {
         contour
             .set_selected_level(0.75, true)
             .set_selected_line_color("blue")
-            .set_selected_line_style("--")
+            .set_selected_line_style(LineStyle::Dashed)
             .set_selected_line_width(2.5);
         let opt = contour.options_selected();
         assert_eq!(
@@ -494,6 +714,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn options_projected_works() {
+        let mut contour = Contour::new();
+        let opt = contour.options_projected();
+        assert_eq!(opt, ",cmap=get_colormap(0)");
+        contour
+            .set_colormap_name("terrain")
+            .set_levels(&[0.5, 1.0])
+            .set_line_style(LineStyle::Dashed)
+            .set_line_width(1.5);
+        let opt = contour.options_projected();
+        assert_eq!(
+            opt,
+            ",cmap=get_cmap('terrain')\
+             ,linestyles=['--']\
+             ,linewidths=[1.5]"
+        );
+        assert_eq!(contour.get_levels(), &[0.5, 1.0]);
+        contour.set_colors(&["red", "blue"]);
+        let opt = contour.options_projected();
+        assert_eq!(
+            opt,
+            ",colors=['red','blue',]\
+             ,linestyles=['--']\
+             ,linewidths=[1.5]"
+        );
+    }
+
     #[test]
     fn draw_works() {
         let mut contour = Contour::new();
@@ -522,6 +770,31 @@ mod tests {
         assert_eq!(contour.buffer, "");
     }
 
+    #[test]
+    fn draw_with_colormap_and_norm_works() {
+        let mut colormap = Colormap::from_colors(&["blue", "white", "red"]).unwrap();
+        colormap.set_bins(4);
+        let mut contour = Contour::new();
+        contour
+            .set_colormap(&colormap)
+            .set_norm(&Norm::Boundary(vec![0.0, 0.1, 0.2, 0.3, 0.5]))
+            .set_levels(&[0.0, 0.1, 0.2, 0.3, 0.5])
+            .set_no_lines(true)
+            .set_no_colorbar(true);
+        let x = vec![vec![-0.5, 0.0, 0.5], vec![-0.5, 0.0, 0.5], vec![-0.5, 0.0, 0.5]];
+        let y = vec![vec![-0.5, -0.5, -0.5], vec![0.0, 0.0, 0.0], vec![0.5, 0.5, 0.5]];
+        let z = vec![vec![0.50, 0.25, 0.50], vec![0.25, 0.00, 0.25], vec![0.50, 0.25, 0.50]];
+        contour.draw(&x, &y, &z);
+        let b: &str = "x=np.array([[-0.5,0,0.5,],[-0.5,0,0.5,],[-0.5,0,0.5,],],dtype=float)\n\
+                       y=np.array([[-0.5,-0.5,-0.5,],[0,0,0,],[0.5,0.5,0.5,],],dtype=float)\n\
+                       z=np.array([[0.5,0.25,0.5,],[0.25,0,0.25,],[0.5,0.25,0.5,],],dtype=float)\n\
+                       cmap=mcl.ListedColormap(mcl.LinearSegmentedColormap.from_list('plotpy',[(0,'blue'),(0.5,'white'),(1,'red'),])(np.linspace(0,1,4)))\n\
+                       norm=mcl.BoundaryNorm([0,0.1,0.2,0.3,0.5,],cmap.N)\n\
+                       levels=np.array([0,0.1,0.2,0.3,0.5,],dtype=float)\n\
+                       cf=plt.contourf(x,y,z,cmap=cmap,norm=norm,levels=levels)\n";
+        assert_eq!(contour.buffer, b);
+    }
+
     #[test]
     fn draw_with_matrix_works() {
         let mut contour = Contour::new();
@@ -0,0 +1,128 @@
+use super::StrError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Defines the coordinate system of positions
+///
+/// The coordinate system may be used by [crate::Annotation], [crate::Text], [crate::Canvas] (2D shapes),
+/// and [crate::Legend] (anchor). For instance, panel letters like "(a)" and watermarks given in
+/// [CoordSystem::Axes] stay in place regardless of the data range.
+///
+/// Setters accepting the coordinate system (e.g., [crate::Text::set_coords]) take a `CoordSystem`;
+/// hence, invalid options are caught at compile time. The strings "data", "axes", "figure", "blended",
+/// and "offset" may be converted by `str::parse`, which returns an error for invalid strings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoordSystem {
+    /// Data coordinates (the default)
+    Data,
+
+    /// Axes coordinates: (0, 0) is the lower-left corner and (1, 1) is the upper-right corner of the axes
+    Axes,
+
+    /// Figure coordinates: (0, 0) is the lower-left corner and (1, 1) is the upper-right corner of the figure
+    Figure,
+
+    /// Blended coordinates: x in data coordinates and y in axes coordinates
+    ///
+    /// Useful for vertical bands and labels spanning the whole height of the axes.
+    XDataYAxes,
+
+    /// Offset in points
+    ///
+    /// For the label of [crate::Annotation], the offset is measured from the annotated point; otherwise,
+    /// the offset is measured from the lower-left corner of the axes.
+    OffsetPoints,
+}
+
+impl CoordSystem {
+    /// Returns the Python expression of the transformation from this coordinate system to display coordinates
+    pub(crate) fn transform(&self) -> &'static str {
+        match self {
+            CoordSystem::Data => "plt.gca().transData",
+            CoordSystem::Axes => "plt.gca().transAxes",
+            CoordSystem::Figure => "plt.gcf().transFigure",
+            CoordSystem::XDataYAxes => "plt.gca().get_xaxis_transform()",
+            CoordSystem::OffsetPoints => {
+                "tra.Affine2D().scale(1.0/72.0)+plt.gcf().dpi_scale_trans+tra.ScaledTranslation(0,0,plt.gca().transAxes)"
+            }
+        }
+    }
+
+    /// Returns the option used by Matplotlib's `annotate` (e.g., "'axes fraction'" with quotes)
+    pub(crate) fn annotation_coords(&self) -> &'static str {
+        match self {
+            CoordSystem::Data => "'data'",
+            CoordSystem::Axes => "'axes fraction'",
+            CoordSystem::Figure => "'figure fraction'",
+            CoordSystem::XDataYAxes => "plt.gca().get_xaxis_transform()",
+            CoordSystem::OffsetPoints => "'offset points'",
+        }
+    }
+}
+
+impl fmt::Display for CoordSystem {
+    /// Writes the name of the coordinate system (the same accepted by `str::parse`)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let option = match self {
+            CoordSystem::Data => "data",
+            CoordSystem::Axes => "axes",
+            CoordSystem::Figure => "figure",
+            CoordSystem::XDataYAxes => "blended",
+            CoordSystem::OffsetPoints => "offset",
+        };
+        write!(f, "{}", option)
+    }
+}
+
+impl FromStr for CoordSystem {
+    type Err = StrError;
+    fn from_str(option: &str) -> Result<Self, Self::Err> {
+        match option {
+            "data" => Ok(CoordSystem::Data),
+            "axes" => Ok(CoordSystem::Axes),
+            "figure" => Ok(CoordSystem::Figure),
+            "blended" => Ok(CoordSystem::XDataYAxes),
+            "offset" => Ok(CoordSystem::OffsetPoints),
+            _ => Err("invalid coordinate system"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::CoordSystem;
+
+    #[test]
+    fn display_and_from_str_work() {
+        for option in ["data", "axes", "figure", "blended", "offset"] {
+            assert_eq!(option.parse::<CoordSystem>().unwrap().to_string(), option);
+        }
+        assert_eq!("pixels".parse::<CoordSystem>().err(), Some("invalid coordinate system"));
+    }
+
+    #[test]
+    fn transform_works() {
+        assert_eq!(CoordSystem::Data.transform(), "plt.gca().transData");
+        assert_eq!(CoordSystem::Axes.transform(), "plt.gca().transAxes");
+        assert_eq!(CoordSystem::Figure.transform(), "plt.gcf().transFigure");
+        assert_eq!(CoordSystem::XDataYAxes.transform(), "plt.gca().get_xaxis_transform()");
+        assert_eq!(
+            CoordSystem::OffsetPoints.transform(),
+            "tra.Affine2D().scale(1.0/72.0)+plt.gcf().dpi_scale_trans+tra.ScaledTranslation(0,0,plt.gca().transAxes)"
+        );
+    }
+
+    #[test]
+    fn annotation_coords_works() {
+        assert_eq!(CoordSystem::Data.annotation_coords(), "'data'");
+        assert_eq!(CoordSystem::Axes.annotation_coords(), "'axes fraction'");
+        assert_eq!(CoordSystem::Figure.annotation_coords(), "'figure fraction'");
+        assert_eq!(
+            CoordSystem::XDataYAxes.annotation_coords(),
+            "plt.gca().get_xaxis_transform()"
+        );
+        assert_eq!(CoordSystem::OffsetPoints.annotation_coords(), "'offset points'");
+    }
+}
@@ -1,4 +1,4 @@
-use super::{vector_to_array, AsVector, GraphMaker};
+use super::{color_to_spec, vector_to_array, AsVector, Color, GraphMaker, LineStyle, Marker, StrError};
 use std::fmt::Write;
 
 /// Holds either the second point coordinates of a ray or the slope of the ray
@@ -66,7 +66,7 @@ pub enum RayEndpoint {
 /// ## Using Vector with point data
 ///
 /// ```
-/// use plotpy::{Curve, Plot, StrError};
+/// use plotpy::{Curve, LineStyle, Marker, Plot, StrError};
 /// use russell_lab::Vector;
 ///
 /// fn main() -> Result<(), StrError> {
@@ -80,14 +80,14 @@ pub enum RayEndpoint {
 ///         .set_label("logistic function")
 ///         .set_line_alpha(0.8)
 ///         .set_line_color("#5f9cd8")
-///         .set_line_style("-")
+///         .set_line_style(LineStyle::Solid)
 ///         .set_line_width(5.0)
 ///         .set_marker_color("#eeea83")
 ///         .set_marker_every(5)
 ///         .set_marker_line_color("#da98d1")
 ///         .set_marker_line_width(2.5)
 ///         .set_marker_size(20.0)
-///         .set_marker_style("*");
+///         .set_marker_style(Marker::StarFilled);
 ///
 ///     // draw curve
 ///     curve.draw(&x, &y);
@@ -125,6 +125,7 @@ pub struct Curve {
     marker_size: f64,          // Size of markers
     marker_style: String,      // Style of markers, e.g., "`o`", "`+`"
     stop_clip: bool,           // Stop clipping features within margins
+    error: Option<StrError>,   // First error found in the options (e.g., an invalid color)
     buffer: String,            // buffer
 }
 
@@ -145,6 +146,7 @@ impl Curve {
             marker_size: 0.0,
             marker_style: String::new(),
             stop_clip: false,
+            error: None,
             buffer: String::new(),
         }
     }
@@ -283,8 +285,8 @@ impl Curve {
     }
 
     /// Sets the color of lines
-    pub fn set_line_color(&mut self, color: &str) -> &mut Self {
-        self.line_color = String::from(color);
+    pub fn set_line_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.line_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -316,10 +318,10 @@ impl Curve {
     ///
     /// Options:
     ///
-    /// * "`-`", `:`", "`--`", "`-.`", or "`None`"
-    /// * As defined in <https://matplotlib.org/stable/gallery/lines_bars_and_markers/linestyles.html>
-    pub fn set_line_style(&mut self, style: &str) -> &mut Self {
-        self.line_style = String::from(style);
+    /// * [LineStyle::Solid], [LineStyle::Dotted], [LineStyle::Dashed], [LineStyle::DashDot], or [LineStyle::None]
+    /// * See [LineStyle] (including custom dashes and the default style)
+    pub fn set_line_style(&mut self, style: impl Into<LineStyle>) -> &mut Self {
+        self.line_style = style.into().to_option();
         self
     }
 
@@ -330,8 +332,8 @@ impl Curve {
     }
 
     /// Sets the color of markers
-    pub fn set_marker_color(&mut self, color: &str) -> &mut Self {
-        self.marker_color = String::from(color);
+    pub fn set_marker_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.marker_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -348,8 +350,8 @@ impl Curve {
     }
 
     /// Sets the edge color of markers
-    pub fn set_marker_line_color(&mut self, color: &str) -> &mut Self {
-        self.marker_line_color = String::from(color);
+    pub fn set_marker_line_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.marker_line_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -369,10 +371,10 @@ impl Curve {
     ///
     /// Examples:
     ///
-    /// * "`o`", "`+`"
-    /// * As defined in <https://matplotlib.org/stable/api/markers_api.html>
-    pub fn set_marker_style(&mut self, style: &str) -> &mut Self {
-        self.marker_style = String::from(style);
+    /// * [Marker::Circle], [Marker::Plus]
+    /// * See [Marker] (including custom paths and the default marker)
+    pub fn set_marker_style(&mut self, style: impl Into<Marker>) -> &mut Self {
+        self.marker_style = style.into().to_option();
         self
     }
 
@@ -407,7 +409,7 @@ impl Curve {
             write!(&mut opt, ",color='{}'", line_color).unwrap();
         }
         if self.line_style != "" {
-            write!(&mut opt, ",linestyle='{}'", self.line_style).unwrap();
+            write!(&mut opt, ",linestyle={}", self.line_style).unwrap();
         }
         if self.line_width > 0.0 {
             write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
@@ -433,7 +435,7 @@ impl Curve {
             write!(&mut opt, ",markersize={}", self.marker_size).unwrap();
         }
         if self.marker_style != "" {
-            write!(&mut opt, ",marker='{}'", self.marker_style).unwrap();
+            write!(&mut opt, ",marker={}", self.marker_style).unwrap();
         }
 
         // clipping
@@ -452,6 +454,9 @@ impl GraphMaker for Curve {
     fn clear_buffer(&mut self) {
         self.buffer.clear();
     }
+    fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -459,7 +464,7 @@ impl GraphMaker for Curve {
 #[cfg(test)]
 mod tests {
     use super::{Curve, RayEndpoint};
-    use crate::GraphMaker;
+    use crate::{Color, GraphMaker, LineStyle, Marker};
     use russell_lab::Vector;
 
     #[test]
@@ -487,7 +492,7 @@ mod tests {
             .set_label("my-curve")
             .set_line_alpha(0.7)
             .set_line_color("#b33434")
-            .set_line_style("-")
+            .set_line_style(LineStyle::Solid)
             .set_line_width(3.0)
             .set_marker_color("#4c4deb")
             .set_marker_every(2)
@@ -495,7 +500,7 @@ mod tests {
             .set_marker_line_color("blue")
             .set_marker_line_width(1.5)
             .set_marker_size(8.0)
-            .set_marker_style("o")
+            .set_marker_style(Marker::Circle)
             .set_stop_clip(true);
         let options = curve.options();
         assert_eq!(
@@ -515,6 +520,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn options_with_color_works() {
+        let red = Color::from("red");
+        let mut curve = Curve::new();
+        curve
+            .set_line_color(&red)
+            .set_marker_color(red.lighten(0.5))
+            .set_marker_line_color(String::from("rgb(0,0,255)"));
+        let options = curve.options();
+        assert_eq!(
+            options,
+            ",color='red'\
+             ,markerfacecolor='#ff8080'\
+             ,markeredgecolor='#0000ff'"
+        );
+    }
+
+    #[test]
+    fn options_with_line_style_and_marker_works() {
+        let mut curve = Curve::new();
+        curve
+            .set_line_style(LineStyle::Dashes(0.0, vec![4.0, 1.0]))
+            .set_marker_style(Marker::Mathtext("\\star".to_string()));
+        let options = curve.options();
+        assert_eq!(options, ",linestyle=(0,(4,1,)),marker=r'$\\star$'");
+        curve
+            .set_line_style(&LineStyle::None)
+            .set_marker_style(Marker::Polygon(6, 0.0));
+        let options = curve.options();
+        assert_eq!(options, ",linestyle='None',marker=(6,0,0)");
+        curve.set_marker_style(Marker::CaretUp);
+        assert_eq!(curve.options(), ",linestyle='None',marker=6");
+        curve
+            .set_line_style(LineStyle::Default)
+            .set_marker_style(Marker::Default);
+        assert_eq!(curve.options(), "");
+    }
+
+    #[test]
+    fn set_line_color_records_invalid_color() {
+        let mut curve = Curve::new();
+        assert_eq!(curve.get_error(), None);
+        curve.set_line_color("redd").set_marker_color("#12345");
+        assert_eq!(curve.get_error(), Some("invalid color: unknown color name"));
+        assert_eq!(curve.options(), ",color='redd',markerfacecolor='#12345'");
+    }
+
     #[test]
     fn points_methods_work() {
         let mut curve = Curve::new();
@@ -0,0 +1,315 @@
+use super::{call_python3_piped, command_exists, StrError};
+use std::fmt;
+
+/// Python script that prints information about the environment (one `key=value` per line)
+const DIAGNOSTICS: &str = "import sys
+import importlib
+print('python_path=' + sys.executable)
+print('python_version=' + sys.version.split()[0])
+def version(module):
+    try:
+        m = importlib.import_module(module)
+        print(module + '=' + getattr(m, '__version__', 'unknown'))
+        return True
+    except Exception:
+        return False
+version('numpy')
+version('PIL')
+if version('matplotlib'):
+    try:
+        import mpl_toolkits.mplot3d
+        print('mplot3d=True')
+    except Exception:
+        pass
+    backends = ['Agg']
+    for name, modules in [('TkAgg', ['tkinter']), ('QtAgg', ['PyQt6', 'PySide6', 'PyQt5', 'PySide2']),
+                          ('GTK3Agg', ['gi']), ('WXAgg', ['wx'])]:
+        for module in modules:
+            try:
+                importlib.import_module(module)
+                backends.append(name)
+                break
+            except Exception:
+                pass
+    if sys.platform == 'darwin':
+        backends.append('MacOSX')
+    print('backends=' + ','.join(backends))
+";
+
+/// Holds information about the Python environment used to generate the figures
+///
+/// The information is obtained by [check_environment]. Use [Environment::get_warnings] to find
+/// out whether the environment is missing anything required by the features of this library.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{check_environment, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let env = check_environment()?;
+///     println!("{}", env);
+///     for warning in env.get_warnings() {
+///         println!("WARNING: {}", warning);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Environment {
+    python_path: String,        // Path of the python3 executable
+    python_version: String,     // Version of Python
+    numpy_version: String,      // Version of NumPy (empty if not installed)
+    matplotlib_version: String, // Version of Matplotlib (empty if not installed)
+    mplot3d: bool,              // mpl_toolkits.mplot3d can be imported
+    backends: Vec<String>,      // Available Matplotlib backends
+    pillow_version: String,     // Version of Pillow (empty if not installed)
+    latex: bool,                // The latex command is available
+    dvipng: bool,               // The dvipng command is available
+    warnings: Vec<String>,      // Incompatibilities with the features of this library
+}
+
+/// Checks the Python environment (Python, NumPy, Matplotlib, backends, LaTeX, and Pillow)
+///
+/// Returns an error if python3 cannot be run. Otherwise, the returned [Environment] reports
+/// the versions and the incompatibilities (if any) with the features of this library.
+pub fn check_environment() -> Result<Environment, StrError> {
+    // run the diagnostics script
+    let (_, output, _) = call_python3_piped("", &DIAGNOSTICS.to_string()).map_err(|e| match e {
+        "cannot run python3" => "cannot run python3; please install Python 3 and make sure that python3 is in the PATH",
+        _ => e,
+    })?;
+    let stdout = String::from_utf8_lossy(&output);
+
+    // parse the results
+    let mut env = Environment::parse(&stdout);
+    env.latex = command_exists("latex");
+    env.dvipng = command_exists("dvipng");
+    env.update_warnings();
+    Ok(env)
+}
+
+impl Environment {
+    /// Returns the path of the python3 executable
+    pub fn get_python_path(&self) -> &str {
+        &self.python_path
+    }
+
+    /// Returns the version of Python
+    pub fn get_python_version(&self) -> &str {
+        &self.python_version
+    }
+
+    /// Returns the version of NumPy (empty if not installed)
+    pub fn get_numpy_version(&self) -> &str {
+        &self.numpy_version
+    }
+
+    /// Returns the version of Matplotlib (empty if not installed)
+    pub fn get_matplotlib_version(&self) -> &str {
+        &self.matplotlib_version
+    }
+
+    /// Returns the available Matplotlib backends (Agg and the interactive ones whose toolkit is installed)
+    pub fn get_backends(&self) -> &[String] {
+        &self.backends
+    }
+
+    /// Returns the version of Pillow (empty if not installed)
+    pub fn get_pillow_version(&self) -> &str {
+        &self.pillow_version
+    }
+
+    /// Returns true if `mpl_toolkits.mplot3d` (required by 3D plots) can be imported
+    pub fn has_mplot3d(&self) -> bool {
+        self.mplot3d
+    }
+
+    /// Returns true if the `latex` and `dvipng` commands (required by usetex) are available
+    pub fn has_latex(&self) -> bool {
+        self.latex && self.dvipng
+    }
+
+    /// Returns the incompatibilities with the features of this library (empty if none)
+    pub fn get_warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Parses the output of the diagnostics script
+    fn parse(output: &str) -> Self {
+        let mut env = Environment {
+            python_path: String::new(),
+            python_version: String::new(),
+            numpy_version: String::new(),
+            matplotlib_version: String::new(),
+            mplot3d: false,
+            backends: Vec::new(),
+            pillow_version: String::new(),
+            latex: false,
+            dvipng: false,
+            warnings: Vec::new(),
+        };
+        for line in output.lines() {
+            if let Some((key, value)) = line.split_once('=') {
+                let value = value.trim().to_string();
+                match key {
+                    "python_path" => env.python_path = value,
+                    "python_version" => env.python_version = value,
+                    "numpy" => env.numpy_version = value,
+                    "matplotlib" => env.matplotlib_version = value,
+                    "mplot3d" => env.mplot3d = value == "True",
+                    "backends" => env.backends = value.split(',').map(|b| b.to_string()).collect(),
+                    "PIL" => env.pillow_version = value,
+                    _ => (),
+                }
+            }
+        }
+        env
+    }
+
+    /// Updates the list of warnings
+    fn update_warnings(&mut self) {
+        let mut warnings = Vec::new();
+        if self.numpy_version.is_empty() {
+            warnings.push("NumPy is not installed; it is required by all figures".to_string());
+        }
+        if self.matplotlib_version.is_empty() {
+            warnings.push("Matplotlib is not installed; it is required by all figures".to_string());
+        } else {
+            if parse_version(&self.matplotlib_version) < (3, 3) {
+                warnings.push(format!(
+                    "Matplotlib {} is too old; version 3.3 or newer is required by set_equal_axes (set_box_aspect)",
+                    self.matplotlib_version
+                ));
+            }
+            if !self.mplot3d {
+                warnings.push("mpl_toolkits.mplot3d cannot be imported; 3D plots will fail".to_string());
+            }
+            if self.backends.len() < 2 {
+                warnings.push("no interactive backend is available; save_and_show will not open a window".to_string());
+            }
+        }
+        if !self.latex {
+            warnings.push("latex cannot be found; Typography::set_usetex cannot be used".to_string());
+        } else if !self.dvipng {
+            warnings.push("dvipng cannot be found; Typography::set_usetex cannot be used with PNG files".to_string());
+        }
+        if self.pillow_version.is_empty() {
+            warnings.push("Pillow is not installed; GIF files cannot be saved".to_string());
+        }
+        self.warnings = warnings;
+    }
+}
+
+impl fmt::Display for Environment {
+    /// Writes a report with one item per line
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let or_missing = |v: &str| {
+            if v.is_empty() {
+                "not installed".to_string()
+            } else {
+                v.to_string()
+            }
+        };
+        let yes_no = |v: bool| if v { "yes" } else { "no" };
+        writeln!(f, "python3 path       : {}", self.python_path)?;
+        writeln!(f, "python3 version    : {}", self.python_version)?;
+        writeln!(f, "numpy version      : {}", or_missing(&self.numpy_version))?;
+        writeln!(f, "matplotlib version : {}", or_missing(&self.matplotlib_version))?;
+        writeln!(f, "mplot3d            : {}", yes_no(self.mplot3d))?;
+        writeln!(f, "backends           : {}", self.backends.join(", "))?;
+        writeln!(f, "pillow version     : {}", or_missing(&self.pillow_version))?;
+        writeln!(f, "latex              : {}", yes_no(self.latex))?;
+        write!(f, "dvipng             : {}", yes_no(self.dvipng))
+    }
+}
+
+/// Returns the (major, minor) numbers of a version string such as "3.8.2" or "3.10.0rc1"
+pub(crate) fn parse_version(version: &str) -> (u32, u32) {
+    let mut numbers = version.split('.').map(|part| {
+        let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse::<u32>().unwrap_or(0)
+    });
+    let major = numbers.next().unwrap_or(0);
+    let minor = numbers.next().unwrap_or(0);
+    (major, minor)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_version, Environment};
+
+    #[test]
+    fn parse_version_works() {
+        assert_eq!(parse_version("3.8.2"), (3, 8));
+        assert_eq!(parse_version("3.10.0rc1"), (3, 10));
+        assert_eq!(parse_version("2"), (2, 0));
+        assert_eq!(parse_version("unknown"), (0, 0));
+    }
+
+    #[test]
+    fn parse_works() {
+        let output = "python_path=/usr/bin/python3\n\
+                      python_version=3.11.4\n\
+                      numpy=1.26.0\n\
+                      PIL=10.0.1\n\
+                      matplotlib=3.8.2\n\
+                      mplot3d=True\n\
+                      backends=Agg,TkAgg\n";
+        let mut env = Environment::parse(output);
+        assert_eq!(env.get_python_path(), "/usr/bin/python3");
+        assert_eq!(env.get_python_version(), "3.11.4");
+        assert_eq!(env.get_numpy_version(), "1.26.0");
+        assert_eq!(env.get_matplotlib_version(), "3.8.2");
+        assert_eq!(env.get_pillow_version(), "10.0.1");
+        assert_eq!(env.get_backends(), &["Agg", "TkAgg"]);
+        assert!(env.has_mplot3d());
+        env.latex = true;
+        env.dvipng = true;
+        env.update_warnings();
+        assert_eq!(env.get_warnings().len(), 0);
+        assert!(env.has_latex());
+        assert_eq!(
+            format!("{}", env),
+            "python3 path       : /usr/bin/python3\n\
+             python3 version    : 3.11.4\n\
+             numpy version      : 1.26.0\n\
+             matplotlib version : 3.8.2\n\
+             mplot3d            : yes\n\
+             backends           : Agg, TkAgg\n\
+             pillow version     : 10.0.1\n\
+             latex              : yes\n\
+             dvipng             : yes"
+        );
+    }
+
+    #[test]
+    fn warnings_work() {
+        let mut env = Environment::parse("python_version=3.8.0\nnumpy=2.0.1\nmatplotlib=3.2.1\nbackends=Agg\n");
+        env.update_warnings();
+        assert_eq!(
+            env.get_warnings(),
+            &[
+                "Matplotlib 3.2.1 is too old; version 3.3 or newer is required by set_equal_axes (set_box_aspect)",
+                "mpl_toolkits.mplot3d cannot be imported; 3D plots will fail",
+                "no interactive backend is available; save_and_show will not open a window",
+                "latex cannot be found; Typography::set_usetex cannot be used",
+                "Pillow is not installed; GIF files cannot be saved",
+            ]
+        );
+        let mut env = Environment::parse("");
+        env.latex = true;
+        env.update_warnings();
+        assert_eq!(
+            env.get_warnings(),
+            &[
+                "NumPy is not installed; it is required by all figures",
+                "Matplotlib is not installed; it is required by all figures",
+                "dvipng cannot be found; Typography::set_usetex cannot be used with PNG files",
+                "Pillow is not installed; GIF files cannot be saved",
+            ]
+        );
+    }
+}
@@ -1,22 +1,46 @@
-use super::{StrError, PYTHON_HEADER};
+use super::StrError;
 use std::fs;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
-use std::process::Command;
+use std::process::{Command, Stdio};
 
 /// Writes a python file and call python3 on it
 ///
 /// # Arguments
 ///
+/// * `header` - Header (e.g., [crate::PYTHON_HEADER]) to be added at the beginning of the file
 /// * `python_commands` - Python commands to be written to file
-/// * `output_dir` - Output directory to be created
-/// * `filename_py` - Filename with extension .py
-///
-/// # Note
+/// * `path` - Path of the .py file (the directory is created if needed)
+pub(crate) fn call_python3(header: &str, python_commands: &str, path: &Path) -> Result<String, StrError> {
+    // write file
+    write_python3(header, python_commands, path)?;
+
+    // execute file
+    let output = Command::new("python3")
+        .arg(path)
+        .output()
+        .map_err(|_| "cannot run python3")?;
+
+    // results
+    let out = String::from_utf8(output.stdout).unwrap();
+    let err = String::from_utf8(output.stderr).unwrap();
+    let mut results = String::new();
+    if out.len() > 0 {
+        results.push_str(&out);
+    }
+    if err.len() > 0 {
+        results.push_str(&err)
+    }
+
+    // done
+    Ok(results)
+}
+
+/// Writes a python file (without calling python3)
 ///
-/// The contents of [PYTHON_HEADER] are added at the beginning of the file.
-pub(crate) fn call_python3(python_commands: &String, path: &Path) -> Result<String, StrError> {
+/// The header (e.g., [crate::PYTHON_HEADER]) is added at the beginning of the file.
+pub(crate) fn write_python3(header: &str, python_commands: &str, path: &Path) -> Result<(), StrError> {
     // create directory
     if let Some(p) = path.parent() {
         fs::create_dir_all(p).map_err(|_| "cannot create directory")?;
@@ -24,7 +48,7 @@ pub(crate) fn call_python3(python_commands: &String, path: &Path) -> Result<Stri
 
     // combine header with commands
     let mut contents = String::new();
-    contents.push_str(PYTHON_HEADER);
+    contents.push_str(header);
     contents.push_str(python_commands);
 
     // write file
@@ -33,33 +57,75 @@ pub(crate) fn call_python3(python_commands: &String, path: &Path) -> Result<Stri
 
     // force sync
     file.sync_all().map_err(|_| "cannot sync file")?;
+    Ok(())
+}
 
-    // execute file
-    let output = Command::new("python3")
-        .arg(path)
-        .output()
+/// Calls python3 with the commands given via the standard input (no file is written)
+///
+/// Returns a flag indicating whether python3 exited successfully, the standard output (as bytes),
+/// and the standard error. Note that the standard error may contain warnings even if python3 succeeds.
+///
+/// The header (e.g., [crate::PYTHON_HEADER]) is written before the commands.
+pub(crate) fn call_python3_piped(header: &str, python_commands: &String) -> Result<(bool, Vec<u8>, String), StrError> {
+    // start python3 reading the script from stdin
+    let mut child = Command::new("python3")
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
         .map_err(|_| "cannot run python3")?;
 
+    // write header and commands (dropping stdin closes the pipe)
+    {
+        let mut stdin = child.stdin.take().ok_or("cannot open the standard input of python3")?;
+        stdin
+            .write_all(header.as_bytes())
+            .map_err(|_| "cannot write to the standard input of python3")?;
+        stdin
+            .write_all(python_commands.as_bytes())
+            .map_err(|_| "cannot write to the standard input of python3")?;
+    }
+
     // results
-    let out = String::from_utf8(output.stdout).unwrap();
-    let err = String::from_utf8(output.stderr).unwrap();
-    let mut results = String::new();
-    if out.len() > 0 {
-        results.push_str(&out);
+    let output = child.wait_with_output().map_err(|_| "cannot wait for python3")?;
+    let err = String::from_utf8_lossy(&output.stderr).to_string();
+    Ok((output.status.success(), output.stdout, err))
+}
+
+/// Writes the output of python3 (if any) to a log file and returns an error
+///
+/// The log file is written with the `.log` extension next to `path` (e.g., the figure path).
+/// Nothing is done if the output is empty (i.e., python3 succeeded).
+///
+/// If `show_errors` is true, the output is also printed to the standard output.
+pub(crate) fn write_log_file(output: &str, path: &Path, show_errors: bool) -> Result<(), StrError> {
+    if output.is_empty() {
+        return Ok(());
     }
-    if err.len() > 0 {
-        results.push_str(&err)
+    let mut log_path = path.to_path_buf();
+    log_path.set_extension("log");
+    let mut log_file = File::create(log_path).map_err(|_| "cannot create log file")?;
+    log_file
+        .write_all(output.as_bytes())
+        .map_err(|_| "cannot write to log file")?;
+    if show_errors {
+        println!("{}", output);
     }
+    Err("python3 failed; please see the log file")
+}
 
-    // done
-    Ok(results)
+/// Returns true if the given command can be found (and started) by the operating system
+pub(crate) fn command_exists(command: &str) -> bool {
+    Command::new(command).arg("--version").output().is_ok()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{call_python3, StrError, PYTHON_HEADER};
+    use super::{call_python3, call_python3_piped, command_exists, write_log_file, write_python3, StrError};
+    use crate::PYTHON_HEADER;
     use std::fs;
     use std::path::Path;
 
@@ -69,7 +135,7 @@ mod tests {
     fn call_python3_works() -> Result<(), StrError> {
         let commands = "print(\"Python says: Hello World!\")".to_string();
         let path = Path::new("call_python3_works.py");
-        let output = call_python3(&commands, &path)?;
+        let output = call_python3(PYTHON_HEADER, &commands, path)?;
         let data = fs::read_to_string(&path).map_err(|_| "cannot read test file")?;
         let mut correct = String::from(PYTHON_HEADER);
         correct.push_str(&commands);
@@ -82,7 +148,7 @@ mod tests {
     fn call_python3_create_dir_works() -> Result<(), StrError> {
         let commands = "print(\"Python says: Hello World!\")".to_string();
         let path = Path::new(OUT_DIR).join("call_python3_works.py");
-        let output = call_python3(&commands, &path)?;
+        let output = call_python3(PYTHON_HEADER, &commands, &path)?;
         let data = fs::read_to_string(&path).map_err(|_| "cannot read test file")?;
         let mut correct = String::from(PYTHON_HEADER);
         correct.push_str(&commands);
@@ -96,7 +162,7 @@ mod tests {
         let path = Path::new(OUT_DIR).join("call_python3_twice_works.py");
         // first
         let commands_first = "print(\"Python says: Hello World!\")".to_string();
-        let output_first = call_python3(&commands_first, &path)?;
+        let output_first = call_python3(PYTHON_HEADER, &commands_first, &path)?;
         let data_first = fs::read_to_string(&path).map_err(|_| "cannot read test file")?;
         let mut correct_first = String::from(PYTHON_HEADER);
         correct_first.push_str(&commands_first);
@@ -104,7 +170,7 @@ mod tests {
         assert_eq!(output_first, "Python says: Hello World!\n");
         // second
         let commands_second = "print(\"Python says: Hello World! again\")".to_string();
-        let output_second = call_python3(&commands_second, &path)?;
+        let output_second = call_python3(PYTHON_HEADER, &commands_second, &path)?;
         let data_second = fs::read_to_string(&path).map_err(|_| "cannot read test file")?;
         let mut correct_second = String::from(PYTHON_HEADER);
         correct_second.push_str(&commands_second);
@@ -112,4 +178,69 @@ mod tests {
         assert_eq!(output_second, "Python says: Hello World! again\n");
         Ok(())
     }
+
+    #[test]
+    fn write_python3_works() -> Result<(), StrError> {
+        let commands = "print(\"Python says: Hello World!\")".to_string();
+        let path = Path::new(OUT_DIR).join("write_python3_works.py");
+        write_python3(PYTHON_HEADER, &commands, &path)?;
+        let data = fs::read_to_string(&path).map_err(|_| "cannot read test file")?;
+        let mut correct = String::from(PYTHON_HEADER);
+        correct.push_str(&commands);
+        assert_eq!(data, correct);
+        Ok(())
+    }
+
+    #[test]
+    fn call_python3_piped_works() -> Result<(), StrError> {
+        let commands = "import sys\nsys.stdout.buffer.write(b'\\x89PNG')\n".to_string();
+        let (success, output, err) = call_python3_piped(PYTHON_HEADER, &commands)?;
+        assert!(success);
+        assert_eq!(output, b"\x89PNG");
+        assert_eq!(err, "");
+        Ok(())
+    }
+
+    #[test]
+    fn call_python3_piped_handles_status() -> Result<(), StrError> {
+        // warnings written to stderr do not indicate failure
+        let commands = "import sys\nsys.stderr.write('warning')\nsys.stdout.write('ok')\n".to_string();
+        let (success, output, err) = call_python3_piped(PYTHON_HEADER, &commands)?;
+        assert!(success);
+        assert_eq!(output, b"ok");
+        assert_eq!(err, "warning");
+
+        // the exit status indicates failure
+        let commands = "raise RuntimeError('failed')\n".to_string();
+        let (success, output, err) = call_python3_piped(PYTHON_HEADER, &commands)?;
+        assert!(!success);
+        assert_eq!(output, b"");
+        assert!(err.contains("RuntimeError: failed"));
+        Ok(())
+    }
+
+    #[test]
+    fn write_log_file_works() -> Result<(), StrError> {
+        fs::create_dir_all(OUT_DIR).map_err(|_| "cannot create directory")?;
+        let path = Path::new(OUT_DIR).join("write_log_file_works.svg");
+        let log_path = Path::new(OUT_DIR).join("write_log_file_works.log");
+        if log_path.exists() {
+            fs::remove_file(&log_path).map_err(|_| "cannot remove log file")?;
+        }
+        assert_eq!(write_log_file("", &path, false), Ok(()));
+        assert!(!log_path.exists());
+        assert_eq!(
+            write_log_file("Traceback\n", &path, false).err(),
+            Some("python3 failed; please see the log file")
+        );
+        let data = fs::read_to_string(&log_path).map_err(|_| "cannot read log file")?;
+        assert_eq!(data, "Traceback\n");
+        Ok(())
+    }
+
+    #[test]
+    fn command_exists_works() {
+        assert!(command_exists("cargo"));
+        assert!(!command_exists("__plotpy_not_a_command__"));
+    }
 }
@@ -1,4 +1,4 @@
-use super::{matrix_to_list, vector_to_strings, GraphMaker};
+use super::{color_to_spec, matrix_to_list, vector_to_strings, Color, GraphMaker, LineStyle, StrError};
 use std::fmt::Write;
 
 /// Generates a Histogram plot
@@ -47,13 +47,19 @@ use std::fmt::Write;
 ///
 /// ![integ_histogram_1.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_histogram_1.svg)
 pub struct Histogram {
-    colors: Vec<String>, // Colors for each bar
-    line_width: f64,     // Line width
-    style: String,       // Type of histogram; e.g. "bar"
-    stacked: bool,       // Draws stacked histogram
-    no_fill: bool,       // Skip filling bars
-    number_bins: usize,  // Number of bins
-    buffer: String,      // buffer
+    colors: Vec<String>,     // Colors for each bar
+    edge_color: String,      // Edge color of bars
+    line_width: f64,         // Line width
+    line_style: String,      // Line style of edges
+    hatch: String,           // Hatch pattern of bars
+    alpha: f64,              // Opacity of bars
+    zorder: Option<f64>,     // Drawing order
+    style: String,           // Type of histogram; e.g. "bar"
+    stacked: bool,           // Draws stacked histogram
+    no_fill: bool,           // Skip filling bars
+    number_bins: usize,      // Number of bins
+    error: Option<StrError>, // First error found in the options (e.g., an invalid color)
+    buffer: String,          // buffer
 }
 
 impl Histogram {
@@ -61,11 +67,17 @@ impl Histogram {
     pub fn new() -> Self {
         Histogram {
             colors: Vec::new(),
+            edge_color: String::new(),
             line_width: 0.0,
+            line_style: String::new(),
+            hatch: String::new(),
+            alpha: 0.0,
+            zorder: None,
             style: String::new(),
             stacked: false,
             no_fill: false,
             number_bins: 0,
+            error: None,
             buffer: String::new(),
         }
     }
@@ -97,8 +109,20 @@ impl Histogram {
     }
 
     /// Sets the colors for each bar
-    pub fn set_colors(&mut self, colors: &[&str]) -> &mut Self {
-        self.colors = colors.iter().map(|color| color.to_string()).collect();
+    pub fn set_colors<C>(&mut self, colors: &[C]) -> &mut Self
+    where
+        C: Clone + Into<Color>,
+    {
+        self.colors = colors
+            .iter()
+            .map(|color| color_to_spec(color.clone(), &mut self.error))
+            .collect();
+        self
+    }
+
+    /// Sets the edge color of bars
+    pub fn set_edge_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.edge_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -108,6 +132,36 @@ impl Histogram {
         self
     }
 
+    /// Sets the line style of edges (e.g., dashed edges)
+    pub fn set_line_style(&mut self, style: impl Into<LineStyle>) -> &mut Self {
+        self.line_style = style.into().to_option();
+        self
+    }
+
+    /// Sets the hatch pattern of bars
+    ///
+    /// See [crate::Canvas::set_hatch] for the options. An empty string means no hatch.
+    ///
+    /// **Note:** The hatch is drawn with the edge color (if set).
+    pub fn set_hatch(&mut self, hatch: &str) -> &mut Self {
+        self.hatch = hatch.replace('\\', "\\\\");
+        self
+    }
+
+    /// Sets the opacity of bars in (0, 1]
+    ///
+    /// A zero value means that the opacity is not set (i.e., the Matplotlib default is used).
+    pub fn set_alpha(&mut self, alpha: f64) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Sets the drawing order
+    pub fn set_zorder(&mut self, zorder: f64) -> &mut Self {
+        self.zorder = Some(zorder);
+        self
+    }
+
     /// Sets the type of histogram
     ///
     /// Options:
@@ -146,9 +200,24 @@ impl Histogram {
         if self.colors.len() > 0 {
             write!(&mut opt, ",color=colors").unwrap();
         }
+        if !self.edge_color.is_empty() {
+            write!(&mut opt, ",edgecolor='{}'", self.edge_color).unwrap();
+        }
         if self.line_width > 0.0 {
             write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
         }
+        if !self.line_style.is_empty() {
+            write!(&mut opt, ",linestyle={}", self.line_style).unwrap();
+        }
+        if !self.hatch.is_empty() {
+            write!(&mut opt, ",hatch='{}'", self.hatch).unwrap();
+        }
+        if self.alpha > 0.0 {
+            write!(&mut opt, ",alpha={}", self.alpha).unwrap();
+        }
+        if let Some(zorder) = self.zorder {
+            write!(&mut opt, ",zorder={}", zorder).unwrap();
+        }
         if self.style != "" {
             write!(&mut opt, ",histtype='{}'", self.style).unwrap();
         }
@@ -172,6 +241,9 @@ impl GraphMaker for Histogram {
     fn clear_buffer(&mut self) {
         self.buffer.clear();
     }
+    fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -179,13 +251,18 @@ impl GraphMaker for Histogram {
 #[cfg(test)]
 mod tests {
     use super::Histogram;
-    use crate::GraphMaker;
+    use crate::{GraphMaker, LineStyle};
 
     #[test]
     fn new_works() {
         let histogram = Histogram::new();
         assert_eq!(histogram.colors.len(), 0);
+        assert_eq!(histogram.edge_color.len(), 0);
         assert_eq!(histogram.line_width, 0.0);
+        assert_eq!(histogram.line_style.len(), 0);
+        assert_eq!(histogram.hatch.len(), 0);
+        assert_eq!(histogram.alpha, 0.0);
+        assert_eq!(histogram.zorder, None);
         assert_eq!(histogram.style.len(), 0);
         assert_eq!(histogram.stacked, false);
         assert_eq!(histogram.no_fill, false);
@@ -213,6 +290,21 @@ mod tests {
              ,fill=False\
              ,bins=8"
         );
+        let mut histogram = Histogram::new();
+        histogram
+            .set_edge_color("black")
+            .set_line_style(LineStyle::Dashed)
+            .set_hatch("/\\")
+            .set_alpha(0.6)
+            .set_zorder(3.0);
+        assert_eq!(
+            histogram.options(),
+            ",edgecolor='black'\
+             ,linestyle='--'\
+             ,hatch='/\\\\'\
+             ,alpha=0.6\
+             ,zorder=3"
+        );
     }
 
     #[test]
@@ -229,5 +321,7 @@ mod tests {
         assert_eq!(histogram.buffer, b);
         histogram.clear_buffer();
         assert_eq!(histogram.buffer, "");
+        histogram.set_colors::<&str>(&[]).draw(&values, &labels);
+        assert!(!histogram.buffer.contains("color"));
     }
 }
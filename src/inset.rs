@@ -0,0 +1,220 @@
+use super::{color_to_spec, Color, GraphMaker, Plot, StrError};
+use std::fmt::Write;
+
+/// Holds a handle to inset axes created by [Plot::add_inset]
+///
+/// The graphs (e.g., Curve, Contour, Canvas) added to the inset are drawn in the inset axes,
+/// whereas the graphs added to the Plot (after the handle is dropped) are drawn in the parent axes.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{CoordSystem, Curve, Plot, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // curve with a detail near x = 0
+///     let x: Vec<f64> = (0..201).map(|i| -1.0 + 2.0 * i as f64 / 200.0).collect();
+///     let y: Vec<f64> = x.iter().map(|x| f64::exp(-50.0 * x * x)).collect();
+///     let mut curve = Curve::new();
+///     curve.draw(&x, &y);
+///
+///     // add curve to plot
+///     let mut plot = Plot::new();
+///     plot.add(&curve);
+///
+///     // add curve to inset (bounds in axes coordinates)
+///     plot.add_inset(0.6, 0.55, 0.35, 0.4, CoordSystem::Axes)?
+///         .add(&curve)
+///         .set_range(-0.1, 0.1, 0.8, 1.02)
+///         .set_hide_ticks()
+///         .set_zoom_edge_color("#5b5b5b")
+///         .indicate_inset_zoom();
+///
+///     // save figure
+///     plot.set_range(-1.0, 1.0, -0.1, 1.6);
+///     plot.save("/tmp/plotpy/doc_tests/doc_inset.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_inset.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_inset.svg)
+pub struct Inset<'a> {
+    plot: &'a mut Plot,      // plot holding the parent axes
+    name: String,            // python variable name of the inset axes
+    zoom_edge_color: String, // edge color of the zoom indicator
+}
+
+impl<'a> Inset<'a> {
+    /// Creates a new handle (the inset axes must have been created already)
+    pub(crate) fn new(plot: &'a mut Plot, name: String) -> Self {
+        Inset {
+            plot,
+            name,
+            zoom_edge_color: String::new(),
+        }
+    }
+
+    /// Returns the python variable name of the inset axes (e.g., to be used with [crate::RawPython])
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Adds new graph entity to the inset axes
+    pub fn add(&mut self, graph: &dyn GraphMaker) -> &mut Self {
+        self.activate();
+        self.plot.add(graph);
+        self.deactivate()
+    }
+
+    /// Sets the limits of the inset axes (i.e., the zoomed region)
+    pub fn set_range(&mut self, xmin: f64, xmax: f64, ymin: f64, ymax: f64) -> &mut Self {
+        self.activate();
+        self.plot.set_range(xmin, xmax, ymin, ymax);
+        self.deactivate()
+    }
+
+    /// Sets the title of the inset axes
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.activate();
+        self.plot.set_title(title);
+        self.deactivate()
+    }
+
+    /// Hides the ticks and tick labels of the inset axes
+    pub fn set_hide_ticks(&mut self) -> &mut Self {
+        let command = format!("{}.set_xticks([])\n{}.set_yticks([])\n", self.name, self.name);
+        self.plot.push_commands(&command);
+        self
+    }
+
+    /// Sets the edge color of the zoom indicator drawn by [Inset::indicate_inset_zoom]
+    ///
+    /// An invalid color is reported by [Plot::save].
+    pub fn set_zoom_edge_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        let mut error: Option<StrError> = None;
+        self.zoom_edge_color = color_to_spec(color, &mut error);
+        self.plot.record_error(error);
+        self
+    }
+
+    /// Draws a rectangle around the zoomed region in the parent axes and lines connecting it to the inset
+    ///
+    /// The edge color is set by [Inset::set_zoom_edge_color] (Matplotlib's default is used otherwise).
+    ///
+    /// **Note:** This function must be called after the range of the inset has been set.
+    pub fn indicate_inset_zoom(&mut self) -> &mut Self {
+        let mut command = format!("{}_PARENT.indicate_inset_zoom({}", self.name, self.name);
+        if !self.zoom_edge_color.is_empty() {
+            write!(&mut command, ",edgecolor='{}'", self.zoom_edge_color).unwrap();
+        }
+        command.push_str(")\n");
+        self.plot.push_commands(&command);
+        self
+    }
+
+    /// Makes the inset axes the current axes
+    fn activate(&mut self) {
+        let command = format!("plt.sca({})\n", self.name);
+        self.plot.push_commands(&command);
+    }
+
+    /// Makes the parent axes the current axes
+    fn deactivate(&mut self) -> &mut Self {
+        let command = format!("plt.sca({}_PARENT)\n", self.name);
+        self.plot.push_commands(&command);
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{CoordSystem, Curve, Plot};
+
+    #[test]
+    fn inset_works() {
+        let mut curve = Curve::new();
+        curve.draw(&[0.0, 1.0], &[0.0, 1.0]);
+        let mut plot = Plot::new();
+        let mut inset = plot.add_inset(0.5, 0.5, 0.4, 0.4, CoordSystem::Axes).unwrap();
+        assert_eq!(inset.get_name(), "INSET_0");
+        inset
+            .add(&curve)
+            .set_range(0.0, 0.1, 0.0, 0.1)
+            .set_title("detail")
+            .set_hide_ticks()
+            .set_zoom_edge_color("red")
+            .indicate_inset_zoom();
+        let script = plot.get_script("/tmp/plotpy/unit_tests/inset_works.svg");
+        let commands = &script[script.find("INSET_0_PARENT=").unwrap()..script.find("\nfn=").unwrap()];
+        assert_eq!(
+            commands,
+            "INSET_0_PARENT=plt.gca()\n\
+             INSET_0=INSET_0_PARENT.inset_axes([0.5,0.5,0.4,0.4])\n\
+             plt.sca(INSET_0)\n\
+             x=np.array([0,1,],dtype=float)\n\
+             y=np.array([0,1,],dtype=float)\n\
+             plt.plot(x,y)\n\
+             plt.sca(INSET_0_PARENT)\n\
+             plt.sca(INSET_0)\n\
+             plt.axis([0,0.1,0,0.1])\n\
+             plt.sca(INSET_0_PARENT)\n\
+             plt.sca(INSET_0)\n\
+             plt.title(r'detail')\n\
+             plt.sca(INSET_0_PARENT)\n\
+             INSET_0.set_xticks([])\n\
+             INSET_0.set_yticks([])\n\
+             INSET_0_PARENT.indicate_inset_zoom(INSET_0,edgecolor='red')\n"
+        );
+    }
+
+    #[test]
+    fn indicate_inset_zoom_handles_colors() {
+        let mut plot = Plot::new();
+        plot.add_inset(0.5, 0.5, 0.4, 0.4, CoordSystem::Axes)
+            .unwrap()
+            .indicate_inset_zoom();
+        plot.add_inset(0.1, 0.1, 0.2, 0.2, CoordSystem::Axes)
+            .unwrap()
+            .set_zoom_edge_color("__not_a_color__")
+            .indicate_inset_zoom();
+        let script = plot.get_script("/tmp/plotpy/unit_tests/indicate_inset_zoom_handles_colors.svg");
+        assert!(script.contains("INSET_0_PARENT.indicate_inset_zoom(INSET_0)\n"));
+        assert_eq!(
+            plot.save("/tmp/plotpy/unit_tests/indicate_inset_zoom_handles_colors.svg")
+                .err(),
+            Some("invalid color: unknown color name")
+        );
+    }
+
+    #[test]
+    fn inset_data_coords_works() {
+        let mut plot = Plot::new();
+        plot.add_inset(1.0, 2.0, 3.0, 4.0, CoordSystem::Axes).unwrap();
+        let inset = plot.add_inset(1.0, 2.0, 3.0, 4.0, CoordSystem::Data).unwrap();
+        assert_eq!(inset.get_name(), "INSET_1");
+        let script = plot.get_script("/tmp/plotpy/unit_tests/inset_data_coords_works.svg");
+        assert!(script.contains(
+            "INSET_1_PARENT=plt.gca()\n\
+             INSET_1=INSET_1_PARENT.inset_axes([1,2,3,4],transform=INSET_1_PARENT.transData)\n"
+        ));
+    }
+
+    #[test]
+    fn add_inset_captures_errors() {
+        let mut plot = Plot::new();
+        for coords in [CoordSystem::Figure, CoordSystem::XDataYAxes, CoordSystem::OffsetPoints] {
+            assert_eq!(
+                plot.add_inset(0.5, 0.5, 0.4, 0.4, coords).err(),
+                Some("the coordinate system of the inset must be Axes or Data")
+            );
+        }
+        assert_eq!(
+            plot.add_inset(0.5, 0.5, 0.4, 0.4, CoordSystem::Axes)
+                .unwrap()
+                .get_name(),
+            "INSET_0"
+        );
+    }
+}
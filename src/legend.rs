@@ -1,5 +1,87 @@
-use super::{vector_to_numbers, GraphMaker};
-use std::fmt::Write;
+use super::{vector_to_numbers, CoordSystem, GraphMaker, StrError};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+/// Defines the location of the legend
+///
+/// [Legend::set_location] takes a `LegendLocation`; hence, invalid locations are caught at compile time.
+/// The Matplotlib strings (e.g., "best", "upper right", "center left") may be converted by `str::parse`,
+/// which returns an error for invalid strings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LegendLocation {
+    /// Lets Matplotlib choose the location with the least overlap with the data ("best")
+    Best,
+
+    /// Upper right corner of the axes ("upper right")
+    UpperRight,
+
+    /// Upper left corner of the axes ("upper left")
+    UpperLeft,
+
+    /// Lower left corner of the axes ("lower left")
+    LowerLeft,
+
+    /// Lower right corner of the axes ("lower right")
+    LowerRight,
+
+    /// Right side of the axes, vertically centered; same as [LegendLocation::CenterRight] ("right")
+    Right,
+
+    /// Left side of the axes, vertically centered ("center left")
+    CenterLeft,
+
+    /// Right side of the axes, vertically centered ("center right")
+    CenterRight,
+
+    /// Bottom of the axes, horizontally centered ("lower center")
+    LowerCenter,
+
+    /// Top of the axes, horizontally centered ("upper center")
+    UpperCenter,
+
+    /// Center of the axes ("center")
+    Center,
+}
+
+impl fmt::Display for LegendLocation {
+    /// Writes the option passed to Matplotlib (without quotes)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let option = match self {
+            LegendLocation::Best => "best",
+            LegendLocation::UpperRight => "upper right",
+            LegendLocation::UpperLeft => "upper left",
+            LegendLocation::LowerLeft => "lower left",
+            LegendLocation::LowerRight => "lower right",
+            LegendLocation::Right => "right",
+            LegendLocation::CenterLeft => "center left",
+            LegendLocation::CenterRight => "center right",
+            LegendLocation::LowerCenter => "lower center",
+            LegendLocation::UpperCenter => "upper center",
+            LegendLocation::Center => "center",
+        };
+        write!(f, "{}", option)
+    }
+}
+
+impl FromStr for LegendLocation {
+    type Err = StrError;
+    fn from_str(option: &str) -> Result<Self, Self::Err> {
+        match option {
+            "best" => Ok(LegendLocation::Best),
+            "upper right" => Ok(LegendLocation::UpperRight),
+            "upper left" => Ok(LegendLocation::UpperLeft),
+            "lower left" => Ok(LegendLocation::LowerLeft),
+            "lower right" => Ok(LegendLocation::LowerRight),
+            "right" => Ok(LegendLocation::Right),
+            "center left" => Ok(LegendLocation::CenterLeft),
+            "center right" => Ok(LegendLocation::CenterRight),
+            "lower center" => Ok(LegendLocation::LowerCenter),
+            "upper center" => Ok(LegendLocation::UpperCenter),
+            "center" => Ok(LegendLocation::Center),
+            _ => Err("invalid legend location"),
+        }
+    }
+}
 
 /// Generates a Legend
 ///
@@ -67,14 +149,16 @@ use std::fmt::Write;
 ///
 /// See also integration tests in the [tests directory](https://github.com/cpmech/plotpy/tree/main/tests)
 pub struct Legend {
-    fontsize: f64,      // Fontsize
-    handle_len: f64,    // Length of legend's indicator line
-    num_col: usize,     // Number of columns
-    location: String,   // Location, e.g., "best", "right", "center left"
-    outside: bool,      // Put legend outside plot area
-    show_frame: bool,   // Show frame around legend
-    x_coords: Vec<f64>, // Normalized coordinates to put legend outside
-    buffer: String,     // buffer
+    fontsize: f64,              // Fontsize
+    handle_len: f64,            // Length of legend's indicator line
+    num_col: usize,             // Number of columns
+    location: String,           // Location, e.g., "best", "right", "center left"
+    outside: bool,              // Put legend outside plot area
+    show_frame: bool,           // Show frame around legend
+    x_coords: Vec<f64>,         // Normalized coordinates to put legend outside
+    anchor: Vec<f64>,           // Anchor point of the legend (empty means unset)
+    anchor_coords: CoordSystem, // Coordinate system of the anchor point
+    buffer: String,             // buffer
 }
 
 impl Legend {
@@ -88,6 +172,8 @@ impl Legend {
             outside: false,
             show_frame: true,
             x_coords: vec![0.0, 1.02, 1.0, 0.102],
+            anchor: Vec::new(),
+            anchor_coords: CoordSystem::Axes,
             buffer: String::new(),
         }
     }
@@ -129,10 +215,10 @@ impl Legend {
     ///
     /// Options:
     ///
-    /// * "best", "right", "center left"
+    /// * [LegendLocation::Best], [LegendLocation::Right], [LegendLocation::CenterLeft]; see [LegendLocation]
     /// * Note: Only used if outside == false
-    pub fn set_location(&mut self, location: &str) -> &mut Self {
-        self.location = String::from(location);
+    pub fn set_location(&mut self, location: LegendLocation) -> &mut Self {
+        self.location = location.to_string();
         self
     }
 
@@ -156,6 +242,24 @@ impl Legend {
         self
     }
 
+    /// Sets the anchor point of the legend
+    ///
+    /// The point of the legend box given by the location (e.g., "upper left") is placed at the anchor point.
+    ///
+    /// * Note: Only used if outside == false
+    pub fn set_anchor(&mut self, x: f64, y: f64) -> &mut Self {
+        self.anchor = vec![x, y];
+        self
+    }
+
+    /// Sets the coordinate system of the anchor point (default is "axes")
+    ///
+    /// Options: [CoordSystem::Data], [CoordSystem::Axes], [CoordSystem::Figure], [CoordSystem::XDataYAxes], [CoordSystem::OffsetPoints]
+    pub fn set_anchor_coords(&mut self, coords: CoordSystem) -> &mut Self {
+        self.anchor_coords = coords;
+        self
+    }
+
     /// Returns options for legend
     fn options(&self) -> String {
         let mut opt = String::new();
@@ -182,6 +286,18 @@ impl Legend {
         } else {
             if self.location != "" {
                 write!(&mut opt, "{}loc='{}'", comma, self.location).unwrap();
+                comma = ",";
+            }
+            if self.anchor.len() == 2 {
+                write!(
+                    &mut opt,
+                    "{}bbox_to_anchor=({},{})",
+                    comma, self.anchor[0], self.anchor[1]
+                )
+                .unwrap();
+                if self.anchor_coords != CoordSystem::Axes {
+                    write!(&mut opt, ",bbox_transform={}", self.anchor_coords.transform()).unwrap();
+                }
             }
         }
         opt
@@ -201,8 +317,8 @@ impl GraphMaker for Legend {
 
 #[cfg(test)]
 mod tests {
-    use super::Legend;
-    use crate::GraphMaker;
+    use super::{Legend, LegendLocation};
+    use crate::{CoordSystem, GraphMaker};
 
     #[test]
     fn new_works() {
@@ -217,6 +333,30 @@ mod tests {
         assert_eq!(legend.buffer.len(), 0);
     }
 
+    #[test]
+    fn legend_location_works() {
+        let all = [
+            "best",
+            "upper right",
+            "upper left",
+            "lower left",
+            "lower right",
+            "right",
+            "center left",
+            "center right",
+            "lower center",
+            "upper center",
+            "center",
+        ];
+        for option in all {
+            assert_eq!(option.parse::<LegendLocation>().unwrap().to_string(), option);
+        }
+        assert_eq!("top".parse::<LegendLocation>().err(), Some("invalid legend location"));
+        let mut legend = Legend::new();
+        legend.set_location(LegendLocation::UpperLeft);
+        assert_eq!(legend.options(), "handlelength=3,ncol=1,loc='upper left'");
+    }
+
     #[test]
     fn options_works() {
         let mut legend = Legend::new();
@@ -225,6 +365,23 @@ mod tests {
         assert_eq!(opt, "handlelength=6,ncol=1,loc='best'");
     }
 
+    #[test]
+    fn options_anchor_works() {
+        let mut legend = Legend::new();
+        legend.set_location(LegendLocation::UpperLeft).set_anchor(1.02, 1.0);
+        assert_eq!(
+            legend.options(),
+            "handlelength=3,ncol=1,loc='upper left',bbox_to_anchor=(1.02,1)"
+        );
+        legend.set_anchor_coords(CoordSystem::Data).set_anchor(2.0, 3.5);
+        assert_eq!(
+            legend.options(),
+            "handlelength=3,ncol=1,loc='upper left',bbox_to_anchor=(2,3.5),bbox_transform=plt.gca().transData"
+        );
+        legend.set_outside(true);
+        assert!(!legend.options().contains("bbox_transform"));
+    }
+
     #[test]
     fn draw_works() {
         let mut legend = Legend::new();
@@ -15,8 +15,9 @@
 //! for more information regarding file extensions.
 //!
 //! We generate the Python script with the preamble listed in [PYTHON_HEADER] and the file
-//! should be useful for double checking or even directly adding Python/Matplotlib commands,
-//! in case the functionality is not implemented here yet.
+//! should be useful for double checking. In case the functionality is not implemented here yet,
+//! Python/Matplotlib commands may be injected via [RawPython] or [Plot::add_python_line];
+//! hence, the commands are not lost when the script is generated again.
 //!
 //! When calling [Plot::save] or [Plot::save_and_show], if an error occurs, we generate a log
 //! file in the same output directory with the same filename as the figure (and python script),
@@ -25,7 +26,8 @@
 //! The typical use of this library is by allocating structures such as [Canvas], [Curve], [Contour],
 //! [Histogram], [Surface], [Text] (and more) and then passing them to [Plot] for the generation
 //! of the files mentioned above. The [Plot::save_and_show] function may also be used to immediately
-//! see the plot or drawing on the screen.
+//! see the plot or drawing on the screen. Sequences of frames may be saved as animated GIF files
+//! (or numbered PNG files) by the [Animation] structure.
 //!
 //! Each structure (e.g. [Curve], [Legend], or [Text]) defines many configuration options
 //! that can be set by calling their own `set_...` function. Typically, these structures provide
@@ -36,7 +38,7 @@
 //! # Example
 //!
 //! ```
-//! use plotpy::{Plot, StrError, Surface};
+//! use plotpy::{LineStyle, Plot, StrError, Surface};
 //! use russell_lab::generate3d;
 //!
 //! fn main() -> Result<(), StrError> {
@@ -47,7 +49,7 @@
 //!         .set_with_colorbar(true)
 //!         .set_colorbar_label("temperature")
 //!         .set_line_color("#1862ab")
-//!         .set_line_style(":")
+//!         .set_line_style(LineStyle::Dotted)
 //!         .set_line_width(0.75);
 //!
 //!     // draw surface
@@ -71,36 +73,76 @@
 pub type StrError = &'static str;
 
 // modules ////////////////////////////////////////
+mod alignment;
+mod animation;
+mod annotation;
+mod arrow_style;
 mod as_matrix;
 mod as_vector;
 mod canvas;
+mod color;
+mod colorbar;
+mod colormap;
+mod connection_style;
 mod constants;
 mod contour;
 mod conversions;
+mod coord_system;
 mod curve;
+mod environment;
 mod fileio;
 mod histogram;
+mod inset;
 mod legend;
+mod line_style;
+mod marker;
+mod palette;
 mod plot;
+mod polygon;
+mod raw_python;
+mod save_options;
 mod slope_icon;
 mod surface;
 mod surface_geometry;
+mod target_versions;
 mod text;
+mod theme;
+mod typography;
+pub use crate::alignment::*;
+pub use crate::animation::*;
+pub use crate::annotation::*;
+pub use crate::arrow_style::*;
 pub use crate::as_matrix::*;
 pub use crate::as_vector::*;
 pub use crate::canvas::*;
+pub use crate::color::*;
+pub use crate::colorbar::*;
+pub use crate::colormap::*;
+pub use crate::connection_style::*;
 pub use crate::constants::*;
 pub use crate::contour::*;
 use crate::conversions::*;
+pub use crate::coord_system::*;
 pub use crate::curve::*;
+pub use crate::environment::*;
 use crate::fileio::*;
 pub use crate::histogram::*;
+pub use crate::inset::*;
 pub use crate::legend::*;
+pub use crate::line_style::*;
+pub use crate::marker::*;
+pub use crate::palette::*;
 pub use crate::plot::*;
+pub use crate::polygon::*;
+pub use crate::raw_python::*;
+pub use crate::save_options::*;
 pub use crate::slope_icon::*;
 pub use crate::surface::*;
 pub use crate::surface_geometry::*;
+pub use crate::target_versions::*;
 pub use crate::text::*;
+pub use crate::theme::*;
+pub use crate::typography::*;
 
 // run code from README file
 #[cfg(doctest)]
@@ -0,0 +1,162 @@
+use super::StrError;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Defines the style of lines
+///
+/// All setters accepting line styles (e.g., [crate::Curve::set_line_style]) take a `LineStyle` (or a
+/// reference to it); hence, invalid styles are caught at compile time. The Matplotlib strings "`-`",
+/// "`--`", "`-.`", "`:`", "`None`" (or "solid", "dashed", "dashdot", "dotted", "none"), and the empty
+/// string (default style) may be converted by `str::parse`, which returns an error for invalid strings.
+///
+/// See [Matplotlib](https://matplotlib.org/stable/gallery/lines_bars_and_markers/linestyles.html)
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, LineStyle, Plot, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // draw curves
+///     let mut curve = Curve::new();
+///     let styles = [
+///         LineStyle::Solid,
+///         LineStyle::Dashed,
+///         LineStyle::DashDot,
+///         LineStyle::Dotted,
+///         LineStyle::Dashes(0.0, vec![10.0, 2.0, 2.0, 2.0]),
+///     ];
+///     for (i, style) in styles.into_iter().enumerate() {
+///         let y = i as f64;
+///         curve.set_line_style(style).draw(&[0.0, 1.0], &[y, y]);
+///     }
+///
+///     // add curves to plot
+///     let mut plot = Plot::new();
+///     plot.add(&curve);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_line_style.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_line_style.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_line_style.svg)
+#[derive(Clone, Debug, PartialEq)]
+pub enum LineStyle {
+    /// Default line style (the option is not passed to Matplotlib)
+    Default,
+
+    /// Solid line ("`-`")
+    Solid,
+
+    /// Dashed line ("`--`")
+    Dashed,
+
+    /// Dash-dot line ("`-.`")
+    DashDot,
+
+    /// Dotted line ("`:`")
+    Dotted,
+
+    /// No line ("`None`")
+    None,
+
+    /// Custom dashes given by (offset, on-off sequence) with lengths in points
+    ///
+    /// For example, `LineStyle::Dashes(0.0, vec![10.0, 2.0, 2.0, 2.0])` draws a 10pt dash,
+    /// a 2pt space, a 2pt dash, and a 2pt space, repeatedly.
+    Dashes(f64, Vec<f64>),
+}
+
+impl LineStyle {
+    /// Returns the Python literal representing this line style (`None` for the default style)
+    pub fn to_python(&self) -> String {
+        match self {
+            LineStyle::Default => "None".to_string(),
+            LineStyle::Solid => "'-'".to_string(),
+            LineStyle::Dashed => "'--'".to_string(),
+            LineStyle::DashDot => "'-.'".to_string(),
+            LineStyle::Dotted => "':'".to_string(),
+            LineStyle::None => "'None'".to_string(),
+            LineStyle::Dashes(offset, sequence) => {
+                let mut buffer = format!("({},(", offset);
+                for length in sequence {
+                    write!(&mut buffer, "{},", length).unwrap();
+                }
+                buffer.push_str("))");
+                buffer
+            }
+        }
+    }
+
+    /// Returns the Python literal stored by the setters (empty for the default style, i.e., the option is omitted)
+    pub(crate) fn to_option(&self) -> String {
+        match self {
+            LineStyle::Default => String::new(),
+            _ => self.to_python(),
+        }
+    }
+}
+
+impl FromStr for LineStyle {
+    type Err = StrError;
+    fn from_str(style: &str) -> Result<Self, Self::Err> {
+        match style {
+            "" => Ok(LineStyle::Default),
+            "-" | "solid" => Ok(LineStyle::Solid),
+            "--" | "dashed" => Ok(LineStyle::Dashed),
+            "-." | "dashdot" => Ok(LineStyle::DashDot),
+            ":" | "dotted" => Ok(LineStyle::Dotted),
+            "None" | "none" | " " => Ok(LineStyle::None),
+            _ => Err("invalid line style"),
+        }
+    }
+}
+
+impl From<&LineStyle> for LineStyle {
+    fn from(style: &LineStyle) -> Self {
+        style.clone()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::LineStyle;
+
+    #[test]
+    fn to_python_works() {
+        assert_eq!(LineStyle::Default.to_python(), "None");
+        assert_eq!(LineStyle::Solid.to_python(), "'-'");
+        assert_eq!(LineStyle::Dashed.to_python(), "'--'");
+        assert_eq!(LineStyle::DashDot.to_python(), "'-.'");
+        assert_eq!(LineStyle::Dotted.to_python(), "':'");
+        assert_eq!(LineStyle::None.to_python(), "'None'");
+        assert_eq!(LineStyle::Dashes(1.5, vec![5.0, 2.0]).to_python(), "(1.5,(5,2,))");
+    }
+
+    #[test]
+    fn to_option_works() {
+        assert_eq!(LineStyle::Default.to_option(), "");
+        assert_eq!(LineStyle::Dashed.to_option(), "'--'");
+    }
+
+    #[test]
+    fn from_str_works() {
+        assert_eq!("".parse(), Ok(LineStyle::Default));
+        assert_eq!("-".parse(), Ok(LineStyle::Solid));
+        assert_eq!("dashed".parse(), Ok(LineStyle::Dashed));
+        assert_eq!("-.".parse(), Ok(LineStyle::DashDot));
+        assert_eq!(":".parse(), Ok(LineStyle::Dotted));
+        assert_eq!("None".parse(), Ok(LineStyle::None));
+        assert_eq!("-.-".parse::<LineStyle>().err(), Some("invalid line style"));
+    }
+
+    #[test]
+    fn from_works() {
+        let style = LineStyle::Dashes(0.0, vec![1.0]);
+        assert_eq!(LineStyle::from(&style), style);
+    }
+}
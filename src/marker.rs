@@ -0,0 +1,351 @@
+use super::StrError;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Defines the style of markers
+///
+/// All setters accepting markers (e.g., [crate::Curve::set_marker_style]) take a `Marker` (or a
+/// reference to it); hence, invalid markers are caught at compile time. The Matplotlib strings (e.g.,
+/// "`o`", "`+`", "`s`") and the empty string (default marker) may be converted by `str::parse`, which
+/// returns an error for invalid strings. A string enclosed by dollar signs (e.g., "`$\clubsuit$`") is
+/// converted into [Marker::Mathtext].
+///
+/// See [Matplotlib](https://matplotlib.org/stable/api/markers_api.html)
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Marker, Plot, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // draw curves
+///     let mut curve = Curve::new();
+///     let markers = [
+///         Marker::Circle,
+///         Marker::Square,
+///         Marker::Star(5, 0.0),
+///         Marker::Mathtext("\\clubsuit".to_string()),
+///         Marker::Path(vec![[-1.0, -1.0], [1.0, -1.0], [0.0, 1.0], [-1.0, -1.0]]),
+///     ];
+///     for (i, marker) in markers.into_iter().enumerate() {
+///         let y = i as f64;
+///         curve
+///             .set_marker_style(marker)
+///             .set_marker_size(12.0)
+///             .draw(&[0.0, 1.0, 2.0], &[y, y, y]);
+///     }
+///
+///     // add curves to plot
+///     let mut plot = Plot::new();
+///     plot.add(&curve);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_marker.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_marker.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_marker.svg)
+#[derive(Clone, Debug, PartialEq)]
+pub enum Marker {
+    /// Default marker (the option is not passed to Matplotlib)
+    Default,
+
+    /// Point ("`.`")
+    Point,
+
+    /// Pixel ("`,`")
+    Pixel,
+
+    /// Circle ("`o`")
+    Circle,
+
+    /// Triangle pointing down ("`v`")
+    TriangleDown,
+
+    /// Triangle pointing up ("`^`")
+    TriangleUp,
+
+    /// Triangle pointing left ("`<`")
+    TriangleLeft,
+
+    /// Triangle pointing right ("`>`")
+    TriangleRight,
+
+    /// Tri pointing down ("`1`")
+    TriDown,
+
+    /// Tri pointing up ("`2`")
+    TriUp,
+
+    /// Tri pointing left ("`3`")
+    TriLeft,
+
+    /// Tri pointing right ("`4`")
+    TriRight,
+
+    /// Octagon ("`8`")
+    Octagon,
+
+    /// Square ("`s`")
+    Square,
+
+    /// Pentagon ("`p`")
+    Pentagon,
+
+    /// Filled plus ("`P`")
+    PlusFilled,
+
+    /// Star ("`*`")
+    StarFilled,
+
+    /// Hexagon with a vertex on top ("`h`")
+    Hexagon1,
+
+    /// Hexagon with a flat side on top ("`H`")
+    Hexagon2,
+
+    /// Plus ("`+`")
+    Plus,
+
+    /// Cross ("`x`")
+    Cross,
+
+    /// Filled cross ("`X`")
+    CrossFilled,
+
+    /// Diamond ("`D`")
+    Diamond,
+
+    /// Thin diamond ("`d`")
+    ThinDiamond,
+
+    /// Vertical line ("`|`")
+    VerticalLine,
+
+    /// Horizontal line ("`_`")
+    HorizontalLine,
+
+    /// No marker ("`None`")
+    None,
+
+    /// Tick pointing left (Matplotlib's integer marker `0`)
+    TickLeft,
+
+    /// Tick pointing right (Matplotlib's integer marker `1`)
+    TickRight,
+
+    /// Tick pointing up (Matplotlib's integer marker `2`)
+    TickUp,
+
+    /// Tick pointing down (Matplotlib's integer marker `3`)
+    TickDown,
+
+    /// Caret pointing left (Matplotlib's integer marker `4`)
+    CaretLeft,
+
+    /// Caret pointing right (Matplotlib's integer marker `5`)
+    CaretRight,
+
+    /// Caret pointing up (Matplotlib's integer marker `6`)
+    CaretUp,
+
+    /// Caret pointing down (Matplotlib's integer marker `7`)
+    CaretDown,
+
+    /// Caret pointing left with the base at the center (Matplotlib's integer marker `8`)
+    CaretLeftBase,
+
+    /// Caret pointing right with the base at the center (Matplotlib's integer marker `9`)
+    CaretRightBase,
+
+    /// Caret pointing up with the base at the center (Matplotlib's integer marker `10`)
+    CaretUpBase,
+
+    /// Caret pointing down with the base at the center (Matplotlib's integer marker `11`)
+    CaretDownBase,
+
+    /// Regular polygon given by (number of sides, rotation angle in degrees)
+    Polygon(usize, f64),
+
+    /// Star-like symbol given by (number of points, rotation angle in degrees)
+    Star(usize, f64),
+
+    /// Asterisk given by (number of points, rotation angle in degrees)
+    Asterisk(usize, f64),
+
+    /// Mathtext symbol (without the dollar signs), e.g., `"\\clubsuit"` or `"f"`
+    Mathtext(String),
+
+    /// Custom path given by the vertices (normalized such that the maximum coordinate is 1)
+    ///
+    /// For a closed shape, the last vertex should be equal to the first one.
+    Path(Vec<[f64; 2]>),
+}
+
+impl Marker {
+    /// Returns the Python literal representing this marker (`None` for the default marker)
+    pub fn to_python(&self) -> String {
+        let code = match self {
+            Marker::Default => return "None".to_string(),
+            Marker::Point => ".",
+            Marker::Pixel => ",",
+            Marker::Circle => "o",
+            Marker::TriangleDown => "v",
+            Marker::TriangleUp => "^",
+            Marker::TriangleLeft => "<",
+            Marker::TriangleRight => ">",
+            Marker::TriDown => "1",
+            Marker::TriUp => "2",
+            Marker::TriLeft => "3",
+            Marker::TriRight => "4",
+            Marker::Octagon => "8",
+            Marker::Square => "s",
+            Marker::Pentagon => "p",
+            Marker::PlusFilled => "P",
+            Marker::StarFilled => "*",
+            Marker::Hexagon1 => "h",
+            Marker::Hexagon2 => "H",
+            Marker::Plus => "+",
+            Marker::Cross => "x",
+            Marker::CrossFilled => "X",
+            Marker::Diamond => "D",
+            Marker::ThinDiamond => "d",
+            Marker::VerticalLine => "|",
+            Marker::HorizontalLine => "_",
+            Marker::None => "None",
+            Marker::TickLeft => return "0".to_string(),
+            Marker::TickRight => return "1".to_string(),
+            Marker::TickUp => return "2".to_string(),
+            Marker::TickDown => return "3".to_string(),
+            Marker::CaretLeft => return "4".to_string(),
+            Marker::CaretRight => return "5".to_string(),
+            Marker::CaretUp => return "6".to_string(),
+            Marker::CaretDown => return "7".to_string(),
+            Marker::CaretLeftBase => return "8".to_string(),
+            Marker::CaretRightBase => return "9".to_string(),
+            Marker::CaretUpBase => return "10".to_string(),
+            Marker::CaretDownBase => return "11".to_string(),
+            Marker::Polygon(n, angle) => return format!("({},0,{})", n, angle),
+            Marker::Star(n, angle) => return format!("({},1,{})", n, angle),
+            Marker::Asterisk(n, angle) => return format!("({},2,{})", n, angle),
+            Marker::Mathtext(symbol) => return format!("r'${}$'", symbol),
+            Marker::Path(vertices) => {
+                let mut buffer = String::from("[");
+                for v in vertices {
+                    write!(&mut buffer, "({},{}),", v[0], v[1]).unwrap();
+                }
+                buffer.push(']');
+                return buffer;
+            }
+        };
+        format!("'{}'", code)
+    }
+
+    /// Returns the Python literal stored by the setters (empty for the default marker, i.e., the option is omitted)
+    pub(crate) fn to_option(&self) -> String {
+        match self {
+            Marker::Default => String::new(),
+            _ => self.to_python(),
+        }
+    }
+}
+
+impl FromStr for Marker {
+    type Err = StrError;
+    fn from_str(marker: &str) -> Result<Self, Self::Err> {
+        if marker.len() > 2 && marker.starts_with('$') && marker.ends_with('$') {
+            return Ok(Marker::Mathtext(marker[1..marker.len() - 1].to_string()));
+        }
+        match marker {
+            "" => Ok(Marker::Default),
+            "." => Ok(Marker::Point),
+            "," => Ok(Marker::Pixel),
+            "o" => Ok(Marker::Circle),
+            "v" => Ok(Marker::TriangleDown),
+            "^" => Ok(Marker::TriangleUp),
+            "<" => Ok(Marker::TriangleLeft),
+            ">" => Ok(Marker::TriangleRight),
+            "1" => Ok(Marker::TriDown),
+            "2" => Ok(Marker::TriUp),
+            "3" => Ok(Marker::TriLeft),
+            "4" => Ok(Marker::TriRight),
+            "8" => Ok(Marker::Octagon),
+            "s" => Ok(Marker::Square),
+            "p" => Ok(Marker::Pentagon),
+            "P" => Ok(Marker::PlusFilled),
+            "*" => Ok(Marker::StarFilled),
+            "h" => Ok(Marker::Hexagon1),
+            "H" => Ok(Marker::Hexagon2),
+            "+" => Ok(Marker::Plus),
+            "x" => Ok(Marker::Cross),
+            "X" => Ok(Marker::CrossFilled),
+            "D" => Ok(Marker::Diamond),
+            "d" => Ok(Marker::ThinDiamond),
+            "|" => Ok(Marker::VerticalLine),
+            "_" => Ok(Marker::HorizontalLine),
+            "None" | "none" | " " => Ok(Marker::None),
+            _ => Err("invalid marker"),
+        }
+    }
+}
+
+impl From<&Marker> for Marker {
+    fn from(marker: &Marker) -> Self {
+        marker.clone()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Marker;
+
+    #[test]
+    fn to_python_works() {
+        assert_eq!(Marker::Default.to_python(), "None");
+        assert_eq!(Marker::Circle.to_python(), "'o'");
+        assert_eq!(Marker::TickLeft.to_python(), "0");
+        assert_eq!(Marker::CaretDownBase.to_python(), "11");
+        assert_eq!(Marker::None.to_python(), "'None'");
+        assert_eq!(Marker::Polygon(6, 30.0).to_python(), "(6,0,30)");
+        assert_eq!(Marker::Star(5, 0.0).to_python(), "(5,1,0)");
+        assert_eq!(Marker::Asterisk(3, 0.0).to_python(), "(3,2,0)");
+        assert_eq!(
+            Marker::Mathtext("\\clubsuit".to_string()).to_python(),
+            "r'$\\clubsuit$'"
+        );
+        assert_eq!(
+            Marker::Path(vec![[0.0, 0.0], [1.0, 0.5]]).to_python(),
+            "[(0,0),(1,0.5),]"
+        );
+    }
+
+    #[test]
+    fn to_option_works() {
+        assert_eq!(Marker::Default.to_option(), "");
+        assert_eq!(Marker::CaretUp.to_option(), "6");
+    }
+
+    #[test]
+    fn from_str_works() {
+        let all = ".,ov^<>12348spP*hHx+XDd|_";
+        for code in all.chars() {
+            let marker: Marker = code.to_string().parse().unwrap();
+            assert_eq!(marker.to_python(), format!("'{}'", code));
+        }
+        assert_eq!("".parse(), Ok(Marker::Default));
+        assert_eq!("none".parse(), Ok(Marker::None));
+        assert_eq!("$f$".parse(), Ok(Marker::Mathtext("f".to_string())));
+        assert_eq!("$".parse::<Marker>().err(), Some("invalid marker"));
+        assert_eq!("$$".parse::<Marker>().err(), Some("invalid marker"));
+        assert_eq!("oo".parse::<Marker>().err(), Some("invalid marker"));
+    }
+
+    #[test]
+    fn from_works() {
+        let marker = Marker::Star(5, 0.0);
+        assert_eq!(Marker::from(&marker), marker);
+    }
+}
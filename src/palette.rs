@@ -0,0 +1,291 @@
+use super::{Color, LineStyle, Marker, StrError};
+use std::fmt::Write;
+
+/// Reference colors of the viridis colormap (equally spaced)
+const VIRIDIS: [&str; 9] = [
+    "#440154", "#472D7B", "#3B528B", "#2C728E", "#21908C", "#27AD81", "#5DC863", "#AADC32", "#FDE725",
+];
+
+/// Holds a list of colors, optionally combined with line styles and markers, to be cycled through
+///
+/// A palette may be set as the property cycle of the current axes by [crate::Plot::set_palette] or
+/// of all axes by [crate::Theme::set_palette]. In this case, curves without an explicit color, line
+/// style, or marker get the next entry of the palette. The colors may also be retrieved in Rust; e.g.,
+/// to be passed to [crate::Histogram::set_colors] or [crate::Contour::set_colors].
+///
+/// If the line styles or markers are given, the property cycle has as many entries as the longest
+/// list, and the shorter lists are repeated. For example, three colors combined with four line styles
+/// yield four entries, in which the first color is used again in the fourth entry.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Histogram, Palette, Plot, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // palette
+///     let palette = Palette::okabe_ito();
+///
+///     // draw curves using the property cycle
+///     let mut curve = Curve::new();
+///     for i in 0..4 {
+///         let y = i as f64;
+///         curve.draw(&[0.0, 1.0, 2.0], &[y, y + 1.0, y]);
+///     }
+///
+///     // draw histogram using the colors from the palette
+///     let mut histogram = Histogram::new();
+///     histogram
+///         .set_colors(&palette.get_cycled_colors(2))
+///         .draw(&vec![vec![1, 1, 2, 3, 3, 3], vec![2, 2, 2, 3, 4]], &["first", "second"]);
+///
+///     // add curves and histogram to plot
+///     let mut plot = Plot::new();
+///     plot.set_subplot(1, 2, 1).set_palette(&palette).add(&curve);
+///     plot.set_subplot(1, 2, 2).add(&histogram);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_palette.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_palette.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_palette.svg)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    colors: Vec<Color>,          // Colors
+    line_styles: Vec<LineStyle>, // Line styles (optional)
+    markers: Vec<Marker>,        // Markers (optional)
+}
+
+impl Palette {
+    /// Creates a new palette from a list of colors
+    ///
+    /// Returns an error if the list is empty or any color is invalid.
+    pub fn from_colors(colors: &[&str]) -> Result<Self, StrError> {
+        if colors.is_empty() {
+            return Err("the palette requires at least one color");
+        }
+        let mut res = Vec::new();
+        for color in colors {
+            res.push(Color::parse(color)?);
+        }
+        Ok(Palette::with_colors(res))
+    }
+
+    /// Returns the color-blind safe palette by Okabe and Ito (8 colors)
+    ///
+    /// See [Okabe and Ito](https://jfly.uni-koeln.de/color/)
+    pub fn okabe_ito() -> Self {
+        Palette::from_hex(&[
+            "#E69F00", "#56B4E9", "#009E73", "#F0E442", "#0072B2", "#D55E00", "#CC79A7", "#000000",
+        ])
+    }
+
+    /// Returns the color-blind safe "bright" palette by Paul Tol (7 colors)
+    ///
+    /// See [Paul Tol's notes](https://personal.sron.nl/~pault/)
+    pub fn tol_bright() -> Self {
+        Palette::from_hex(&[
+            "#4477AA", "#EE6677", "#228833", "#CCBB44", "#66CCEE", "#AA3377", "#BBBBBB",
+        ])
+    }
+
+    /// Returns the color-blind safe "muted" palette by Paul Tol (9 colors)
+    ///
+    /// See [Paul Tol's notes](https://personal.sron.nl/~pault/)
+    pub fn tol_muted() -> Self {
+        Palette::from_hex(&[
+            "#CC6677", "#332288", "#DDCC77", "#117733", "#88CCEE", "#882255", "#44AA99", "#999933", "#AA4499",
+        ])
+    }
+
+    /// Returns the color-blind safe "vibrant" palette by Paul Tol (7 colors)
+    ///
+    /// See [Paul Tol's notes](https://personal.sron.nl/~pault/)
+    pub fn tol_vibrant() -> Self {
+        Palette::from_hex(&[
+            "#EE7733", "#0077BB", "#33BBEE", "#EE3377", "#CC3311", "#009988", "#BBBBBB",
+        ])
+    }
+
+    /// Returns `n` colors equally sampled from the viridis colormap (from dark purple to yellow)
+    ///
+    /// **Note:** If `n` is zero, one color is returned.
+    pub fn viridis(n: usize) -> Self {
+        let reference: Vec<Color> = VIRIDIS.iter().map(|c| Color::from(*c)).collect();
+        let m = reference.len() - 1;
+        let n = usize::max(n, 1);
+        let mut colors = Vec::new();
+        for i in 0..n {
+            let t = if n == 1 { 0.0 } else { (i as f64) / ((n - 1) as f64) };
+            let k = usize::min((t * m as f64) as usize, m - 1);
+            let local = t * m as f64 - k as f64;
+            colors.push(reference[k].interpolate(&reference[k + 1], local));
+        }
+        Palette::with_colors(colors)
+    }
+
+    /// Returns a palette for grayscale printing (three gray levels, four line styles, and four markers)
+    pub fn grayscale() -> Self {
+        let mut palette = Palette::from_hex(&["#000000", "#555555", "#999999"]);
+        palette
+            .set_line_styles(&[
+                LineStyle::Solid,
+                LineStyle::Dashed,
+                LineStyle::DashDot,
+                LineStyle::Dotted,
+            ])
+            .set_markers(&[Marker::Circle, Marker::Square, Marker::TriangleUp, Marker::Diamond]);
+        palette
+    }
+
+    /// Sets the line styles to be cycled together with the colors
+    pub fn set_line_styles<L>(&mut self, styles: &[L]) -> &mut Self
+    where
+        L: Clone + Into<LineStyle>,
+    {
+        self.line_styles = styles.iter().map(|s| s.clone().into()).collect();
+        self
+    }
+
+    /// Sets the markers to be cycled together with the colors
+    pub fn set_markers<M>(&mut self, markers: &[M]) -> &mut Self
+    where
+        M: Clone + Into<Marker>,
+    {
+        self.markers = markers.iter().map(|m| m.clone().into()).collect();
+        self
+    }
+
+    /// Returns the colors
+    pub fn get_colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Returns the color at the given index (the colors are repeated if the index is out of range)
+    pub fn get_color(&self, index: usize) -> &Color {
+        &self.colors[index % self.colors.len()]
+    }
+
+    /// Returns `n` colors (the colors are repeated if `n` is greater than the number of colors)
+    pub fn get_cycled_colors(&self, n: usize) -> Vec<Color> {
+        (0..n).map(|i| self.get_color(i).clone()).collect()
+    }
+
+    /// Returns the Python expression defining the property cycle
+    pub(crate) fn to_python(&self) -> String {
+        let n = usize::max(
+            self.colors.len(),
+            usize::max(self.line_styles.len(), self.markers.len()),
+        );
+        let mut buffer = String::from("plt.cycler(color=[");
+        for i in 0..n {
+            write!(&mut buffer, "{},", self.get_color(i).to_python()).unwrap();
+        }
+        buffer.push(']');
+        if !self.line_styles.is_empty() {
+            buffer.push_str(",linestyle=[");
+            for i in 0..n {
+                write!(
+                    &mut buffer,
+                    "{},",
+                    self.line_styles[i % self.line_styles.len()].to_python()
+                )
+                .unwrap();
+            }
+            buffer.push(']');
+        }
+        if !self.markers.is_empty() {
+            buffer.push_str(",marker=[");
+            for i in 0..n {
+                write!(&mut buffer, "{},", self.markers[i % self.markers.len()].to_python()).unwrap();
+            }
+            buffer.push(']');
+        }
+        buffer.push(')');
+        buffer
+    }
+
+    /// Creates a new palette from a list of colors
+    fn with_colors(colors: Vec<Color>) -> Self {
+        Palette {
+            colors,
+            line_styles: Vec::new(),
+            markers: Vec::new(),
+        }
+    }
+
+    /// Creates a new palette from a list of (valid) hex codes
+    fn from_hex(colors: &[&str]) -> Self {
+        Palette::with_colors(colors.iter().map(|c| Color::from(*c)).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Palette;
+    use crate::{Color, LineStyle, Marker};
+
+    #[test]
+    fn from_colors_works() {
+        assert_eq!(
+            Palette::from_colors(&[]).err(),
+            Some("the palette requires at least one color")
+        );
+        assert_eq!(
+            Palette::from_colors(&["red", "wrong"]).err(),
+            Some("invalid color: unknown color name")
+        );
+        let palette = Palette::from_colors(&["red", "#00f"]).unwrap();
+        assert_eq!(palette.get_colors(), &[Color::from("red"), Color::from("#00f")]);
+        assert_eq!(palette.to_python(), "plt.cycler(color=['red','#00f',])");
+    }
+
+    #[test]
+    fn presets_work() {
+        assert_eq!(Palette::okabe_ito().get_colors().len(), 8);
+        assert_eq!(Palette::tol_bright().get_colors().len(), 7);
+        assert_eq!(Palette::tol_muted().get_colors().len(), 9);
+        assert_eq!(Palette::tol_vibrant().get_colors().len(), 7);
+        assert_eq!(Palette::okabe_ito().get_color(0).to_string(), "#E69F00");
+        assert_eq!(Palette::okabe_ito().get_color(9).to_string(), "#56B4E9");
+    }
+
+    #[test]
+    fn viridis_works() {
+        let palette = Palette::viridis(3);
+        let colors: Vec<_> = palette.get_colors().iter().map(|c| c.to_string()).collect();
+        assert_eq!(colors, &["#440154", "#21908c", "#fde725"]);
+        let palette = Palette::viridis(17);
+        assert_eq!(palette.get_colors().len(), 17);
+        assert_eq!(palette.get_color(2).to_string(), "#472d7b");
+        assert_eq!(Palette::viridis(0).get_colors().len(), 1);
+    }
+
+    #[test]
+    fn get_cycled_colors_works() {
+        let palette = Palette::from_colors(&["red", "green"]).unwrap();
+        let colors = palette.get_cycled_colors(3);
+        assert_eq!(colors, &[Color::from("red"), Color::from("green"), Color::from("red")]);
+    }
+
+    #[test]
+    fn to_python_works() {
+        let mut palette = Palette::from_colors(&["k", "gray"]).unwrap();
+        palette.set_line_styles(&[LineStyle::Solid, LineStyle::Dashed, LineStyle::Dotted]);
+        assert_eq!(
+            palette.to_python(),
+            "plt.cycler(color=['k','gray','k',],linestyle=['-','--',':',])"
+        );
+        palette.set_markers(&[Marker::Circle]);
+        assert_eq!(
+            palette.to_python(),
+            "plt.cycler(color=['k','gray','k',],linestyle=['-','--',':',],marker=['o','o','o',])"
+        );
+        let grayscale = Palette::grayscale().to_python();
+        assert!(grayscale.starts_with("plt.cycler(color=['#000000','#555555','#999999','#000000',]"));
+    }
+}
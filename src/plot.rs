@@ -1,9 +1,10 @@
-use super::{call_python3, Legend, StrError};
+use super::{
+    call_python3, call_python3_piped, write_log_file, write_python3, CoordSystem, Inset, Legend, Palette, SaveOptions,
+    StrError, TargetVersions, Theme, Typography, PYTHON_HEADER,
+};
 use std::ffi::OsStr;
 use std::fmt::Write;
-use std::fs::File;
-use std::io::Write as IoWrite;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Defines the trait used by Plot to add graph entities
 pub trait GraphMaker {
@@ -12,6 +13,20 @@ pub trait GraphMaker {
 
     /// Clear the text buffer with Python commands
     fn clear_buffer(&mut self);
+
+    /// Returns the extra import statements required by the Python commands (none by default)
+    ///
+    /// The import statements are written after [PYTHON_HEADER] (repeated statements are written once).
+    fn get_imports(&self) -> &[String] {
+        &[]
+    }
+
+    /// Returns the first error found while configuring the graph, e.g., an invalid color (none by default)
+    ///
+    /// The error is returned by [Plot::save] (and similar functions) before python3 is called.
+    fn get_error(&self) -> Option<StrError> {
+        None
+    }
 }
 
 /// Driver structure that calls Python
@@ -85,8 +100,15 @@ pub trait GraphMaker {
 ///
 /// See also integration tests in the [tests directory](https://github.com/cpmech/plotpy/tree/main/tests)
 pub struct Plot {
-    show_errors: bool, // show python errors, if any
-    buffer: String,    // buffer
+    show_errors: bool,                       // show python errors, if any
+    theme: String,                           // commands to apply the theme (before all other commands)
+    imports: Vec<String>,                    // extra import statements (written after the header)
+    typography: Option<Typography>,          // typography (applied after the theme)
+    save_options: SaveOptions,               // options for savefig
+    target_versions: Option<TargetVersions>, // versions of Matplotlib and NumPy (None means detect at runtime)
+    inset_count: usize,                      // number of inset axes
+    error: Option<StrError>,                 // first error found in the graphs or options (e.g., an invalid color)
+    buffer: String,                          // buffer
 }
 
 impl Plot {
@@ -94,16 +116,75 @@ impl Plot {
     pub fn new() -> Self {
         Plot {
             show_errors: false,
+            theme: String::new(),
+            imports: Vec::new(),
+            typography: None,
+            save_options: SaveOptions::new(),
+            target_versions: None,
+            inset_count: 0,
+            error: None,
             buffer: String::new(),
         }
     }
 
     /// Adds new graph entity
     pub fn add(&mut self, graph: &dyn GraphMaker) -> &mut Self {
+        self.merge_imports(graph.get_imports());
+        self.record_error(graph.get_error());
         self.buffer.push_str(graph.get_buffer());
         self
     }
 
+    /// Adds a line of Python code at the current point of the script
+    ///
+    /// The variable `ax` holds the current axes and the function `add_to_ea` may be used to register
+    /// artists that must be considered when calculating the bounding box. See also [crate::RawPython]
+    pub fn add_python_line(&mut self, line: &str) -> &mut Self {
+        write!(&mut self.buffer, "ax=plt.gca()\n{}\n", line).unwrap();
+        self
+    }
+
+    /// Adds inset axes to the current axes and returns a handle to add graphs to the inset
+    ///
+    /// # Input
+    ///
+    /// * `xmin`, `ymin` -- lower-left corner of the inset
+    /// * `width`, `height` -- dimensions of the inset
+    /// * `coords` -- the coordinate system of the bounds: [CoordSystem::Axes] (from 0 to 1) or
+    ///   [CoordSystem::Data] of the current axes
+    ///
+    /// Returns an error if `coords` is neither [CoordSystem::Axes] nor [CoordSystem::Data].
+    ///
+    /// See [Inset]
+    pub fn add_inset(
+        &mut self,
+        xmin: f64,
+        ymin: f64,
+        width: f64,
+        height: f64,
+        coords: CoordSystem,
+    ) -> Result<Inset<'_>, StrError> {
+        let data_coords = match coords {
+            CoordSystem::Axes => false,
+            CoordSystem::Data => true,
+            _ => return Err("the coordinate system of the inset must be Axes or Data"),
+        };
+        let name = format!("INSET_{}", self.inset_count);
+        self.inset_count += 1;
+        writeln!(&mut self.buffer, "{}_PARENT=plt.gca()", name).unwrap();
+        write!(
+            &mut self.buffer,
+            "{}={}_PARENT.inset_axes([{},{},{},{}]",
+            name, name, xmin, ymin, width, height
+        )
+        .unwrap();
+        if data_coords {
+            write!(&mut self.buffer, ",transform={}_PARENT.transData", name).unwrap();
+        }
+        self.buffer.push_str(")\n");
+        Ok(Inset::new(self, name))
+    }
+
     /// Calls python3 and saves the python script and figure
     ///
     /// # Input
@@ -117,7 +198,7 @@ impl Plot {
     where
         S: AsRef<OsStr> + ?Sized,
     {
-        self.run(figure_path, false)
+        self.run(figure_path, &[], false)
     }
 
     /// Calls python3, saves the python script and figure, and show the plot window
@@ -133,7 +214,151 @@ impl Plot {
     where
         S: AsRef<OsStr> + ?Sized,
     {
-        self.run(figure_path, true)
+        self.run(figure_path, &[], true)
+    }
+
+    /// Calls python3 and saves the python script and one figure for each format
+    ///
+    /// # Input
+    ///
+    /// * `figure_path` -- may be a String, &str, or Path; the extension (if any) is replaced by each format
+    /// * `formats` -- the figure formats supported by Matplotlib, e.g., `&["png", "pdf", "svg"]`
+    ///
+    /// # Note
+    ///
+    /// The figure is generated once (in a single run of python3) and saved multiple times.
+    /// Call `set_show_errors` to configure how the errors (if any) are printed.
+    pub fn save_formats<S>(&self, figure_path: &S, formats: &[&str]) -> Result<(), StrError>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        if formats.is_empty() {
+            return Err("at least one format must be given");
+        }
+        self.run(figure_path, formats, false)
+    }
+
+    /// Returns the complete Python script (header, commands, and savefig) without calling python3
+    ///
+    /// # Input
+    ///
+    /// * `figure_path` -- may be a String, &str, or Path; the path of the figure to be saved by the script
+    pub fn get_script<S>(&self, figure_path: &S) -> String
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        let targets = self.get_targets(Path::new(figure_path), &[]);
+        format!("{}{}", self.get_header(), self.get_commands(&targets, false))
+    }
+
+    /// Writes the Python script (with the `.py` extension) without calling python3
+    ///
+    /// # Input
+    ///
+    /// * `figure_path` -- may be a String, &str, or Path; the path of the figure to be saved by the script.
+    ///   The script is written to the same path, with the extension replaced by `.py`
+    ///
+    /// # Note
+    ///
+    /// The script may be run later (e.g., on another machine) by calling `python3 script.py`.
+    pub fn write_script<S>(&self, figure_path: &S) -> Result<(), StrError>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let targets = self.get_targets(Path::new(figure_path), &[]);
+        let mut path = Path::new(figure_path).to_path_buf();
+        path.set_extension("py");
+        write_python3(&self.get_header(), &self.get_commands(&targets, false), &path)
+    }
+
+    /// Calls python3 and returns the figure as bytes (no file is written)
+    ///
+    /// # Input
+    ///
+    /// * `format` -- the figure format supported by Matplotlib, e.g., "png", "svg", "pdf"
+    /// * `dpi` -- the resolution in dots per inch (ignored if ≤ 0, i.e., the dpi in [SaveOptions] is used)
+    ///
+    /// # Note
+    ///
+    /// Returns an error only if python3 exits with a non-zero status. Call `set_show_errors`
+    /// to print the standard error in this case.
+    pub fn render_to_bytes(&self, format: &str, dpi: f64) -> Result<Vec<u8>, StrError> {
+        // update commands
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        self.check_typography(&[format])?;
+        let mut options = self.save_options.clone();
+        if dpi > 0.0 {
+            options.set_dpi(dpi);
+        }
+        let commands = format!(
+            "{}{}\nimport io\nimport sys\nbuf=io.BytesIO()\n{}sys.stdout.buffer.write(buf.getvalue())\n",
+            self.get_preamble(),
+            self.buffer,
+            options.savefig(&format!("buf,format='{}'", format), format),
+        );
+
+        // call python
+        let (success, output, err) = call_python3_piped(&self.get_header(), &commands)?;
+
+        // handle error (the standard error may contain warnings even if python3 succeeds)
+        if !success {
+            if self.show_errors {
+                println!("{}", err);
+            }
+            return Err("python3 failed to render the figure");
+        }
+        Ok(output)
+    }
+
+    /// Calls python3 and returns the figure as an SVG string (no file is written)
+    ///
+    /// # Note
+    ///
+    /// Call `set_show_errors` to print the errors (if any).
+    pub fn render_to_svg_string(&self) -> Result<String, StrError> {
+        let bytes = self.render_to_bytes("svg", 0.0)?;
+        String::from_utf8(bytes).map_err(|_| "cannot convert the SVG output to string")
+    }
+
+    /// Sets the figure-wide theme (style sheets and rcParams)
+    ///
+    /// The theme is applied before all other commands, regardless of when this function is called.
+    /// Calling this function again replaces the previous theme. See [Theme]
+    pub fn set_theme(&mut self, theme: &Theme) -> &mut Self {
+        self.record_error(theme.get_error());
+        self.theme = theme.to_python();
+        self
+    }
+
+    /// Sets the options to save the figure (resolution, background, bounding box, and metadata)
+    ///
+    /// Calling this function again replaces the previous options. See [SaveOptions]
+    pub fn set_save_options(&mut self, options: &SaveOptions) -> &mut Self {
+        self.record_error(options.get_error());
+        self.save_options = options.clone();
+        self
+    }
+
+    /// Sets the figure-wide typography (LaTeX rendering and fonts)
+    ///
+    /// The typography is applied after the theme and before all other commands, regardless of when
+    /// this function is called. Calling this function again replaces the previous typography. See [Typography]
+    pub fn set_typography(&mut self, typography: &Typography) -> &mut Self {
+        self.typography = Some(typography.clone());
+        self
+    }
+
+    /// Sets the versions of Matplotlib and NumPy targeted by the generated script
+    ///
+    /// By default, the version-dependent code paths are selected at runtime. See [TargetVersions]
+    pub fn set_target_versions(&mut self, versions: &TargetVersions) -> &mut Self {
+        self.target_versions = Some(*versions);
+        self
     }
 
     /// Clears current figure
@@ -241,6 +466,14 @@ impl Plot {
         self
     }
 
+    /// Sets the property cycle (colors, line styles, and markers) of the current axes
+    ///
+    /// **Note:** Only graphs added after calling this function are affected. See also [Theme::set_palette]
+    pub fn set_palette(&mut self, palette: &Palette) -> &mut Self {
+        writeln!(&mut self.buffer, "plt.gca().set_prop_cycle({})", palette.to_python()).unwrap();
+        self
+    }
+
     /// Sets the figure size in inches
     pub fn set_figure_size_inches(&mut self, width: f64, height: f64) -> &mut Self {
         write!(&mut self.buffer, "plt.gcf().set_size_inches({},{})\n", width, height).unwrap();
@@ -617,39 +850,127 @@ impl Plot {
         self.set_frame_border(show_all, show_all, show_all, show_all)
     }
 
+    /// Returns the commands to be executed before all other commands (extra imports, theme, and typography)
+    pub(crate) fn get_preamble(&self) -> String {
+        let mut preamble = String::new();
+        for statement in &self.imports {
+            preamble.push_str(statement);
+            preamble.push('\n');
+        }
+        preamble.push_str(&self.theme);
+        if let Some(typography) = &self.typography {
+            preamble.push_str(&typography.to_python());
+        }
+        preamble
+    }
+
+    /// Checks whether the figure `formats` can be generated with the typography options
+    pub(crate) fn check_typography(&self, formats: &[&str]) -> Result<(), StrError> {
+        if let Some(typography) = &self.typography {
+            for format in formats {
+                typography.check_tex(format)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the figure paths and formats
+    ///
+    /// If `formats` is empty, the figure is saved to `figure_path`; otherwise, one figure is saved for
+    /// each format by replacing the extension of `figure_path`.
+    fn get_targets(&self, figure_path: &Path, formats: &[&str]) -> Vec<(PathBuf, String)> {
+        if formats.is_empty() {
+            let extension = figure_path.extension().unwrap_or_default().to_string_lossy();
+            vec![(figure_path.to_path_buf(), extension.to_string())]
+        } else {
+            formats
+                .iter()
+                .map(|f| (figure_path.with_extension(f), f.to_string()))
+                .collect()
+        }
+    }
+
+    /// Returns the commands (without the header) to save the figures to the given targets
+    fn get_commands(&self, targets: &[(PathBuf, String)], show: bool) -> String {
+        let mut commands = format!("{}{}", self.get_preamble(), self.buffer);
+        for (path, format) in targets {
+            write!(&mut commands, "\nfn='{}'\n", path.to_string_lossy()).unwrap();
+            commands.push_str(&self.save_options.savefig("fn", format));
+        }
+        if show {
+            commands.push_str("plt.show()\n");
+        }
+        commands
+    }
+
+    /// Appends commands to the buffer (used by [Inset])
+    pub(crate) fn push_commands(&mut self, commands: &str) {
+        self.buffer.push_str(commands);
+    }
+
+    /// Merges extra import statements (written once)
+    pub(crate) fn merge_imports(&mut self, imports: &[String]) {
+        for statement in imports {
+            if !self.imports.contains(statement) {
+                self.imports.push(statement.clone());
+            }
+        }
+    }
+
+    /// Returns the options to save the figure
+    pub(crate) fn get_save_options(&self) -> &SaveOptions {
+        &self.save_options
+    }
+
+    /// Records the first error found in the graphs or options (returned by save)
+    pub(crate) fn record_error(&mut self, error: Option<StrError>) {
+        if self.error.is_none() {
+            self.error = error;
+        }
+    }
+
+    /// Returns the first error found in the graphs or options (e.g., an invalid color)
+    pub(crate) fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
+
+    /// Returns the extra import statements and the commands (used as a frame of an [crate::Animation])
+    pub(crate) fn get_frame(&self) -> (&[String], &String) {
+        (&self.imports, &self.buffer)
+    }
+
+    /// Returns the header of the script (depending on the target versions, if any)
+    pub(crate) fn get_header(&self) -> String {
+        match &self.target_versions {
+            Some(versions) => versions.python_header(),
+            None => PYTHON_HEADER.to_string(),
+        }
+    }
+
     /// Run python
-    fn run<S>(&self, figure_path: &S, show: bool) -> Result<(), StrError>
+    ///
+    /// If `formats` is empty, the figure is saved to `figure_path`; otherwise, one figure is saved for
+    /// each format by replacing the extension of `figure_path`.
+    fn run<S>(&self, figure_path: &S, formats: &[&str], show: bool) -> Result<(), StrError>
     where
         S: AsRef<OsStr> + ?Sized,
     {
         // update commands
-        let fig_path = Path::new(figure_path);
-        let txt = if show {
-            "plt.savefig(fn,bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\nplt.show()\n"
-        } else {
-            "plt.savefig(fn,bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\n"
-        };
-        let commands = format!("{}\nfn='{}'\n{}", self.buffer, fig_path.to_string_lossy(), txt);
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let targets = self.get_targets(Path::new(figure_path), formats);
+        let target_formats: Vec<&str> = targets.iter().map(|(_, f)| f.as_str()).collect();
+        self.check_typography(&target_formats)?;
+        let commands = self.get_commands(&targets, show);
 
         // call python
         let mut path = Path::new(figure_path).to_path_buf();
         path.set_extension("py");
-        let output = call_python3(&commands, &path)?;
+        let output = call_python3(&self.get_header(), &commands, &path)?;
 
         // handle error => write log file
-        if output != "" {
-            let mut log_path = Path::new(figure_path).to_path_buf();
-            log_path.set_extension("log");
-            let mut log_file = File::create(log_path).map_err(|_| "cannot create log file")?;
-            log_file
-                .write_all(output.as_bytes())
-                .map_err(|_| "cannot write to log file")?;
-            if self.show_errors {
-                println!("{}", output);
-            }
-            return Err("python3 failed; please see the log file");
-        }
-        Ok(())
+        write_log_file(&output, Path::new(figure_path), self.show_errors)
     }
 }
 
@@ -657,7 +978,8 @@ impl Plot {
 
 #[cfg(test)]
 mod tests {
-    use super::{Plot, StrError};
+    use super::{Palette, Plot, SaveOptions, StrError, TargetVersions, Theme, Typography, PYTHON_HEADER};
+    use crate::{Curve, LineStyle, RawPython};
     use std::fs::File;
     use std::io::{BufRead, BufReader};
     use std::path::Path;
@@ -667,9 +989,85 @@ mod tests {
     #[test]
     fn new_plot_works() {
         let plot = Plot::new();
+        assert_eq!(plot.theme.len(), 0);
+        assert!(plot.typography.is_none());
         assert_eq!(plot.buffer.len(), 0);
     }
 
+    #[test]
+    fn set_theme_works() {
+        let mut theme = Theme::new();
+        theme.set_font_size(12.0);
+        let mut plot = Plot::new();
+        plot.set_title("first").set_theme(&theme);
+        assert_eq!(plot.theme, "plt.rcParams.update({'font.size':12,})\n");
+        assert_eq!(plot.buffer, "plt.title(r'first')\n");
+        plot.set_theme(&Theme::new());
+        assert_eq!(plot.theme, "");
+    }
+
+    #[test]
+    fn set_palette_works() {
+        let mut plot = Plot::new();
+        plot.set_palette(&Palette::from_colors(&["red", "blue"]).unwrap());
+        assert_eq!(
+            plot.buffer,
+            "plt.gca().set_prop_cycle(plt.cycler(color=['red','blue',]))\n"
+        );
+    }
+
+    #[test]
+    fn set_save_options_works() {
+        let mut options = SaveOptions::new();
+        options.set_dpi(300.0).set_tight_bbox(false);
+        let mut plot = Plot::new();
+        plot.set_save_options(&options);
+        assert_eq!(plot.save_options.savefig("fn", "png"), "plt.savefig(fn,dpi=300)\n");
+        assert_eq!(
+            plot.save_formats("/tmp/plotpy/unit_tests/save_formats", &[]).err(),
+            Some("at least one format must be given")
+        );
+    }
+
+    #[test]
+    fn set_typography_works() {
+        let mut typography = Typography::new();
+        typography.set_font_size(12.0);
+        let mut plot = Plot::new();
+        plot.set_title("first").set_typography(&typography);
+        assert_eq!(
+            plot.typography.as_ref().unwrap().to_python(),
+            "plt.rcParams.update({'font.size':12,})\n"
+        );
+        assert_eq!(plot.buffer, "plt.title(r'first')\n");
+    }
+
+    #[test]
+    fn save_captures_invalid_color() {
+        let mut curve = Curve::new();
+        curve.set_line_color("#12345");
+        curve.draw(&[0.0, 1.0], &[0.0, 1.0]);
+        let mut plot = Plot::new();
+        plot.add(&curve);
+        let path = Path::new(OUT_DIR).join("save_captures_invalid_color.svg");
+        let error = Some("invalid color: the hex code must have 3, 4, 6, or 8 digits");
+        assert_eq!(plot.save(&path).err(), error);
+        assert_eq!(plot.write_script(&path).err(), error);
+        assert_eq!(plot.render_to_bytes("png", 0.0).err(), error);
+        let mut options = SaveOptions::new();
+        options.set_face_color("whyte");
+        let mut plot = Plot::new();
+        plot.set_save_options(&options);
+        assert_eq!(plot.save(&path).err(), Some("invalid color: unknown color name"));
+        let mut theme = Theme::new();
+        theme
+            .set_grid_style("gray", LineStyle::Dashed, 0.5)
+            .set_grid_style("grey50", LineStyle::Dotted, 0.5);
+        let mut plot = Plot::new();
+        plot.set_theme(&theme);
+        assert_eq!(plot.save(&path).err(), Some("invalid color: unknown color name"));
+    }
+
     #[test]
     fn save_works() -> Result<(), StrError> {
         let plot = Plot::new();
@@ -696,6 +1094,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn add_raw_python_works() {
+        let mut raw = RawPython::new();
+        raw.add_import("import scipy.stats as sst")
+            .add_line("ax.invert_xaxis()");
+        let mut plot = Plot::new();
+        plot.add(&raw).add(&raw).add_python_line("ax.grid()");
+        assert_eq!(plot.imports, &["import scipy.stats as sst"]);
+        assert_eq!(
+            plot.buffer,
+            "ax=plt.gca()\n\
+             ax.invert_xaxis()\n\
+             ax=plt.gca()\n\
+             ax.invert_xaxis()\n\
+             ax=plt.gca()\n\
+             ax.grid()\n"
+        );
+        let script = plot.get_script("/tmp/plotpy/unit_tests/add_raw_python_works.svg");
+        assert!(script.starts_with(&format!("{}import scipy.stats as sst\nax=plt.gca()\n", PYTHON_HEADER)));
+    }
+
+    #[test]
+    fn get_script_works() {
+        let mut theme = Theme::new();
+        theme.set_font_size(12.0);
+        let mut plot = Plot::new();
+        plot.set_theme(&theme).set_title("script");
+        let script = plot.get_script("/tmp/plotpy/unit_tests/get_script_works.svg");
+        let correct = format!(
+            "{}plt.rcParams.update({{'font.size':12,}})\n\
+             plt.title(r'script')\n\
+             \nfn='/tmp/plotpy/unit_tests/get_script_works.svg'\n\
+             plt.savefig(fn,bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\n",
+            PYTHON_HEADER
+        );
+        assert_eq!(script, correct);
+    }
+
+    #[test]
+    fn set_target_versions_works() {
+        let versions = TargetVersions::new("3.4.3", "1.20.0").unwrap();
+        let mut plot = Plot::new();
+        plot.set_target_versions(&versions);
+        assert_eq!(plot.target_versions, Some(versions));
+        let script = plot.get_script("/tmp/plotpy/unit_tests/set_target_versions_works.svg");
+        assert!(script.starts_with(&versions.python_header()));
+        assert!(!script.contains("mpl.colormaps"));
+    }
+
+    #[test]
+    fn write_script_works() -> Result<(), StrError> {
+        let mut plot = Plot::new();
+        plot.set_title("script");
+        let path = Path::new(OUT_DIR).join("write_script_works.svg");
+        plot.write_script(&path)?;
+        let data = std::fs::read_to_string(path.with_extension("py")).map_err(|_| "cannot read file")?;
+        assert_eq!(data, plot.get_script(&path));
+        assert!(!path.exists());
+        Ok(())
+    }
+
     #[test]
     fn show_errors_works() -> Result<(), StrError> {
         const WRONG: usize = 0;
@@ -0,0 +1,545 @@
+use super::{AsMatrix, PolyCode, StrError};
+
+/// Holds a polygon region made of closed rings (e.g., outer boundaries and holes)
+///
+/// The rings are oriented such that the region lies to the left of each edge; i.e., the outer
+/// boundaries are counterclockwise and the holes are clockwise. The rings do not repeat the first point.
+///
+/// The boolean operations (union, intersection, and difference) are computed in Rust and may result
+/// in several disjoint pieces and holes. Use [Polygon::get_polycurve] to draw the result with
+/// [crate::Canvas::draw_polycurve].
+///
+/// **Note:** The input polygons must be simple (i.e., without self-intersections).
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Canvas, Plot, Polygon, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // a plate with a circular hole
+///     let plate = Polygon::new(&[[0.0, 0.0], [4.0, 0.0], [4.0, 2.0], [0.0, 2.0]])?;
+///     let circle: Vec<Vec<f64>> = (0..36)
+///         .map(|i| {
+///             let a = (i as f64) * std::f64::consts::PI / 18.0;
+///             vec![2.0 + 0.6 * f64::cos(a), 1.0 + 0.6 * f64::sin(a)]
+///         })
+///         .collect();
+///     let hole = Polygon::new(&circle)?;
+///     let notch = Polygon::new(&[[3.5, 1.5], [4.5, 1.5], [4.5, 2.5], [3.5, 2.5]])?;
+///     let region = plate.difference(&hole).difference(&notch);
+///
+///     // draw region
+///     let mut canvas = Canvas::new();
+///     canvas.set_face_color("#eeea83").set_edge_color("#cd0000");
+///     let (points, codes) = region.get_polycurve();
+///     canvas.draw_polycurve(&points, &codes, true)?;
+///
+///     // add canvas to plot
+///     let mut plot = Plot::new();
+///     plot.add(&canvas)
+///         .set_range(-0.5, 4.5, -0.5, 2.5)
+///         .set_equal_axes(true)
+///         .set_show_errors(true);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_polygon.svg")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Polygon {
+    rings: Vec<Vec<[f64; 2]>>, // closed rings (the first point is not repeated)
+}
+
+impl Polygon {
+    /// Creates a new polygon with a single (counterclockwise) ring
+    ///
+    /// # Input
+    ///
+    /// * `points` -- (npoint ≥ 3, 2) coordinates of the vertices in any orientation;
+    ///   the last point may repeat the first one; all coordinates must be finite
+    pub fn new<'a, T, U>(points: &'a T) -> Result<Self, StrError>
+    where
+        T: AsMatrix<'a, U>,
+        U: 'a + Into<f64>,
+    {
+        let ring = ring_from_matrix(points)?;
+        Ok(Polygon {
+            rings: vec![oriented_ring(ring, true)],
+        })
+    }
+
+    /// Returns the rings (counterclockwise outer boundaries and clockwise holes)
+    pub fn get_rings(&self) -> &Vec<Vec<[f64; 2]>> {
+        &self.rings
+    }
+
+    /// Returns the area of the region (the area of holes is subtracted)
+    pub fn area(&self) -> f64 {
+        self.rings.iter().map(|ring| signed_area(ring)).sum()
+    }
+
+    /// Returns true if the point is inside the region
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let mut inside = false;
+        for ring in &self.rings {
+            let n = ring.len();
+            let mut j = n - 1;
+            for i in 0..n {
+                let (a, b) = (ring[i], ring[j]);
+                if (a[1] > y) != (b[1] > y) && x < (b[0] - a[0]) * (y - a[1]) / (b[1] - a[1]) + a[0] {
+                    inside = !inside;
+                }
+                j = i;
+            }
+        }
+        inside
+    }
+
+    /// Returns the union of this region with another one
+    pub fn union(&self, other: &Polygon) -> Polygon {
+        self.boolean(other, |a, b| a || b)
+    }
+
+    /// Returns the intersection of this region with another one
+    pub fn intersection(&self, other: &Polygon) -> Polygon {
+        self.boolean(other, |a, b| a && b)
+    }
+
+    /// Returns the difference between this region and another one (this minus other)
+    pub fn difference(&self, other: &Polygon) -> Polygon {
+        self.boolean(other, |a, b| a && !b)
+    }
+
+    /// Returns the points and codes to be used with [crate::Canvas::draw_polycurve]
+    ///
+    /// Each ring starts with [PolyCode::MoveTo] and ends with a [PolyCode::LineTo] back to its first point.
+    /// The result is empty if the region is empty; in this case, [crate::Canvas::draw_polycurve] draws nothing.
+    pub fn get_polycurve(&self) -> (Vec<Vec<f64>>, Vec<PolyCode>) {
+        let mut points = Vec::new();
+        let mut codes = Vec::new();
+        for ring in &self.rings {
+            points.push(vec![ring[0][0], ring[0][1]]);
+            codes.push(PolyCode::MoveTo);
+            for p in ring.iter().skip(1).chain(ring.iter().take(1)) {
+                points.push(vec![p[0], p[1]]);
+                codes.push(PolyCode::LineTo);
+            }
+        }
+        (points, codes)
+    }
+
+    /// Computes a boolean operation given the rule to combine the membership in each region
+    ///
+    /// All edges are split at their intersections; then each piece is kept (and oriented) if the region
+    /// resulting from the operation lies on one side of the piece only. Finally, the pieces are linked into rings.
+    fn boolean(&self, other: &Polygon, rule: fn(bool, bool) -> bool) -> Polygon {
+        // edges of both regions
+        let mut edges: Vec<([f64; 2], [f64; 2])> = Vec::new();
+        for ring in self.rings.iter().chain(other.rings.iter()) {
+            let n = ring.len();
+            for i in 0..n {
+                edges.push((ring[i], ring[(i + 1) % n]));
+            }
+        }
+        let na: usize = self.rings.iter().map(|ring| ring.len()).sum();
+        let scale = bounding_box_diagonal(&edges);
+        if scale == 0.0 {
+            return Polygon { rings: Vec::new() };
+        }
+        let tol = 1e-10 * scale;
+
+        // split points (parameter along the edge and coordinates)
+        let mut splits: Vec<Vec<(f64, [f64; 2])>> = edges.iter().map(|e| vec![(0.0, e.0), (1.0, e.1)]).collect();
+        for i in 0..na {
+            for j in na..edges.len() {
+                for (t, u, point) in segment_intersections(&edges[i], &edges[j], tol) {
+                    splits[i].push((t, point));
+                    splits[j].push((u, point));
+                }
+            }
+        }
+
+        // pieces of edges (coincident pieces are considered once)
+        let mut pieces: Vec<([f64; 2], [f64; 2])> = Vec::new();
+        for split in &mut splits {
+            split.sort_by(|a, b| a.0.total_cmp(&b.0));
+            for k in 1..split.len() {
+                let (a, b) = (split[k - 1].1, split[k].1);
+                if distance(&a, &b) <= tol {
+                    continue;
+                }
+                let repeated = pieces.iter().any(|(c, d)| {
+                    (distance(&a, c) <= tol && distance(&b, d) <= tol)
+                        || (distance(&a, d) <= tol && distance(&b, c) <= tol)
+                });
+                if !repeated {
+                    pieces.push((a, b));
+                }
+            }
+        }
+
+        // keep the pieces on the boundary of the resulting region (with the region on the left)
+        let eps = 1e-7 * scale;
+        let mut kept: Vec<([f64; 2], [f64; 2])> = Vec::new();
+        for (a, b) in pieces {
+            let length = distance(&a, &b);
+            let (nx, ny) = (-(b[1] - a[1]) / length, (b[0] - a[0]) / length);
+            let (xm, ym) = ((a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0);
+            let (xl, yl) = (xm + eps * nx, ym + eps * ny);
+            let (xr, yr) = (xm - eps * nx, ym - eps * ny);
+            let left = rule(self.contains(xl, yl), other.contains(xl, yl));
+            let right = rule(self.contains(xr, yr), other.contains(xr, yr));
+            if left && !right {
+                kept.push((a, b));
+            } else if right && !left {
+                kept.push((b, a));
+            }
+        }
+        Polygon {
+            rings: link_pieces(kept, tol),
+        }
+    }
+}
+
+/// Extracts a ring from a matrix of points (the repeated last point, if any, is removed)
+pub(crate) fn ring_from_matrix<'a, T, U>(points: &'a T) -> Result<Vec<[f64; 2]>, StrError>
+where
+    T: AsMatrix<'a, U>,
+    U: 'a + Into<f64>,
+{
+    let (npoint, ndim) = points.size();
+    if ndim != 2 {
+        return Err("ndim must be equal to 2");
+    }
+    let mut ring: Vec<[f64; 2]> = (0..npoint)
+        .map(|i| [points.at(i, 0).into(), points.at(i, 1).into()])
+        .collect();
+    if ring.iter().any(|p| !p[0].is_finite() || !p[1].is_finite()) {
+        return Err("the coordinates of the polygon must be finite");
+    }
+    if ring.len() > 1 && ring[0] == ring[ring.len() - 1] {
+        ring.pop();
+    }
+    if ring.len() < 3 {
+        return Err("polygon must have at least 3 points");
+    }
+    Ok(ring)
+}
+
+/// Returns the signed area of a ring (positive if counterclockwise)
+pub(crate) fn signed_area(ring: &[[f64; 2]]) -> f64 {
+    let n = ring.len();
+    let mut area = 0.0;
+    for i in 0..n {
+        let (a, b) = (ring[i], ring[(i + 1) % n]);
+        area += a[0] * b[1] - b[0] * a[1];
+    }
+    area / 2.0
+}
+
+/// Returns the ring with the requested orientation (counterclockwise or clockwise)
+pub(crate) fn oriented_ring(mut ring: Vec<[f64; 2]>, counterclockwise: bool) -> Vec<[f64; 2]> {
+    if (signed_area(&ring) > 0.0) != counterclockwise {
+        ring.reverse();
+    }
+    ring
+}
+
+/// Returns the distance between two points
+fn distance(a: &[f64; 2], b: &[f64; 2]) -> f64 {
+    f64::sqrt((b[0] - a[0]) * (b[0] - a[0]) + (b[1] - a[1]) * (b[1] - a[1]))
+}
+
+/// Returns the cross product between two 2D vectors
+fn cross(u: &[f64; 2], v: &[f64; 2]) -> f64 {
+    u[0] * v[1] - u[1] * v[0]
+}
+
+/// Returns the diagonal of the bounding box of all edges
+fn bounding_box_diagonal(edges: &[([f64; 2], [f64; 2])]) -> f64 {
+    if edges.is_empty() {
+        return 0.0;
+    }
+    let (mut min, mut max) = (edges[0].0, edges[0].0);
+    for (a, b) in edges {
+        for p in [a, b] {
+            min = [f64::min(min[0], p[0]), f64::min(min[1], p[1])];
+            max = [f64::max(max[0], p[0]), f64::max(max[1], p[1])];
+        }
+    }
+    distance(&min, &max)
+}
+
+/// Returns the intersections (t, u, point) between two segments, where t and u are the parameters along each segment
+///
+/// Collinear overlapping segments yield the endpoints of each segment lying inside the other one.
+fn segment_intersections(e: &([f64; 2], [f64; 2]), f: &([f64; 2], [f64; 2]), tol: f64) -> Vec<(f64, f64, [f64; 2])> {
+    let (p, p2) = *e;
+    let (q, q2) = *f;
+    let r = [p2[0] - p[0], p2[1] - p[1]];
+    let s = [q2[0] - q[0], q2[1] - q[1]];
+    let qp = [q[0] - p[0], q[1] - p[1]];
+    let (len_r, len_s) = (distance(&p, &p2), distance(&q, &q2));
+    let mut res = Vec::new();
+    if len_r <= tol || len_s <= tol {
+        return res;
+    }
+    let denom = cross(&r, &s);
+    if denom.abs() > 1e-12 * len_r * len_s {
+        let t = cross(&qp, &s) / denom;
+        let u = cross(&qp, &r) / denom;
+        let (tol_t, tol_u) = (tol / len_r, tol / len_s);
+        if t < -tol_t || t > 1.0 + tol_t || u < -tol_u || u > 1.0 + tol_u {
+            return res;
+        }
+        // snap to existing vertices to avoid tiny pieces
+        let point = if t <= tol_t {
+            p
+        } else if t >= 1.0 - tol_t {
+            p2
+        } else if u <= tol_u {
+            q
+        } else if u >= 1.0 - tol_u {
+            q2
+        } else {
+            [p[0] + t * r[0], p[1] + t * r[1]]
+        };
+        res.push((t.clamp(0.0, 1.0), u.clamp(0.0, 1.0), point));
+    } else if cross(&qp, &r).abs() <= tol * len_r {
+        let (rr, ss) = (len_r * len_r, len_s * len_s);
+        for (point, u) in [(q, 0.0), (q2, 1.0)] {
+            let t = ((point[0] - p[0]) * r[0] + (point[1] - p[1]) * r[1]) / rr;
+            if t > 0.0 && t < 1.0 {
+                res.push((t, u, point));
+            }
+        }
+        for (point, t) in [(p, 0.0), (p2, 1.0)] {
+            let u = ((point[0] - q[0]) * s[0] + (point[1] - q[1]) * s[1]) / ss;
+            if u > 0.0 && u < 1.0 {
+                res.push((t, u, point));
+            }
+        }
+    }
+    res
+}
+
+/// Links oriented pieces into closed rings (collinear vertices are removed)
+///
+/// If several pieces start at the same point, the one turning the most to the right is selected,
+/// thus regions touching at a single vertex are kept as separate rings.
+fn link_pieces(pieces: Vec<([f64; 2], [f64; 2])>, tol: f64) -> Vec<Vec<[f64; 2]>> {
+    let mut used = vec![false; pieces.len()];
+    let mut rings = Vec::new();
+    for first in 0..pieces.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let start = pieces[first].0;
+        let mut ring = vec![start];
+        let (mut prev, mut current) = pieces[first];
+        let mut closed = false;
+        loop {
+            if distance(&current, &start) <= tol {
+                closed = true;
+                break;
+            }
+            let incoming = [current[0] - prev[0], current[1] - prev[1]];
+            let mut selected: Option<(usize, f64)> = None;
+            for (k, (a, b)) in pieces.iter().enumerate() {
+                if used[k] || distance(a, &current) > tol {
+                    continue;
+                }
+                let outgoing = [b[0] - a[0], b[1] - a[1]];
+                let angle = f64::atan2(
+                    cross(&incoming, &outgoing),
+                    incoming[0] * outgoing[0] + incoming[1] * outgoing[1],
+                );
+                let better = match selected {
+                    Some((_, best)) => angle < best,
+                    None => true,
+                };
+                if better {
+                    selected = Some((k, angle));
+                }
+            }
+            match selected {
+                Some((k, _)) => {
+                    used[k] = true;
+                    ring.push(current);
+                    prev = current;
+                    current = pieces[k].1;
+                }
+                None => break,
+            }
+        }
+        if closed {
+            let ring = remove_collinear(ring, tol);
+            if ring.len() >= 3 {
+                rings.push(ring);
+            }
+        }
+    }
+    rings
+}
+
+/// Removes the vertices of a ring lying on the straight line connecting their neighbors
+fn remove_collinear(ring: Vec<[f64; 2]>, tol: f64) -> Vec<[f64; 2]> {
+    let n = ring.len();
+    let mut res = Vec::with_capacity(n);
+    for i in 0..n {
+        let a = ring[(i + n - 1) % n];
+        let b = ring[i];
+        let c = ring[(i + 1) % n];
+        let u = [b[0] - a[0], b[1] - a[1]];
+        let v = [c[0] - b[0], c[1] - b[1]];
+        let length = distance(&a, &c);
+        let straight = cross(&u, &v).abs() <= tol * length && u[0] * v[0] + u[1] * v[1] > 0.0;
+        if !straight {
+            res.push(b);
+        }
+    }
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{signed_area, Polygon};
+    use crate::{Canvas, GraphMaker, PolyCode};
+
+    fn square(xmin: f64, ymin: f64, size: f64) -> Polygon {
+        let (xmax, ymax) = (xmin + size, ymin + size);
+        Polygon::new(&[[xmin, ymin], [xmax, ymin], [xmax, ymax], [xmin, ymax]]).unwrap()
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            Polygon::new(&[[0.0, 0.0], [1.0, 0.0]]).err(),
+            Some("polygon must have at least 3 points")
+        );
+        assert_eq!(
+            Polygon::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 0.0]]).err(),
+            Some("polygon must have at least 3 points")
+        );
+        assert_eq!(
+            Polygon::new(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]).err(),
+            Some("ndim must be equal to 2")
+        );
+        assert_eq!(
+            Polygon::new(&[[0.0, 0.0], [f64::NAN, 0.0], [0.0, 1.0]]).err(),
+            Some("the coordinates of the polygon must be finite")
+        );
+        assert_eq!(
+            Polygon::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, f64::INFINITY]]).err(),
+            Some("the coordinates of the polygon must be finite")
+        );
+    }
+
+    #[test]
+    fn new_works() {
+        // clockwise input becomes counterclockwise
+        let p = Polygon::new(&[[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]]).unwrap();
+        assert_eq!(p.get_rings().len(), 1);
+        assert_eq!(p.get_rings()[0].len(), 4);
+        assert_eq!(signed_area(&p.get_rings()[0]), 1.0);
+        assert_eq!(p.area(), 1.0);
+        assert!(p.contains(0.5, 0.5));
+        assert!(!p.contains(1.5, 0.5));
+    }
+
+    #[test]
+    fn union_works() {
+        // overlapping squares
+        let u = square(0.0, 0.0, 2.0).union(&square(1.0, 1.0, 2.0));
+        assert_eq!(u.get_rings().len(), 1);
+        assert_eq!(u.get_rings()[0].len(), 8);
+        assert!((u.area() - 7.0).abs() < 1e-14);
+
+        // disjoint squares
+        let u = square(0.0, 0.0, 1.0).union(&square(2.0, 0.0, 1.0));
+        assert_eq!(u.get_rings().len(), 2);
+        assert_eq!(u.area(), 2.0);
+
+        // squares sharing an edge
+        let u = square(0.0, 0.0, 1.0).union(&square(1.0, 0.0, 1.0));
+        assert_eq!(u.get_rings().len(), 1);
+        assert_eq!(u.get_rings()[0].len(), 4);
+        assert_eq!(u.area(), 2.0);
+    }
+
+    #[test]
+    fn intersection_works() {
+        let i = square(0.0, 0.0, 2.0).intersection(&square(1.0, 1.0, 2.0));
+        assert_eq!(i.get_rings().len(), 1);
+        assert_eq!(i.get_rings()[0].len(), 4);
+        assert!((i.area() - 1.0).abs() < 1e-14);
+        assert!(i.contains(1.5, 1.5));
+
+        // disjoint squares
+        let i = square(0.0, 0.0, 1.0).intersection(&square(2.0, 0.0, 1.0));
+        assert_eq!(i.get_rings().len(), 0);
+        assert_eq!(i.area(), 0.0);
+    }
+
+    #[test]
+    fn difference_works() {
+        // corner removed
+        let d = square(0.0, 0.0, 2.0).difference(&square(1.0, 1.0, 2.0));
+        assert_eq!(d.get_rings().len(), 1);
+        assert_eq!(d.get_rings()[0].len(), 6);
+        assert!((d.area() - 3.0).abs() < 1e-14);
+
+        // hole
+        let d = square(0.0, 0.0, 3.0).difference(&square(1.0, 1.0, 1.0));
+        assert_eq!(d.get_rings().len(), 2);
+        assert_eq!(d.area(), 8.0);
+        assert!(!d.contains(1.5, 1.5));
+        assert!(d.contains(0.5, 0.5));
+        let areas: Vec<_> = d.get_rings().iter().map(|ring| signed_area(ring)).collect();
+        assert!(areas.contains(&9.0));
+        assert!(areas.contains(&-1.0));
+
+        // split into two pieces
+        let bar = Polygon::new(&[[1.0, -1.0], [2.0, -1.0], [2.0, 4.0], [1.0, 4.0]]).unwrap();
+        let d = square(0.0, 0.0, 3.0).difference(&bar);
+        assert_eq!(d.get_rings().len(), 2);
+        assert!((d.area() - 6.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn get_polycurve_works() {
+        let (points, codes) = square(0.0, 0.0, 1.0).get_polycurve();
+        assert_eq!(
+            points,
+            vec![
+                vec![0.0, 0.0],
+                vec![1.0, 0.0],
+                vec![1.0, 1.0],
+                vec![0.0, 1.0],
+                vec![0.0, 0.0]
+            ]
+        );
+        assert_eq!(
+            codes,
+            vec![
+                PolyCode::MoveTo,
+                PolyCode::LineTo,
+                PolyCode::LineTo,
+                PolyCode::LineTo,
+                PolyCode::LineTo
+            ]
+        );
+        let empty = square(0.0, 0.0, 1.0).intersection(&square(5.0, 5.0, 1.0));
+        let (points, codes) = empty.get_polycurve();
+        assert_eq!(points.len(), 0);
+        assert_eq!(codes.len(), 0);
+        let mut canvas = Canvas::new();
+        assert_eq!(canvas.draw_polycurve(&points, &codes, true), Ok(()));
+        assert_eq!(canvas.get_buffer(), "");
+    }
+}
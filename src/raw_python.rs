@@ -0,0 +1,222 @@
+use super::GraphMaker;
+
+/// Holds arbitrary Python commands to be injected into the script
+///
+/// This is an escape hatch for Matplotlib functionality that is not implemented by this library.
+/// The commands are added to the script at the point where the RawPython is passed to [crate::Plot::add].
+///
+/// The commands have access to:
+///
+/// * `ax` -- the current axes (i.e., `plt.gca()` at the point where the commands are added)
+/// * `add_to_ea(obj)` -- a function to register an artist that must be considered when calculating the bounding box
+/// * the modules imported by [crate::PYTHON_HEADER], e.g., `np`, `plt`, and `pat`
+///
+/// Extra imports (e.g., `import scipy.stats as sst`) are merged into the beginning of the script
+/// (after [crate::PYTHON_HEADER]). Repeated imports are written only once.
+///
+/// See also [crate::Plot::add_python_line].
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Plot, RawPython, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // draw curve
+///     let mut curve = Curve::new();
+///     curve.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+///
+///     // raw commands
+///     let mut raw = RawPython::new();
+///     raw.add_import("import matplotlib.patches as mpatches")
+///         .add_line("p = mpatches.Circle((1.5, 4.5), 0.5, color='#cd0000', alpha=0.5)")
+///         .add_line("ax.add_patch(p)")
+///         .add_line("add_to_ea(ax.annotate('circle', (1.5, 4.5), (0.5, 7.0), arrowprops={'arrowstyle':'->'}))");
+///
+///     // add curve and raw commands to plot
+///     let mut plot = Plot::new();
+///     plot.add(&curve).add(&raw).set_equal_axes(true);
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_raw_python.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_raw_python.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_raw_python.svg)
+pub struct RawPython {
+    imports: Vec<String>, // extra import statements
+    buffer: String,       // buffer
+}
+
+impl RawPython {
+    /// Creates a new RawPython object
+    pub fn new() -> Self {
+        RawPython {
+            imports: Vec::new(),
+            buffer: String::new(),
+        }
+    }
+
+    /// Adds an import statement (e.g., "import scipy.stats as sst") to the beginning of the script
+    pub fn add_import(&mut self, statement: &str) -> &mut Self {
+        let statement = statement.trim().to_string();
+        if !self.imports.contains(&statement) {
+            self.imports.push(statement);
+        }
+        self
+    }
+
+    /// Adds a line of Python code
+    pub fn add_line(&mut self, line: &str) -> &mut Self {
+        self.begin();
+        self.buffer.push_str(line);
+        self.buffer.push('\n');
+        self
+    }
+
+    /// Adds a block of Python code (multiple lines)
+    ///
+    /// **Note:** The common indentation of the lines is removed as in Python's `textwrap.dedent`; i.e.,
+    /// only leading spaces and tabs are considered and the indentation is removed only if all lines
+    /// (except the blank ones) start with exactly the same sequence of spaces and tabs.
+    pub fn add_code(&mut self, code: &str) -> &mut Self {
+        let mut lines: Vec<&str> = code.lines().skip_while(|l| is_blank(l)).collect();
+        while lines.last().is_some_and(|l| is_blank(l)) {
+            lines.pop();
+        }
+        let margin = lines
+            .iter()
+            .filter(|l| !is_blank(l))
+            .map(|l| indentation(l))
+            .reduce(|margin, indent| {
+                let n = margin.bytes().zip(indent.bytes()).take_while(|(a, b)| a == b).count();
+                &margin[..n]
+            })
+            .unwrap_or("");
+        self.begin();
+        for line in lines {
+            if !is_blank(line) {
+                self.buffer.push_str(&line[margin.len()..]);
+            }
+            self.buffer.push('\n');
+        }
+        self
+    }
+
+    /// Defines the `ax` variable at the beginning of the buffer
+    fn begin(&mut self) {
+        if self.buffer.is_empty() {
+            self.buffer.push_str("ax=plt.gca()\n");
+        }
+    }
+}
+
+impl Default for RawPython {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the leading spaces and tabs of a line
+fn indentation(line: &str) -> &str {
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+/// Returns true if the line contains only spaces and tabs
+fn is_blank(line: &str) -> bool {
+    line.trim_start_matches([' ', '\t']).is_empty()
+}
+
+impl GraphMaker for RawPython {
+    fn get_buffer(&self) -> &String {
+        &self.buffer
+    }
+    fn clear_buffer(&mut self) {
+        self.buffer.clear();
+    }
+    fn get_imports(&self) -> &[String] {
+        &self.imports
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::RawPython;
+    use crate::GraphMaker;
+
+    #[test]
+    fn new_works() {
+        let raw = RawPython::new();
+        assert_eq!(raw.imports.len(), 0);
+        assert_eq!(raw.buffer.len(), 0);
+    }
+
+    #[test]
+    fn add_import_works() {
+        let mut raw = RawPython::new();
+        raw.add_import("import scipy.stats as sst")
+            .add_import(" import scipy.stats as sst ")
+            .add_import("from matplotlib import cm");
+        assert_eq!(
+            raw.get_imports(),
+            &["import scipy.stats as sst", "from matplotlib import cm"]
+        );
+    }
+
+    #[test]
+    fn add_line_works() {
+        let mut raw = RawPython::new();
+        raw.add_line("ax.set_facecolor('gray')").add_line("ax.invert_xaxis()");
+        assert_eq!(
+            raw.buffer,
+            "ax=plt.gca()\n\
+             ax.set_facecolor('gray')\n\
+             ax.invert_xaxis()\n"
+        );
+        raw.clear_buffer();
+        assert_eq!(raw.buffer, "");
+    }
+
+    #[test]
+    fn add_code_works() {
+        let mut raw = RawPython::new();
+        raw.add_code(
+            "
+            for i in range(3):
+                ax.axhline(i)
+
+            ax.grid()
+            ",
+        );
+        assert_eq!(
+            raw.buffer,
+            "ax=plt.gca()\n\
+             for i in range(3):\n    \
+             ax.axhline(i)\n\
+             \n\
+             ax.grid()\n"
+        );
+    }
+
+    #[test]
+    fn add_code_handles_unicode_whitespace() {
+        // U+3000 (ideographic space) is not indentation; hence, nothing is removed
+        let mut raw = RawPython::new();
+        raw.add_code("\u{3000}x = 1\n  y = 2\n");
+        assert_eq!(raw.buffer, "ax=plt.gca()\n\u{3000}x = 1\n  y = 2\n");
+    }
+
+    #[test]
+    fn add_code_handles_mixed_tabs_and_spaces() {
+        // only the exact common prefix is removed
+        let mut raw = RawPython::new();
+        raw.add_code("\tif True:\n\t    x = 1\n\t\ty = 2\n");
+        assert_eq!(raw.buffer, "ax=plt.gca()\nif True:\n    x = 1\n\ty = 2\n");
+        raw.clear_buffer();
+        raw.add_code("    a = 1\n\tb = 2\n");
+        assert_eq!(raw.buffer, "ax=plt.gca()\n    a = 1\n\tb = 2\n");
+    }
+}
@@ -0,0 +1,398 @@
+use super::{color_to_spec, Color, StrError};
+use std::fmt::Write;
+
+/// Holds options to save figures (resolution, background, bounding box, and metadata)
+///
+/// The options are passed to [crate::Plot::set_save_options] and used by [crate::Plot::save],
+/// [crate::Plot::save_formats], and [crate::Plot::render_to_bytes]. See also [crate::Animation::set_save_options].
+///
+/// The metadata (title, author, and date) are written to PDF, SVG, and PNG files. Other formats ignore them.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Plot, SaveOptions, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // draw curve
+///     let mut curve = Curve::new();
+///     curve.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+///
+///     // save options
+///     let mut options = SaveOptions::new();
+///     options
+///         .set_dpi(200.0)
+///         .set_transparent(true)
+///         .set_pad_inches(0.05)
+///         .set_title("Parabola")
+///         .set_author("Plotpy")
+///         .set_date("2024-01-01T00:00:00");
+///
+///     // add curve to plot
+///     let mut plot = Plot::new();
+///     plot.set_save_options(&options).add(&curve);
+///
+///     // save figure in three formats
+///     plot.save_formats("/tmp/plotpy/doc_tests/doc_save_options", &["png", "pdf", "svg"])?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SaveOptions {
+    dpi: f64,                // Resolution in dots per inch (0 means Matplotlib's default)
+    transparent: bool,       // Transparent background
+    face_color: String,      // Background color of the figure
+    pad_inches: f64,         // Padding around the figure when using the tight bounding box (negative means default)
+    tight_bbox: bool,        // Use the tight bounding box
+    bbox: Vec<f64>,          // Explicit bounding box in inches [xmin, ymin, xmax, ymax]
+    title: String,           // Metadata: title
+    author: String,          // Metadata: author
+    date: String,            // Metadata: creation date in ISO format
+    svg_fonts_text: bool,    // Keep the text of SVG files as text (instead of paths)
+    deterministic: bool,     // Generate byte-identical files for identical input
+    error: Option<StrError>, // First error found in the options (e.g., an invalid color)
+}
+
+impl SaveOptions {
+    /// Creates a new SaveOptions object (with a tight bounding box)
+    pub fn new() -> Self {
+        SaveOptions {
+            dpi: 0.0,
+            transparent: false,
+            face_color: String::new(),
+            pad_inches: -1.0,
+            tight_bbox: true,
+            bbox: Vec::new(),
+            title: String::new(),
+            author: String::new(),
+            date: String::new(),
+            svg_fonts_text: false,
+            deterministic: false,
+            error: None,
+        }
+    }
+
+    /// Sets the resolution in dots per inch (ignored by vector formats, except for embedded images)
+    pub fn set_dpi(&mut self, dpi: f64) -> &mut Self {
+        self.dpi = dpi;
+        self
+    }
+
+    /// Sets the option to save the figure with a transparent background
+    pub fn set_transparent(&mut self, flag: bool) -> &mut Self {
+        self.transparent = flag;
+        self
+    }
+
+    /// Sets the background color of the figure
+    pub fn set_face_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.face_color = color_to_spec(color, &mut self.error);
+        self
+    }
+
+    /// Sets the padding (in inches) around the figure when using the tight bounding box
+    pub fn set_pad_inches(&mut self, pad: f64) -> &mut Self {
+        self.pad_inches = pad;
+        self
+    }
+
+    /// Sets the option to crop the figure to the tight bounding box (default = true)
+    ///
+    /// If false, the whole figure (as given by its size) is saved.
+    pub fn set_tight_bbox(&mut self, flag: bool) -> &mut Self {
+        self.tight_bbox = flag;
+        self
+    }
+
+    /// Sets an explicit bounding box in inches (overrides the tight bounding box)
+    pub fn set_bbox(&mut self, xmin: f64, ymin: f64, xmax: f64, ymax: f64) -> &mut Self {
+        self.bbox = vec![xmin, ymin, xmax, ymax];
+        self
+    }
+
+    /// Sets the title written to the metadata
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = String::from(title);
+        self
+    }
+
+    /// Sets the author written to the metadata
+    pub fn set_author(&mut self, author: &str) -> &mut Self {
+        self.author = String::from(author);
+        self
+    }
+
+    /// Sets the creation date written to the metadata
+    ///
+    /// The date must be given in ISO format, i.e., "YYYY-MM-DD" or "YYYY-MM-DDTHH:MM:SS"
+    pub fn set_date(&mut self, date: &str) -> &mut Self {
+        self.date = String::from(date);
+        self
+    }
+
+    /// Sets the option to keep the text of SVG files as text, instead of converting the glyphs into paths
+    ///
+    /// Text is smaller and searchable, but requires the fonts to be available where the SVG file is viewed.
+    pub fn set_svg_fonts_as_text(&mut self, flag: bool) -> &mut Self {
+        self.svg_fonts_text = flag;
+        self
+    }
+
+    /// Sets the option to generate byte-identical files for identical input
+    ///
+    /// This option:
+    ///
+    /// * sets `svg.hashsalt` such that the ids of SVG elements (e.g., clip paths) are not random
+    /// * removes the creation date from the PDF and SVG metadata, unless a date is given by [SaveOptions::set_date]
+    /// * sets the `SOURCE_DATE_EPOCH` environment variable to 0 (if not already set), which is used by
+    ///   Matplotlib for the remaining dates (e.g., in PS files)
+    pub fn set_deterministic(&mut self, flag: bool) -> &mut Self {
+        self.deterministic = flag;
+        self
+    }
+
+    /// Returns the first error found in the options (e.g., an invalid color)
+    pub(crate) fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
+
+    /// Returns the resolution in dots per inch (0 means Matplotlib's default)
+    pub(crate) fn get_dpi(&self) -> f64 {
+        self.dpi
+    }
+
+    /// Returns the background options accepted by the `grab_frame` function of Matplotlib's animation writers
+    ///
+    /// The bounding box and resolution are not accepted because all frames must have the same size.
+    pub(crate) fn grab_frame_options(&self) -> String {
+        let mut opt = Vec::new();
+        if self.transparent {
+            opt.push("transparent=True".to_string());
+        }
+        if !self.face_color.is_empty() {
+            opt.push(format!("facecolor='{}'", self.face_color));
+        }
+        opt.join(",")
+    }
+
+    /// Returns the Python commands to save the figure to `target` in the given `format` (e.g., "png")
+    ///
+    /// The `target` is the first argument of savefig, e.g., "fn" or "buf,format='png'".
+    pub(crate) fn savefig(&self, target: &str, format: &str) -> String {
+        let format = format.to_lowercase();
+        let mut buffer = String::new();
+        if self.deterministic {
+            buffer.push_str("import os\nos.environ.setdefault('SOURCE_DATE_EPOCH','0')\n");
+            if format == "svg" {
+                buffer.push_str("plt.rcParams['svg.hashsalt']='plotpy'\n");
+            }
+        }
+        if format == "svg" && self.svg_fonts_text {
+            buffer.push_str("plt.rcParams['svg.fonttype']='none'\n");
+        }
+        if format == "pdf" && !self.date.is_empty() {
+            buffer.push_str("import datetime\n");
+        }
+        writeln!(
+            &mut buffer,
+            "plt.savefig({}{}{})",
+            target,
+            self.options(),
+            self.metadata(&format)
+        )
+        .unwrap();
+        buffer
+    }
+
+    /// Returns options for savefig (except the metadata)
+    fn options(&self) -> String {
+        let mut opt = String::new();
+        if self.bbox.len() == 4 {
+            write!(
+                &mut opt,
+                ",bbox_inches=tra.Bbox([[{},{}],[{},{}]])",
+                self.bbox[0], self.bbox[1], self.bbox[2], self.bbox[3]
+            )
+            .unwrap();
+        } else if self.tight_bbox {
+            opt.push_str(",bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS");
+        }
+        if self.dpi > 0.0 {
+            write!(&mut opt, ",dpi={}", self.dpi).unwrap();
+        }
+        if self.transparent {
+            opt.push_str(",transparent=True");
+        }
+        if !self.face_color.is_empty() {
+            write!(&mut opt, ",facecolor='{}'", self.face_color).unwrap();
+        }
+        if self.pad_inches >= 0.0 {
+            write!(&mut opt, ",pad_inches={}", self.pad_inches).unwrap();
+        }
+        opt
+    }
+
+    /// Returns the metadata option for savefig (the keys depend on the format)
+    fn metadata(&self, format: &str) -> String {
+        let (title, author, date) = match format {
+            "pdf" => ("Title", "Author", "CreationDate"),
+            "svg" => ("Title", "Creator", "Date"),
+            "png" => ("Title", "Author", "Creation Time"),
+            _ => return String::new(),
+        };
+        let mut entries = String::new();
+        if !self.title.is_empty() {
+            write!(&mut entries, "'{}':r'{}',", title, self.title).unwrap();
+        }
+        if !self.author.is_empty() {
+            write!(&mut entries, "'{}':r'{}',", author, self.author).unwrap();
+        }
+        if !self.date.is_empty() {
+            if format == "pdf" {
+                write!(
+                    &mut entries,
+                    "'{}':datetime.datetime.fromisoformat('{}'),",
+                    date, self.date
+                )
+                .unwrap();
+            } else {
+                write!(&mut entries, "'{}':'{}',", date, self.date).unwrap();
+            }
+        } else if self.deterministic && format != "png" {
+            write!(&mut entries, "'{}':None,", date).unwrap();
+        }
+        if entries.is_empty() {
+            String::new()
+        } else {
+            format!(",metadata={{{}}}", entries)
+        }
+    }
+}
+
+impl Default for SaveOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SaveOptions;
+
+    #[test]
+    fn new_works() {
+        let options = SaveOptions::new();
+        assert_eq!(options.dpi, 0.0);
+        assert!(!options.transparent);
+        assert_eq!(options.face_color.len(), 0);
+        assert_eq!(options.pad_inches, -1.0);
+        assert!(options.tight_bbox);
+        assert_eq!(options.bbox.len(), 0);
+        assert_eq!(options.title.len(), 0);
+        assert_eq!(options.author.len(), 0);
+        assert_eq!(options.date.len(), 0);
+        assert!(!options.svg_fonts_text);
+        assert!(!options.deterministic);
+        assert_eq!(
+            options.savefig("fn", "svg"),
+            "plt.savefig(fn,bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS)\n"
+        );
+    }
+
+    #[test]
+    fn options_work() {
+        let mut options = SaveOptions::new();
+        options
+            .set_dpi(150.0)
+            .set_transparent(true)
+            .set_face_color("white")
+            .set_pad_inches(0.0);
+        assert_eq!(
+            options.savefig("fn", "png"),
+            "plt.savefig(fn,bbox_inches='tight',bbox_extra_artists=EXTRA_ARTISTS,\
+             dpi=150,transparent=True,facecolor='white',pad_inches=0)\n"
+        );
+        options.set_tight_bbox(false);
+        assert_eq!(
+            options.savefig("buf,format='png'", "png"),
+            "plt.savefig(buf,format='png',dpi=150,transparent=True,facecolor='white',pad_inches=0)\n"
+        );
+        options.set_bbox(0.0, 0.0, 4.0, 3.0);
+        assert_eq!(
+            options.savefig("fn", "png"),
+            "plt.savefig(fn,bbox_inches=tra.Bbox([[0,0],[4,3]]),\
+             dpi=150,transparent=True,facecolor='white',pad_inches=0)\n"
+        );
+    }
+
+    #[test]
+    fn metadata_works() {
+        let mut options = SaveOptions::new();
+        options
+            .set_tight_bbox(false)
+            .set_title("Figure")
+            .set_author("Me")
+            .set_date("2024-01-02");
+        assert_eq!(
+            options.savefig("fn", "pdf"),
+            "import datetime\n\
+             plt.savefig(fn,metadata={'Title':r'Figure','Author':r'Me',\
+             'CreationDate':datetime.datetime.fromisoformat('2024-01-02'),})\n"
+        );
+        assert_eq!(
+            options.savefig("fn", "SVG"),
+            "plt.savefig(fn,metadata={'Title':r'Figure','Creator':r'Me','Date':'2024-01-02',})\n"
+        );
+        assert_eq!(
+            options.savefig("fn", "png"),
+            "plt.savefig(fn,metadata={'Title':r'Figure','Author':r'Me','Creation Time':'2024-01-02',})\n"
+        );
+        assert_eq!(options.savefig("fn", "eps"), "plt.savefig(fn)\n");
+    }
+
+    #[test]
+    fn svg_fonts_as_text_works() {
+        let mut options = SaveOptions::new();
+        options.set_tight_bbox(false).set_svg_fonts_as_text(true);
+        assert_eq!(
+            options.savefig("fn", "svg"),
+            "plt.rcParams['svg.fonttype']='none'\nplt.savefig(fn)\n"
+        );
+        assert_eq!(options.savefig("fn", "png"), "plt.savefig(fn)\n");
+    }
+
+    #[test]
+    fn deterministic_works() {
+        let mut options = SaveOptions::new();
+        options.set_tight_bbox(false).set_deterministic(true);
+        assert_eq!(
+            options.savefig("fn", "svg"),
+            "import os\n\
+             os.environ.setdefault('SOURCE_DATE_EPOCH','0')\n\
+             plt.rcParams['svg.hashsalt']='plotpy'\n\
+             plt.savefig(fn,metadata={'Date':None,})\n"
+        );
+        assert_eq!(
+            options.savefig("fn", "pdf"),
+            "import os\n\
+             os.environ.setdefault('SOURCE_DATE_EPOCH','0')\n\
+             plt.savefig(fn,metadata={'CreationDate':None,})\n"
+        );
+        assert_eq!(
+            options.savefig("fn", "png"),
+            "import os\n\
+             os.environ.setdefault('SOURCE_DATE_EPOCH','0')\n\
+             plt.savefig(fn)\n"
+        );
+        options.set_date("2024-01-02");
+        assert_eq!(
+            options.savefig("fn", "svg"),
+            "import os\n\
+             os.environ.setdefault('SOURCE_DATE_EPOCH','0')\n\
+             plt.rcParams['svg.hashsalt']='plotpy'\n\
+             plt.savefig(fn,metadata={'Date':'2024-01-02',})\n"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::GraphMaker;
+use super::{color_to_spec, Color, GraphMaker, LineStyle, StrError};
 use std::fmt::Write;
 
 /// Creates an icon to indicate the slope of lines
@@ -11,7 +11,7 @@ use std::fmt::Write;
 /// # Example
 ///
 /// ```
-/// use plotpy::{Curve, Plot, SlopeIcon, StrError};
+/// use plotpy::{Curve, Marker, Plot, SlopeIcon, StrError};
 /// use russell_lab::Vector;
 ///
 /// fn main() -> Result<(), StrError> {
@@ -27,8 +27,8 @@ use std::fmt::Write;
 ///     let x = Vector::linspace(xi, xf, 3)?;
 ///     let y1 = x.get_mapped(f);
 ///     let y2 = x.get_mapped(g);
-///     curve1.set_marker_style("o").draw(&x, &y1);
-///     curve2.set_marker_style("*").draw(&x, &y2);
+///     curve1.set_marker_style(Marker::Circle).draw(&x, &y1);
+///     curve2.set_marker_style(Marker::StarFilled).draw(&x, &y2);
 ///
 ///     // icons
 ///     let mut icon1 = SlopeIcon::new();
@@ -76,22 +76,23 @@ use std::fmt::Write;
 ///
 /// ![integ_slope_icon_logx_logy.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_slope_icon_logx_logy.svg)
 pub struct SlopeIcon {
-    above: bool,        // draw icon above line
-    edge_color: String, // Color of icon lines
-    face_color: String, // Color of icon faces
-    line_style: String, // Style of lines
-    line_width: f64,    // Width of lines
-    length: f64,        // horizontal length of icon in Axes coords [0,1]
-    offset_v: f64,      // vertical offset in points
-    no_text: bool,      // do not draw text
-    fontsize: f64,      // text font size
-    precision: usize,   // precision of slope number in label
-    text_h: String,     // use fixed text for horizontal value
-    text_v: String,     // use fixed text for vertical (slope) value
-    text_color: String, // Color of text
-    text_offset_h: f64, // horizontal offset for text in points
-    text_offset_v: f64, // vertical offset for text in points
-    buffer: String,     // buffer
+    above: bool,             // draw icon above line
+    edge_color: String,      // Color of icon lines
+    face_color: String,      // Color of icon faces
+    line_style: String,      // Style of lines
+    line_width: f64,         // Width of lines
+    length: f64,             // horizontal length of icon in Axes coords [0,1]
+    offset_v: f64,           // vertical offset in points
+    no_text: bool,           // do not draw text
+    fontsize: f64,           // text font size
+    precision: usize,        // precision of slope number in label
+    text_h: String,          // use fixed text for horizontal value
+    text_v: String,          // use fixed text for vertical (slope) value
+    text_color: String,      // Color of text
+    text_offset_h: f64,      // horizontal offset for text in points
+    text_offset_v: f64,      // vertical offset for text in points
+    error: Option<StrError>, // First error found in the options (e.g., an invalid color)
+    buffer: String,          // buffer
 }
 
 impl SlopeIcon {
@@ -113,6 +114,7 @@ impl SlopeIcon {
             text_color: "#000000".to_string(),
             text_offset_h: 3.0,
             text_offset_v: 2.0,
+            error: None,
             buffer: String::new(),
         }
     }
@@ -249,14 +251,14 @@ impl SlopeIcon {
     }
 
     /// Sets the color of icon lines
-    pub fn set_edge_color(&mut self, color: &str) -> &mut Self {
-        self.edge_color = String::from(color);
+    pub fn set_edge_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.edge_color = color_to_spec(color, &mut self.error);
         self
     }
 
     /// Sets the color of icon face
-    pub fn set_face_color(&mut self, color: &str) -> &mut Self {
-        self.face_color = String::from(color);
+    pub fn set_face_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.face_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -264,10 +266,10 @@ impl SlopeIcon {
     ///
     /// Options:
     ///
-    /// * "`-`", `:`", "`--`", "`-.`", or "`None`"
-    /// * As defined in <https://matplotlib.org/stable/gallery/lines_bars_and_markers/linestyles.html>
-    pub fn set_line_style(&mut self, style: &str) -> &mut Self {
-        self.line_style = String::from(style);
+    /// * [LineStyle::Solid], [LineStyle::Dotted], [LineStyle::Dashed], [LineStyle::DashDot], or [LineStyle::None]
+    /// * See [LineStyle] (including custom dashes and the default style)
+    pub fn set_line_style(&mut self, style: impl Into<LineStyle>) -> &mut Self {
+        self.line_style = style.into().to_option();
         self
     }
 
@@ -320,8 +322,8 @@ impl SlopeIcon {
     }
 
     /// Sets the color of text
-    pub fn set_text_color(&mut self, color: &str) -> &mut Self {
-        self.text_color = String::from(color);
+    pub fn set_text_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.text_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -407,7 +409,7 @@ impl SlopeIcon {
             write!(&mut opt, ",facecolor='{}'", self.face_color).unwrap();
         }
         if self.line_style != "" {
-            write!(&mut opt, ",linestyle='{}'", self.line_style).unwrap();
+            write!(&mut opt, ",linestyle={}", self.line_style).unwrap();
         }
         if self.line_width > 0.0 {
             write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
@@ -438,6 +440,9 @@ impl GraphMaker for SlopeIcon {
     fn clear_buffer(&mut self) {
         self.buffer.clear();
     }
+    fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -445,7 +450,7 @@ impl GraphMaker for SlopeIcon {
 #[cfg(test)]
 mod tests {
     use super::SlopeIcon;
-    use crate::GraphMaker;
+    use crate::{GraphMaker, LineStyle};
 
     #[test]
     fn new_works() {
@@ -586,7 +591,7 @@ mod tests {
         let mut icon = SlopeIcon::new();
         icon.set_edge_color("red")
             .set_face_color("gold")
-            .set_line_style("--")
+            .set_line_style(LineStyle::Dashed)
             .set_line_width(2.0);
         let options = icon.options();
         assert_eq!(
@@ -624,7 +629,7 @@ mod tests {
         icon.set_above(true)
             .set_edge_color("red")
             .set_face_color("blue")
-            .set_line_style(":")
+            .set_line_style(LineStyle::Dotted)
             .set_line_width(1.1)
             .set_length(0.2)
             .set_offset_v(3.0)
@@ -1,4 +1,6 @@
-use super::{matrix_to_array, AsMatrix, GraphMaker, StrError};
+use super::{
+    color_to_spec, matrix_to_array, AsMatrix, Color, Colormap, Contour, GraphMaker, LineStyle, Norm, StrError,
+};
 use std::fmt::Write;
 
 /// Generates a 3D a surface (or wireframe, or both)
@@ -44,21 +46,34 @@ use std::fmt::Write;
 ///
 /// ![integ_surface_wireframe.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/integ_surface_wireframe.svg)
 pub struct Surface {
-    row_stride: usize,        // Row stride
-    col_stride: usize,        // Column stride
-    with_surface: bool,       // Generates a surface
-    with_wireframe: bool,     // Generates a wireframe
-    colormap_index: usize,    // Colormap index
-    colormap_name: String,    // Colormap name
-    with_colormap: bool,      // Use colormap
-    with_colorbar: bool,      // Draw a colorbar
-    colorbar_label: String,   // Colorbar label
-    number_format_cb: String, // Number format for labels in colorbar
-    solid_color: String,      // Solid color of surface (when not using colormap)
-    line_color: String,       // Color of wireframe lines
-    line_style: String,       // Style of wireframe line
-    line_width: f64,          // Width of wireframe line
-    buffer: String,           // buffer
+    row_stride: usize,                // Row stride
+    col_stride: usize,                // Column stride
+    with_surface: bool,               // Generates a surface
+    with_wireframe: bool,             // Generates a wireframe
+    colormap_index: usize,            // Colormap index
+    colormap_name: String,            // Colormap name
+    colormap: Option<Colormap>,       // Custom colormap (overrides colormap index and name)
+    norm: Option<Norm>,               // Normalization of data values into the colormap
+    color_limits: Option<(f64, f64)>, // Limits (vmin, vmax) of data values mapped into the colormap
+    with_colormap: bool,              // Use colormap
+    with_colorbar: bool,              // Draw a colorbar
+    colorbar_label: String,           // Colorbar label
+    number_format_cb: String,         // Number format for labels in colorbar
+    solid_color: String,              // Solid color of surface (when not using colormap)
+    line_color: String,               // Color of wireframe lines
+    line_style: String,               // Style of wireframe line
+    line_width: f64,                  // Width of wireframe line
+
+    // projected contours
+    project_contours: Vec<(&'static str, f64, Vec<f64>)>, // Direction (zdir), offset, and levels of projected contours
+    project_contours_levels: Vec<f64>,                    // Default levels of projected contours taken from a Contour
+    project_contours_options: String,                     // Options of projected contours taken from a Contour
+
+    // errors
+    error: Option<StrError>, // First error found in the options (e.g., an invalid color)
+
+    // buffer
+    buffer: String, // buffer
 }
 
 impl Surface {
@@ -71,6 +86,9 @@ impl Surface {
             with_wireframe: false,
             colormap_index: 0,
             colormap_name: String::new(),
+            colormap: None,
+            norm: None,
+            color_limits: None,
             with_colormap: true,
             with_colorbar: false,
             colorbar_label: String::new(),
@@ -79,6 +97,10 @@ impl Surface {
             line_color: "black".to_string(),
             line_style: String::new(),
             line_width: 0.0,
+            project_contours: Vec::new(),
+            project_contours_levels: Vec::new(),
+            project_contours_options: String::new(),
+            error: None,
             buffer: String::new(),
         }
     }
@@ -97,6 +119,7 @@ impl Surface {
     ///
     /// * `surface` -- draws surface
     /// * `wireframe` -- draws wireframe
+    /// * `project_contours` -- draws the contours projected onto the walls (if any)
     ///
     /// # Notes
     ///
@@ -111,6 +134,13 @@ impl Surface {
         matrix_to_array(&mut self.buffer, "y", y);
         matrix_to_array(&mut self.buffer, "z", z);
         write!(&mut self.buffer, "maybe_create_ax3d()\n").unwrap();
+        if self.with_cmap_variable() {
+            let cmap = self.colormap_expr();
+            writeln!(&mut self.buffer, "cmap={}", cmap).unwrap();
+        }
+        if let Some(norm) = &self.norm {
+            writeln!(&mut self.buffer, "norm={}", norm.to_python("cmap.N")).unwrap();
+        }
         if self.with_surface {
             let opt_surface = self.options_surface();
             write!(&mut self.buffer, "sf=AX3D.plot_surface(x,y,z{})\n", &opt_surface).unwrap();
@@ -119,6 +149,15 @@ impl Surface {
             let opt_wireframe = self.options_wireframe();
             write!(&mut self.buffer, "AX3D.plot_wireframe(x,y,z{})\n", &opt_wireframe).unwrap();
         }
+        for (zdir, offset, levels) in &self.project_contours {
+            let opt_projected = self.options_projected(levels);
+            writeln!(
+                &mut self.buffer,
+                "AX3D.contour(x,y,z,zdir='{}',offset={}{})",
+                zdir, offset, &opt_projected
+            )
+            .unwrap();
+        }
         if self.with_colorbar {
             let opt_colorbar = self.options_colorbar();
             write!(&mut self.buffer, "cb=plt.colorbar(sf{})\n", &opt_colorbar).unwrap();
@@ -167,6 +206,7 @@ impl Surface {
     pub fn set_colormap_index(&mut self, index: usize) -> &mut Self {
         self.colormap_index = index;
         self.colormap_name = String::new();
+        self.colormap = None;
         self
     }
 
@@ -184,6 +224,34 @@ impl Surface {
     /// * see more here <https://matplotlib.org/stable/tutorials/colors/colormaps.html>
     pub fn set_colormap_name(&mut self, name: &str) -> &mut Self {
         self.colormap_name = String::from(name);
+        self.colormap = None;
+        self
+    }
+
+    /// Sets a custom colormap (overrides the colormap index and name)
+    ///
+    /// See [Colormap]
+    pub fn set_colormap(&mut self, colormap: &Colormap) -> &mut Self {
+        self.colormap = Some(colormap.clone());
+        self
+    }
+
+    /// Sets the normalization of data values into the colormap
+    ///
+    /// See [Norm]
+    pub fn set_norm(&mut self, norm: &Norm) -> &mut Self {
+        self.norm = Some(norm.clone());
+        self
+    }
+
+    /// Sets the limits (vmin, vmax) of data values mapped into the colormap
+    ///
+    /// Use the same limits in several surfaces (e.g., in subplots) to share the color range.
+    /// See also [crate::Colorbar].
+    ///
+    /// **Note:** This option is ignored if a norm is set.
+    pub fn set_color_limits(&mut self, vmin: f64, vmax: f64) -> &mut Self {
+        self.color_limits = Some((vmin, vmax));
         self
     }
 
@@ -212,15 +280,15 @@ impl Surface {
     }
 
     /// Sets a solid color for the surface (disables colormap)
-    pub fn set_solid_color(&mut self, color: &str) -> &mut Self {
-        self.solid_color = String::from(color);
+    pub fn set_solid_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.solid_color = color_to_spec(color, &mut self.error);
         self.with_colormap = false;
         self
     }
 
     /// Sets the color of wireframe lines
-    pub fn set_line_color(&mut self, color: &str) -> &mut Self {
-        self.line_color = String::from(color);
+    pub fn set_line_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.line_color = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -228,9 +296,10 @@ impl Surface {
     ///
     /// Options:
     ///
-    /// * "`-`", "`:`", "`--`", "`-.`"
-    pub fn set_line_style(&mut self, style: &str) -> &mut Self {
-        self.line_style = String::from(style);
+    /// * [LineStyle::Solid], [LineStyle::Dotted], [LineStyle::Dashed], [LineStyle::DashDot]
+    /// * See [LineStyle] (including custom dashes and the default style)
+    pub fn set_line_style(&mut self, style: impl Into<LineStyle>) -> &mut Self {
+        self.line_style = style.into().to_option();
         self
     }
 
@@ -240,6 +309,60 @@ impl Surface {
         self
     }
 
+    /// Adds contour lines projected onto a wall of the 3D plot
+    ///
+    /// This function may be called more than once to project onto several walls.
+    ///
+    /// # Input
+    ///
+    /// * `axis` -- the axis perpendicular to the wall (0, 1, or 2)
+    /// * `offset` -- the position of the wall along `axis`; e.g., the minimum z value to project onto the floor
+    /// * `levels` -- the levels of the contour lines (automatically computed if empty)
+    ///
+    /// # Notes
+    ///
+    /// * The projected contours use the colormap of this surface, unless [Surface::set_project_contours_style] is called.
+    /// * The axes limits should include the offset; see [crate::Plot::set_range_3d]
+    pub fn set_project_contours(&mut self, axis: usize, offset: f64, levels: &[f64]) -> Result<&mut Self, StrError> {
+        let zdir = match axis {
+            0 => "x",
+            1 => "y",
+            2 => "z",
+            _ => return Err("axis must be 0, 1, or 2"),
+        };
+        self.project_contours.push((zdir, offset, levels.to_vec()));
+        Ok(self)
+    }
+
+    /// Sets the style of projected contours using the options of a Contour object
+    ///
+    /// The following options of the Contour are used: colors (or colormap), levels (if not given
+    /// to [Surface::set_project_contours]), line style, and line width.
+    pub fn set_project_contours_style(&mut self, contour: &Contour) -> &mut Self {
+        self.project_contours_levels = contour.get_levels().to_vec();
+        self.project_contours_options = contour.options_projected();
+        self
+    }
+
+    /// Returns true if the colormap must be written to the "cmap" variable (custom colormap or norm)
+    fn with_cmap_variable(&self) -> bool {
+        self.colormap.is_some() || self.norm.is_some()
+    }
+
+    /// Returns the Python expression of the colormap
+    fn colormap_expr(&self) -> String {
+        match &self.colormap {
+            Some(colormap) => colormap.to_python(),
+            None => {
+                if !self.colormap_name.is_empty() {
+                    format!("get_cmap('{}')", self.colormap_name)
+                } else {
+                    format!("get_colormap({})", self.colormap_index)
+                }
+            }
+        }
+    }
+
     /// Returns options for surface
     fn options_surface(&self) -> String {
         let mut opt = String::new();
@@ -253,10 +376,15 @@ impl Surface {
             write!(&mut opt, ",color='{}'", self.solid_color).unwrap();
         }
         if self.with_colormap {
-            if self.colormap_name != "" {
-                write!(&mut opt, ",cmap=plt.get_cmap('{}')", self.colormap_name).unwrap();
+            if self.with_cmap_variable() {
+                write!(&mut opt, ",cmap=cmap").unwrap();
             } else {
-                write!(&mut opt, ",cmap=get_colormap({})", self.colormap_index).unwrap();
+                write!(&mut opt, ",cmap={}", self.colormap_expr()).unwrap();
+            }
+            if self.norm.is_some() {
+                write!(&mut opt, ",norm=norm").unwrap();
+            } else if let Some((vmin, vmax)) = self.color_limits {
+                write!(&mut opt, ",vmin={},vmax={}", vmin, vmax).unwrap();
             }
         }
         opt
@@ -275,7 +403,7 @@ impl Surface {
             write!(&mut opt, ",color='{}'", self.line_color).unwrap();
         }
         if self.line_style != "" {
-            write!(&mut opt, ",linestyle='{}'", self.line_style).unwrap();
+            write!(&mut opt, ",linestyle={}", self.line_style).unwrap();
         }
         if self.line_width > 0.0 {
             write!(&mut opt, ",linewidth={}", self.line_width).unwrap();
@@ -283,6 +411,34 @@ impl Surface {
         opt
     }
 
+    /// Returns options for projected contours
+    fn options_projected(&self, levels: &[f64]) -> String {
+        let mut opt = String::new();
+        let levels = if !levels.is_empty() {
+            levels
+        } else {
+            &self.project_contours_levels
+        };
+        if !levels.is_empty() {
+            opt.push_str(",levels=[");
+            for level in levels {
+                write!(&mut opt, "{},", level).unwrap();
+            }
+            opt.push(']');
+        }
+        if !self.project_contours_options.is_empty() {
+            opt.push_str(&self.project_contours_options);
+        } else if self.with_cmap_variable() {
+            opt.push_str(",cmap=cmap");
+            if self.norm.is_some() {
+                opt.push_str(",norm=norm");
+            }
+        } else {
+            write!(&mut opt, ",cmap={}", self.colormap_expr()).unwrap();
+        }
+        opt
+    }
+
     /// Returns options for colorbar
     fn options_colorbar(&self) -> String {
         let mut opt = String::new();
@@ -338,6 +494,9 @@ impl GraphMaker for Surface {
     fn clear_buffer(&mut self) {
         self.buffer.clear();
     }
+    fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -345,7 +504,7 @@ impl GraphMaker for Surface {
 #[cfg(test)]
 mod tests {
     use super::{StrError, Surface};
-    use crate::GraphMaker;
+    use crate::{Colormap, Contour, GraphMaker, LineStyle, Norm};
     use russell_chk::assert_vec_approx_eq;
     use russell_lab::Matrix;
 
@@ -358,12 +517,18 @@ mod tests {
         assert_eq!(surface.with_wireframe, false);
         assert_eq!(surface.colormap_index, 0);
         assert_eq!(surface.colormap_name.len(), 0);
+        assert!(surface.colormap.is_none());
+        assert!(surface.norm.is_none());
+        assert_eq!(surface.color_limits, None);
         assert_eq!(surface.with_colorbar, false);
         assert_eq!(surface.colorbar_label.len(), 0);
         assert_eq!(surface.number_format_cb.len(), 0);
         assert_eq!(surface.line_color, "black".to_string());
         assert_eq!(surface.line_style.len(), 0);
         assert_eq!(surface.line_width, 0.0);
+        assert_eq!(surface.project_contours.len(), 0);
+        assert_eq!(surface.project_contours_levels.len(), 0);
+        assert_eq!(surface.project_contours_options.len(), 0);
         assert_eq!(surface.buffer.len(), 0);
     }
 
@@ -376,7 +541,7 @@ mod tests {
 
         surface.set_colormap_name("Pastel1");
         let opt = surface.options_surface();
-        assert_eq!(opt, ",rstride=3,cstride=4,cmap=plt.get_cmap('Pastel1')");
+        assert_eq!(opt, ",rstride=3,cstride=4,cmap=get_cmap('Pastel1')");
 
         surface.set_colormap_index(3);
         let opt = surface.options_surface();
@@ -384,7 +549,7 @@ mod tests {
 
         surface.set_colormap_name("turbo");
         let opt = surface.options_surface();
-        assert_eq!(opt, ",rstride=3,cstride=4,cmap=plt.get_cmap('turbo')");
+        assert_eq!(opt, ",rstride=3,cstride=4,cmap=get_cmap('turbo')");
 
         surface.set_with_colormap(false);
         let opt = surface.options_surface();
@@ -396,6 +561,31 @@ mod tests {
         assert_eq!(opt, ",rstride=3,cstride=4,color='blue'");
     }
 
+    #[test]
+    fn options_surface_with_color_limits_works() {
+        let mut surface = Surface::new();
+        surface.set_color_limits(0.0, 4.0);
+        assert_eq!(surface.options_surface(), ",cmap=get_colormap(0),vmin=0,vmax=4");
+        surface.set_norm(&Norm::Log(None, None));
+        assert_eq!(surface.options_surface(), ",cmap=cmap,norm=norm");
+    }
+
+    #[test]
+    fn options_surface_with_colormap_and_norm_works() {
+        let mut colormap = Colormap::from_name("coolwarm");
+        colormap.set_reversed(true);
+        let mut surface = Surface::new();
+        surface.set_colormap(&colormap);
+        assert_eq!(surface.colormap_expr(), "get_cmap('coolwarm').reversed()");
+        assert_eq!(surface.options_surface(), ",cmap=cmap");
+        assert_eq!(surface.options_projected(&[]), ",cmap=cmap");
+        surface.set_norm(&Norm::TwoSlope(0.0, None, None));
+        assert_eq!(surface.options_surface(), ",cmap=cmap,norm=norm");
+        assert_eq!(surface.options_projected(&[]), ",cmap=cmap,norm=norm");
+        surface.set_colormap_index(1);
+        assert_eq!(surface.colormap_expr(), "get_colormap(1)");
+    }
+
     #[test]
     fn options_wireframe_works() {
         let mut surface = Surface::new();
@@ -403,7 +593,7 @@ mod tests {
             .set_row_stride(3)
             .set_col_stride(4)
             .set_line_color("red")
-            .set_line_style("--")
+            .set_line_style(LineStyle::Dashed)
             .set_line_width(2.5);
         let opt = surface.options_wireframe();
         assert_eq!(opt, ",rstride=3,cstride=4,color='red',linestyle='--',linewidth=2.5");
@@ -417,6 +607,60 @@ mod tests {
         assert_eq!(opt, ",format='%.3f'");
     }
 
+    #[test]
+    fn options_projected_works() {
+        let mut surface = Surface::new();
+        surface.set_colormap_name("terrain");
+        let opt = surface.options_projected(&[]);
+        assert_eq!(opt, ",cmap=get_cmap('terrain')");
+        let opt = surface.options_projected(&[0.0, 0.5]);
+        assert_eq!(opt, ",levels=[0,0.5,],cmap=get_cmap('terrain')");
+
+        let mut contour = Contour::new();
+        contour
+            .set_colors(&["red", "green"])
+            .set_levels(&[1.0, 2.0])
+            .set_line_style(LineStyle::Dotted)
+            .set_line_width(2.0);
+        surface.set_project_contours_style(&contour);
+        let opt = surface.options_projected(&[]);
+        assert_eq!(
+            opt,
+            ",levels=[1,2,],colors=['red','green',],linestyles=[':'],linewidths=[2]"
+        );
+        let opt = surface.options_projected(&[0.5]);
+        assert_eq!(
+            opt,
+            ",levels=[0.5,],colors=['red','green',],linestyles=[':'],linewidths=[2]"
+        );
+    }
+
+    #[test]
+    fn draw_with_projected_contours_works() {
+        let mut surface = Surface::new();
+        surface.set_with_surface(false);
+        surface
+            .set_project_contours(2, -1.0, &[0.25])
+            .unwrap()
+            .set_project_contours(0, 1.0, &[])
+            .unwrap();
+        assert_eq!(
+            surface.set_project_contours(3, 0.0, &[]).err(),
+            Some("axis must be 0, 1, or 2")
+        );
+        let x = vec![vec![-0.5, 0.0, 0.5], vec![-0.5, 0.0, 0.5], vec![-0.5, 0.0, 0.5]];
+        let y = vec![vec![-0.5, -0.5, -0.5], vec![0.0, 0.0, 0.0], vec![0.5, 0.5, 0.5]];
+        let z = vec![vec![0.50, 0.25, 0.50], vec![0.25, 0.00, 0.25], vec![0.50, 0.25, 0.50]];
+        surface.draw(&x, &y, &z);
+        let b: &str = "x=np.array([[-0.5,0,0.5,],[-0.5,0,0.5,],[-0.5,0,0.5,],],dtype=float)\n\
+                       y=np.array([[-0.5,-0.5,-0.5,],[0,0,0,],[0.5,0.5,0.5,],],dtype=float)\n\
+                       z=np.array([[0.5,0.25,0.5,],[0.25,0,0.25,],[0.5,0.25,0.5,],],dtype=float)\n\
+                       maybe_create_ax3d()\n\
+                       AX3D.contour(x,y,z,zdir='z',offset=-1,levels=[0.25,],cmap=get_colormap(0))\n\
+                       AX3D.contour(x,y,z,zdir='x',offset=1,cmap=get_colormap(0))\n";
+        assert_eq!(surface.buffer, b);
+    }
+
     #[test]
     fn draw_works() {
         let mut surface = Surface::new();
@@ -441,6 +685,26 @@ mod tests {
         assert_eq!(surface.buffer, "");
     }
 
+    #[test]
+    fn draw_with_colormap_and_norm_works() {
+        let mut surface = Surface::new();
+        surface
+            .set_colormap(&Colormap::from_name("viridis"))
+            .set_norm(&Norm::Linear(Some(0.0), Some(1.0)));
+        let x = vec![vec![-0.5, 0.0, 0.5], vec![-0.5, 0.0, 0.5], vec![-0.5, 0.0, 0.5]];
+        let y = vec![vec![-0.5, -0.5, -0.5], vec![0.0, 0.0, 0.0], vec![0.5, 0.5, 0.5]];
+        let z = vec![vec![0.50, 0.25, 0.50], vec![0.25, 0.00, 0.25], vec![0.50, 0.25, 0.50]];
+        surface.draw(&x, &y, &z);
+        let b: &str = "x=np.array([[-0.5,0,0.5,],[-0.5,0,0.5,],[-0.5,0,0.5,],],dtype=float)\n\
+                       y=np.array([[-0.5,-0.5,-0.5,],[0,0,0,],[0.5,0.5,0.5,],],dtype=float)\n\
+                       z=np.array([[0.5,0.25,0.5,],[0.25,0,0.25,],[0.5,0.25,0.5,],],dtype=float)\n\
+                       maybe_create_ax3d()\n\
+                       cmap=get_cmap('viridis')\n\
+                       norm=mcl.Normalize(vmin=0,vmax=1)\n\
+                       sf=AX3D.plot_surface(x,y,z,cmap=cmap,norm=norm)\n";
+        assert_eq!(surface.buffer, b);
+    }
+
     #[test]
     fn draw_with_matrix_works() {
         let mut surface = Surface::new();
@@ -0,0 +1,145 @@
+use super::{check_environment, parse_version, StrError, GET_CMAP_AUTO, PYTHON_HEADER};
+
+/// Holds the versions of Matplotlib and NumPy targeted by the generated Python code
+///
+/// By default, [PYTHON_HEADER] selects the version-dependent code paths at runtime; hence,
+/// the generated script works with all supported versions. If the versions are given via
+/// [crate::Plot::set_target_versions], the header contains only the code paths for these versions.
+///
+/// The minimum supported versions are Matplotlib 3.3 and NumPy 1.17. The target versions are written
+/// in the first line of the header. Currently, only Matplotlib selects different code paths; regarding
+/// NumPy, the header defines `NaN` as `np.nan`, which is available in all supported versions (whereas
+/// `np.NaN` was removed in NumPy 2.0).
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Plot, StrError, TargetVersions};
+///
+/// fn main() -> Result<(), StrError> {
+///     // draw curve
+///     let mut curve = Curve::new();
+///     curve.draw(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0]);
+///
+///     // add curve to plot
+///     let mut plot = Plot::new();
+///     plot.set_target_versions(&TargetVersions::new("3.8.2", "1.26.0")?).add(&curve);
+///
+///     // write the script (e.g., to be run on another machine)
+///     plot.write_script("/tmp/plotpy/doc_tests/doc_target_versions.svg")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TargetVersions {
+    matplotlib: (u32, u32), // (major, minor) version of Matplotlib
+    numpy: (u32, u32),      // (major, minor) version of NumPy
+}
+
+impl TargetVersions {
+    /// Creates a new TargetVersions object from version strings (e.g., "3.8.2" and "1.26.0")
+    ///
+    /// Returns an error if a version cannot be parsed or is older than the minimum supported version.
+    pub fn new(matplotlib: &str, numpy: &str) -> Result<Self, StrError> {
+        let matplotlib = parse_version(matplotlib);
+        let numpy = parse_version(numpy);
+        if matplotlib == (0, 0) {
+            return Err("cannot parse the version of Matplotlib");
+        }
+        if numpy == (0, 0) {
+            return Err("cannot parse the version of NumPy");
+        }
+        if matplotlib < (3, 3) {
+            return Err("the version of Matplotlib must be at least 3.3");
+        }
+        if numpy < (1, 17) {
+            return Err("the version of NumPy must be at least 1.17");
+        }
+        Ok(TargetVersions { matplotlib, numpy })
+    }
+
+    /// Detects the versions installed in the local Python environment (see [check_environment])
+    pub fn detect() -> Result<Self, StrError> {
+        let env = check_environment()?;
+        if env.get_matplotlib_version().is_empty() || env.get_numpy_version().is_empty() {
+            return Err("cannot detect the versions because Matplotlib or NumPy is not installed");
+        }
+        TargetVersions::new(env.get_matplotlib_version(), env.get_numpy_version())
+    }
+
+    /// Returns the (major, minor) version of Matplotlib
+    pub fn get_matplotlib(&self) -> (u32, u32) {
+        self.matplotlib
+    }
+
+    /// Returns the (major, minor) version of NumPy
+    pub fn get_numpy(&self) -> (u32, u32) {
+        self.numpy
+    }
+
+    /// Returns the Python header with the code paths for the target versions
+    pub fn python_header(&self) -> String {
+        let get_cmap = if self.matplotlib >= (3, 5) {
+            "def get_cmap(name): return mpl.colormaps[name]\n"
+        } else {
+            "def get_cmap(name): return plt.get_cmap(name)\n"
+        };
+        let first_line = format!(
+            "### file generated by plotpy (targets: Matplotlib {}.{}, NumPy {}.{})\n",
+            self.matplotlib.0, self.matplotlib.1, self.numpy.0, self.numpy.1
+        );
+        PYTHON_HEADER
+            .replacen("### file generated by plotpy\n", &first_line, 1)
+            .replace(GET_CMAP_AUTO, get_cmap)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::TargetVersions;
+    use crate::{GET_CMAP_AUTO, PYTHON_HEADER};
+
+    #[test]
+    fn new_works() {
+        assert_eq!(
+            TargetVersions::new("x", "1.26").err(),
+            Some("cannot parse the version of Matplotlib")
+        );
+        assert_eq!(
+            TargetVersions::new("3.8", "").err(),
+            Some("cannot parse the version of NumPy")
+        );
+        assert_eq!(
+            TargetVersions::new("3.2.2", "1.26").err(),
+            Some("the version of Matplotlib must be at least 3.3")
+        );
+        assert_eq!(
+            TargetVersions::new("3.8", "1.16.6").err(),
+            Some("the version of NumPy must be at least 1.17")
+        );
+        let versions = TargetVersions::new("3.10.0", "2.1.1").unwrap();
+        assert_eq!(versions.get_matplotlib(), (3, 10));
+        assert_eq!(versions.get_numpy(), (2, 1));
+    }
+
+    #[test]
+    fn python_header_works() {
+        let old = TargetVersions::new("3.4.3", "1.21.0").unwrap().python_header();
+        assert!(old.starts_with("### file generated by plotpy (targets: Matplotlib 3.4, NumPy 1.21)\n"));
+        assert!(old.contains("NaN = np.nan\n"));
+        assert!(!old.contains(GET_CMAP_AUTO));
+        assert!(old.contains("def get_cmap(name): return plt.get_cmap(name)\n"));
+        let new = TargetVersions::new("3.9.0", "2.0.0").unwrap().python_header();
+        assert!(!new.contains(GET_CMAP_AUTO));
+        assert!(new.starts_with("### file generated by plotpy (targets: Matplotlib 3.9, NumPy 2.0)\n"));
+        assert!(new.contains("def get_cmap(name): return mpl.colormaps[name]\n"));
+        assert_eq!(
+            new.len(),
+            PYTHON_HEADER.len() - GET_CMAP_AUTO.len()
+                + "def get_cmap(name): return mpl.colormaps[name]\n".len()
+                + " (targets: Matplotlib 3.9, NumPy 2.0)".len()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::GraphMaker;
+use super::{color_to_spec, Color, CoordSystem, GraphMaker, HAlign, StrError, VAlign};
 use std::fmt::Write;
 
 /// Creates text to be added to a plot
@@ -6,15 +6,15 @@ use std::fmt::Write;
 /// # Example
 ///
 /// ```
-/// use plotpy::{Plot, Text, StrError};
+/// use plotpy::{HAlign, Plot, StrError, Text, VAlign};
 /// use std::path::Path;
 ///
 /// fn main() -> Result<(), StrError> {
 ///     // configure text
 ///     let mut text = Text::new();
 ///     text.set_color("purple")
-///         .set_align_horizontal("center")
-///         .set_align_vertical("center")
+///         .set_align_horizontal(HAlign::Center)
+///         .set_align_vertical(VAlign::Center)
 ///         .set_fontsize(30.0)
 ///         .set_rotation(45.0)
 ///         .set_bbox(true)
@@ -44,6 +44,7 @@ pub struct Text {
     align_vertical: String,   // Vertical alignment
     fontsize: f64,            // Font size
     rotation: f64,            // Text rotation
+    coords: CoordSystem,      // Coordinate system (2D only)
 
     // bounding box
     bbox: bool,             // Use bounding box
@@ -52,6 +53,9 @@ pub struct Text {
     bbox_alpha: f64,        // Alpha of bounding box
     bbox_style: String,     // Style of bounding box; example "round,pad=0.2"
 
+    // errors
+    error: Option<StrError>, // First error found in the options (e.g., an invalid color)
+
     // buffer
     buffer: String,
 }
@@ -65,18 +69,25 @@ impl Text {
             align_vertical: String::new(),
             fontsize: 0.0,
             rotation: 0.0,
+            coords: CoordSystem::Data,
             bbox: false,
             bbox_facecolor: String::new(),
             bbox_edgecolor: String::new(),
             bbox_alpha: 1.0,
             bbox_style: String::new(),
+            error: None,
             buffer: String::new(),
         }
     }
 
     /// Draws text
+    ///
+    /// The position is given in the coordinate system defined by [Text::set_coords].
     pub fn draw(&mut self, x: f64, y: f64, message: &str) {
-        let opt = self.options();
+        let mut opt = self.options();
+        if self.coords != CoordSystem::Data {
+            write!(&mut opt, ",transform={}", self.coords.transform()).unwrap();
+        }
         write!(&mut self.buffer, "t=plt.text({},{},'{}'{})\n", x, y, message, &opt).unwrap();
         if self.bbox {
             let opt_bbox = self.options_bbox();
@@ -101,24 +112,24 @@ impl Text {
     }
 
     /// Sets the text color
-    pub fn set_color(&mut self, color: &str) -> &mut Self {
-        self.color = String::from(color);
+    pub fn set_color(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.color = color_to_spec(color, &mut self.error);
         self
     }
 
     /// Sets the horizontal alignment
     ///
-    /// Options: "center", "left", "right"
-    pub fn set_align_horizontal(&mut self, option: &str) -> &mut Self {
-        self.align_horizontal = String::from(option);
+    /// Options: [HAlign::Center], [HAlign::Left], [HAlign::Right]
+    pub fn set_align_horizontal(&mut self, option: HAlign) -> &mut Self {
+        self.align_horizontal = option.to_string();
         self
     }
 
     /// Sets the vertical alignment
     ///
-    /// Options: "center", "top", "bottom", "baseline", "center_baseline"
-    pub fn set_align_vertical(&mut self, option: &str) -> &mut Self {
-        self.align_vertical = String::from(option);
+    /// Options: [VAlign::Center], [VAlign::Top], [VAlign::Bottom], [VAlign::Baseline], [VAlign::CenterBaseline]
+    pub fn set_align_vertical(&mut self, option: VAlign) -> &mut Self {
+        self.align_vertical = option.to_string();
         self
     }
 
@@ -134,6 +145,16 @@ impl Text {
         self
     }
 
+    /// Sets the coordinate system of the position (2D only)
+    ///
+    /// Options: [CoordSystem::Data], [CoordSystem::Axes], [CoordSystem::Figure], [CoordSystem::XDataYAxes], [CoordSystem::OffsetPoints]
+    ///
+    /// For instance, a panel letter like "(a)" may be placed at (0.02, 0.95) in axes coordinates.
+    pub fn set_coords(&mut self, coords: CoordSystem) -> &mut Self {
+        self.coords = coords;
+        self
+    }
+
     /// Sets use bounding box flag
     pub fn set_bbox(&mut self, flag: bool) -> &mut Self {
         self.bbox = flag;
@@ -141,14 +162,14 @@ impl Text {
     }
 
     /// Sets facecolor of bounding box
-    pub fn set_bbox_facecolor(&mut self, color: &str) -> &mut Self {
-        self.bbox_facecolor = String::from(color);
+    pub fn set_bbox_facecolor(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.bbox_facecolor = color_to_spec(color, &mut self.error);
         self
     }
 
     /// Sets edgecolor of bounding box
-    pub fn set_bbox_edgecolor(&mut self, color: &str) -> &mut Self {
-        self.bbox_edgecolor = String::from(color);
+    pub fn set_bbox_edgecolor(&mut self, color: impl Into<Color>) -> &mut Self {
+        self.bbox_edgecolor = color_to_spec(color, &mut self.error);
         self
     }
 
@@ -223,6 +244,9 @@ impl GraphMaker for Text {
     fn clear_buffer(&mut self) {
         self.buffer.clear();
     }
+    fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -230,7 +254,7 @@ impl GraphMaker for Text {
 #[cfg(test)]
 mod tests {
     use super::Text;
-    use crate::GraphMaker;
+    use crate::{CoordSystem, GraphMaker, HAlign, VAlign};
 
     #[test]
     fn new_works() {
@@ -240,6 +264,7 @@ mod tests {
         assert_eq!(text.align_vertical.len(), 0);
         assert_eq!(text.fontsize, 0.0);
         assert_eq!(text.rotation, 0.0);
+        assert_eq!(text.coords, CoordSystem::Data);
         assert_eq!(text.buffer.len(), 0);
     }
 
@@ -247,8 +272,8 @@ mod tests {
     fn options_works() {
         let mut text = Text::new();
         text.set_color("red")
-            .set_align_horizontal("center")
-            .set_align_vertical("center")
+            .set_align_horizontal(HAlign::Center)
+            .set_align_vertical(VAlign::Center)
             .set_fontsize(8.0)
             .set_rotation(45.0);
         let opt = text.options();
@@ -291,6 +316,16 @@ mod tests {
         assert_eq!(text.buffer, "");
     }
 
+    #[test]
+    fn draw_with_coords_works() {
+        let mut text = Text::new();
+        text.set_coords(CoordSystem::Axes).draw(0.02, 0.95, "(a)");
+        text.set_coords(CoordSystem::XDataYAxes).draw(1.5, 0.5, "band");
+        let b: &str = "t=plt.text(0.02,0.95,'(a)',transform=plt.gca().transAxes)\n\
+                       t=plt.text(1.5,0.5,'band',transform=plt.gca().get_xaxis_transform())\n";
+        assert_eq!(text.buffer, b);
+    }
+
     #[test]
     fn draw_3d_works() {
         let mut text = Text::new();
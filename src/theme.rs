@@ -0,0 +1,375 @@
+use super::{Color, LineStyle, Palette, StrError};
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Holds figure-wide style options (Matplotlib style sheets and rcParams)
+///
+/// A theme is applied by [crate::Plot::set_theme] before all other commands; thus, it affects
+/// every graph in the figure. The style sheets are applied first (in the given order), followed
+/// by the parameters, which override the style sheets.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Plot, StrError, Theme};
+///
+/// fn main() -> Result<(), StrError> {
+///     // theme
+///     let mut theme = Theme::journal_single_column();
+///     theme.set_font_family("serif").set_param_num("lines.linewidth", 1.5);
+///
+///     // draw curve
+///     let mut curve = Curve::new();
+///     curve.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+///
+///     // add curve to plot
+///     let mut plot = Plot::new();
+///     plot.set_theme(&theme).add(&curve).set_labels("x", "y");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_theme.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_theme.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_theme.svg)
+#[derive(Clone, Debug)]
+pub struct Theme {
+    style_sheets: Vec<String>,     // Names or paths of Matplotlib style sheets
+    params: Vec<(String, String)>, // rcParams (key, Python literal)
+    error: Option<StrError>,       // First error found in the options (e.g., an invalid color)
+}
+
+impl Theme {
+    /// Creates a new (empty) Theme
+    pub fn new() -> Self {
+        Theme {
+            style_sheets: Vec::new(),
+            params: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Returns a preset for figures spanning a single column of a journal page (3.5 inches wide)
+    pub fn journal_single_column() -> Self {
+        let mut theme = Theme::new();
+        theme
+            .set_figure_size_inches(3.5, 2.625)
+            .set_font_size(8.0)
+            .set_label_size(8.0)
+            .set_tick_label_size(7.0)
+            .set_legend_font_size(7.0)
+            .set_line_width(1.0)
+            .set_marker_size(4.0)
+            .set_axes_line_width(0.6)
+            .set_tick_direction("in")
+            .set_ticks_on_all_sides(true)
+            .set_grid(true)
+            .set_grid_style("#bfbfbf", LineStyle::Dotted, 0.5);
+        theme
+    }
+
+    /// Returns a preset for figures spanning two columns of a journal page (7 inches wide)
+    pub fn journal_double_column() -> Self {
+        let mut theme = Theme::journal_single_column();
+        theme
+            .set_figure_size_inches(7.0, 3.5)
+            .set_font_size(9.0)
+            .set_label_size(9.0)
+            .set_tick_label_size(8.0)
+            .set_legend_font_size(8.0);
+        theme
+    }
+
+    /// Returns a preset for slides (large fonts and thick lines)
+    pub fn presentation() -> Self {
+        let mut theme = Theme::new();
+        theme
+            .set_figure_size_inches(10.0, 6.25)
+            .set_font_size(18.0)
+            .set_label_size(20.0)
+            .set_tick_label_size(16.0)
+            .set_legend_font_size(16.0)
+            .set_line_width(2.5)
+            .set_marker_size(9.0)
+            .set_axes_line_width(1.5)
+            .set_grid(true)
+            .set_grid_style("#cccccc", LineStyle::Dashed, 1.0);
+        theme
+    }
+
+    /// Returns a preset with a dark background (based on the "dark_background" style sheet)
+    pub fn dark() -> Self {
+        let mut theme = Theme::new();
+        theme
+            .set_style_sheet("dark_background")
+            .set_grid(true)
+            .set_grid_style("#555555", LineStyle::Dashed, 0.6)
+            .set_param_str("savefig.facecolor", "black");
+        theme
+    }
+
+    /// Adds a Matplotlib style sheet given by name
+    ///
+    /// Examples: "ggplot", "seaborn-v0_8-paper", "dark_background"
+    ///
+    /// See [Matplotlib](https://matplotlib.org/stable/gallery/style_sheets/style_sheets_reference.html)
+    pub fn set_style_sheet(&mut self, name: &str) -> &mut Self {
+        self.style_sheets.push(String::from(name));
+        self
+    }
+
+    /// Adds a Matplotlib style sheet given by the path of a `.mplstyle` file
+    ///
+    /// Returns an error if the file does not exist.
+    pub fn set_style_sheet_file<S>(&mut self, path: &S) -> Result<&mut Self, StrError>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        let path = Path::new(path);
+        if !path.is_file() {
+            return Err("cannot find the style sheet file");
+        }
+        self.style_sheets.push(path.to_string_lossy().to_string());
+        Ok(self)
+    }
+
+    /// Sets an rcParams entry with a string value
+    ///
+    /// Example: `theme.set_param_str("axes.titleweight", "bold")`
+    ///
+    /// See [Matplotlib](https://matplotlib.org/stable/users/explain/customizing.html)
+    pub fn set_param_str(&mut self, key: &str, value: &str) -> &mut Self {
+        self.set_param(key, format!("'{}'", value))
+    }
+
+    /// Sets an rcParams entry with a numeric value
+    ///
+    /// Example: `theme.set_param_num("axes.titlesize", 12.0)`
+    pub fn set_param_num(&mut self, key: &str, value: f64) -> &mut Self {
+        self.set_param(key, format!("{}", value))
+    }
+
+    /// Sets an rcParams entry with a boolean value
+    ///
+    /// Example: `theme.set_param_bool("axes.spines.top", false)`
+    pub fn set_param_bool(&mut self, key: &str, value: bool) -> &mut Self {
+        self.set_param(key, if value { "True" } else { "False" }.to_string())
+    }
+
+    /// Sets the font family
+    ///
+    /// Examples: "serif", "sans-serif", "monospace", "DejaVu Sans"
+    pub fn set_font_family(&mut self, family: &str) -> &mut Self {
+        self.set_param_str("font.family", family)
+    }
+
+    /// Sets the default font size
+    pub fn set_font_size(&mut self, size: f64) -> &mut Self {
+        self.set_param_num("font.size", size)
+    }
+
+    /// Sets the font size of the axes labels
+    pub fn set_label_size(&mut self, size: f64) -> &mut Self {
+        self.set_param_num("axes.labelsize", size)
+    }
+
+    /// Sets the font size of the tick labels
+    pub fn set_tick_label_size(&mut self, size: f64) -> &mut Self {
+        self.set_param_num("xtick.labelsize", size)
+            .set_param_num("ytick.labelsize", size)
+    }
+
+    /// Sets the font size of legends
+    pub fn set_legend_font_size(&mut self, size: f64) -> &mut Self {
+        self.set_param_num("legend.fontsize", size)
+    }
+
+    /// Sets the default width of lines
+    pub fn set_line_width(&mut self, width: f64) -> &mut Self {
+        self.set_param_num("lines.linewidth", width)
+    }
+
+    /// Sets the default size of markers
+    pub fn set_marker_size(&mut self, size: f64) -> &mut Self {
+        self.set_param_num("lines.markersize", size)
+    }
+
+    /// Sets the width of the axes frame (spines) and ticks
+    pub fn set_axes_line_width(&mut self, width: f64) -> &mut Self {
+        self.set_param_num("axes.linewidth", width)
+            .set_param_num("xtick.major.width", width)
+            .set_param_num("ytick.major.width", width)
+    }
+
+    /// Sets the direction of ticks
+    ///
+    /// Options: "in", "out", "inout"
+    pub fn set_tick_direction(&mut self, direction: &str) -> &mut Self {
+        self.set_param_str("xtick.direction", direction)
+            .set_param_str("ytick.direction", direction)
+    }
+
+    /// Sets the option to draw ticks on the top and right sides too
+    pub fn set_ticks_on_all_sides(&mut self, flag: bool) -> &mut Self {
+        self.set_param_bool("xtick.top", flag)
+            .set_param_bool("ytick.right", flag)
+    }
+
+    /// Sets the option to draw a grid in all axes
+    pub fn set_grid(&mut self, flag: bool) -> &mut Self {
+        self.set_param_bool("axes.grid", flag)
+    }
+
+    /// Sets the color, style, and width of grid lines
+    pub fn set_grid_style(&mut self, color: impl Into<Color>, style: impl Into<LineStyle>, width: f64) -> &mut Self {
+        let color = color.into();
+        if self.error.is_none() {
+            self.error = color.get_error();
+        }
+        self.set_param("grid.color", color.to_python());
+        let style = style.into();
+        if style != LineStyle::Default {
+            self.set_param("grid.linestyle", style.to_python());
+        }
+        self.set_param_num("grid.linewidth", width)
+    }
+
+    /// Sets the property cycle (colors, line styles, and markers) of all axes
+    pub fn set_palette(&mut self, palette: &Palette) -> &mut Self {
+        self.set_param("axes.prop_cycle", palette.to_python())
+    }
+
+    /// Sets the figure size in inches
+    pub fn set_figure_size_inches(&mut self, width: f64, height: f64) -> &mut Self {
+        self.set_param("figure.figsize", format!("({},{})", width, height))
+    }
+
+    /// Returns the Python commands that apply this theme
+    pub(crate) fn to_python(&self) -> String {
+        let mut buffer = String::new();
+        if !self.style_sheets.is_empty() {
+            buffer.push_str("plt.style.use([");
+            for sheet in &self.style_sheets {
+                write!(&mut buffer, "r'{}',", sheet).unwrap();
+            }
+            buffer.push_str("])\n");
+        }
+        if !self.params.is_empty() {
+            buffer.push_str("plt.rcParams.update({");
+            for (key, value) in &self.params {
+                write!(&mut buffer, "'{}':{},", key, value).unwrap();
+            }
+            buffer.push_str("})\n");
+        }
+        buffer
+    }
+
+    /// Returns the first error found in the options (e.g., an invalid color)
+    pub(crate) fn get_error(&self) -> Option<StrError> {
+        self.error
+    }
+
+    /// Sets an rcParams entry (replacing a previous entry with the same key)
+    fn set_param(&mut self, key: &str, literal: String) -> &mut Self {
+        match self.params.iter_mut().find(|(k, _)| k == key) {
+            Some(entry) => entry.1 = literal,
+            None => self.params.push((String::from(key), literal)),
+        }
+        self
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Theme;
+    use crate::{LineStyle, Palette};
+
+    #[test]
+    fn new_works() {
+        let theme = Theme::new();
+        assert_eq!(theme.style_sheets.len(), 0);
+        assert_eq!(theme.params.len(), 0);
+        assert_eq!(theme.to_python(), "");
+    }
+
+    #[test]
+    fn set_param_functions_work() {
+        let mut theme = Theme::new();
+        theme
+            .set_param_str("font.family", "serif")
+            .set_param_num("font.size", 10.0)
+            .set_param_bool("axes.grid", true)
+            .set_param_num("font.size", 12.0);
+        assert_eq!(
+            theme.to_python(),
+            "plt.rcParams.update({'font.family':'serif','font.size':12,'axes.grid':True,})\n"
+        );
+    }
+
+    #[test]
+    fn set_style_sheet_works() {
+        let mut theme = Theme::new();
+        theme.set_style_sheet("ggplot").set_grid(false);
+        assert_eq!(
+            theme.to_python(),
+            "plt.style.use([r'ggplot',])\n\
+             plt.rcParams.update({'axes.grid':False,})\n"
+        );
+        assert_eq!(
+            theme.set_style_sheet_file("/tmp/not-found.mplstyle").err(),
+            Some("cannot find the style sheet file")
+        );
+        theme.set_style_sheet_file("Cargo.toml").unwrap();
+        assert_eq!(theme.style_sheets, &["ggplot", "Cargo.toml"]);
+    }
+
+    #[test]
+    fn typed_setters_work() {
+        let mut theme = Theme::new();
+        theme
+            .set_tick_direction("in")
+            .set_ticks_on_all_sides(true)
+            .set_grid_style("grey", LineStyle::Dashed, 0.5)
+            .set_figure_size_inches(3.5, 2.5)
+            .set_palette(&Palette::from_colors(&["red", "blue"]).unwrap());
+        assert_eq!(
+            theme.to_python(),
+            "plt.rcParams.update({\
+             'xtick.direction':'in',\
+             'ytick.direction':'in',\
+             'xtick.top':True,\
+             'ytick.right':True,\
+             'grid.color':'grey',\
+             'grid.linestyle':'--',\
+             'grid.linewidth':0.5,\
+             'figure.figsize':(3.5,2.5),\
+             'axes.prop_cycle':plt.cycler(color=['red','blue',]),\
+             })\n"
+        );
+    }
+
+    #[test]
+    fn presets_work() {
+        let single = Theme::journal_single_column().to_python();
+        assert!(single.contains("'figure.figsize':(3.5,2.625)"));
+        assert!(single.contains("'font.size':8,"));
+        let double = Theme::journal_double_column().to_python();
+        assert!(double.contains("'figure.figsize':(7,3.5)"));
+        assert!(double.contains("'font.size':9,"));
+        assert!(double.contains("'xtick.direction':'in'"));
+        let presentation = Theme::presentation().to_python();
+        assert!(presentation.contains("'lines.linewidth':2.5"));
+        let dark = Theme::dark().to_python();
+        assert!(dark.starts_with("plt.style.use([r'dark_background',])\n"));
+    }
+}
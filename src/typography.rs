@@ -0,0 +1,283 @@
+use super::{command_exists, StrError};
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Holds the typography options (LaTeX rendering and fonts)
+///
+/// A typography is applied by [crate::Plot::set_typography] after the theme (see [crate::Theme]) and
+/// before all other commands; thus, it affects every text in the figure.
+///
+/// When `usetex` is enabled, Matplotlib calls the local TeX installation to render all texts.
+/// In this case, [crate::Plot::save] (and [crate::Plot::render_to_bytes]) checks whether the `latex` command (and `dvipng` for PNG files,
+/// or `gs` for PS/EPS files) is available and returns an error otherwise.
+///
+/// # Example
+///
+/// ```
+/// use plotpy::{Curve, Plot, StrError, Typography};
+///
+/// fn main() -> Result<(), StrError> {
+///     // typography
+///     let mut typography = Typography::new();
+///     typography
+///         .set_font_family("serif")
+///         .set_font_names(&["DejaVu Serif"])
+///         .set_font_size(11.0)
+///         .set_math_font_set("dejavuserif");
+///
+///     // draw curve
+///     let mut curve = Curve::new();
+///     curve.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+///
+///     // add curve to plot
+///     let mut plot = Plot::new();
+///     plot.set_typography(&typography)
+///         .add(&curve)
+///         .set_labels("$x$", "$y = x^2$");
+///
+///     // save figure
+///     plot.save("/tmp/plotpy/doc_tests/doc_typography.svg")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ![doc_typography.svg](https://raw.githubusercontent.com/cpmech/plotpy/main/figures/doc_typography.svg)
+#[derive(Clone, Debug)]
+pub struct Typography {
+    usetex: bool,            // Use LaTeX to render all texts
+    preamble: Vec<String>,   // LaTeX preamble lines (e.g., \usepackage{amsmath})
+    font_family: String,     // Font family (e.g., "serif")
+    font_names: Vec<String>, // Preferred fonts of the family
+    font_size: f64,          // Default font size
+    math_font_set: String,   // Font set for mathtext (when not using LaTeX)
+    font_files: Vec<String>, // Font files to be registered
+}
+
+impl Typography {
+    /// Creates a new Typography object
+    pub fn new() -> Self {
+        Typography {
+            usetex: false,
+            preamble: Vec::new(),
+            font_family: String::new(),
+            font_names: Vec::new(),
+            font_size: 0.0,
+            math_font_set: String::new(),
+            font_files: Vec::new(),
+        }
+    }
+
+    /// Sets the option to render all texts with LaTeX (requires a local TeX installation)
+    pub fn set_usetex(&mut self, flag: bool) -> &mut Self {
+        self.usetex = flag;
+        self
+    }
+
+    /// Adds a line to the LaTeX preamble (only used with `usetex`)
+    ///
+    /// Example: `typography.set_preamble(r"\usepackage{siunitx}")`
+    pub fn set_preamble(&mut self, line: &str) -> &mut Self {
+        self.preamble.push(String::from(line));
+        self
+    }
+
+    /// Adds `\usepackage{...}` lines to the LaTeX preamble (only used with `usetex`)
+    ///
+    /// Example: `typography.set_latex_packages(&["amsmath", "siunitx"])`
+    pub fn set_latex_packages(&mut self, packages: &[&str]) -> &mut Self {
+        for package in packages {
+            self.preamble.push(format!("\\usepackage{{{}}}", package));
+        }
+        self
+    }
+
+    /// Sets the font family
+    ///
+    /// Options: "serif", "sans-serif", "monospace", "cursive", "fantasy", or the name of a font
+    pub fn set_font_family(&mut self, family: &str) -> &mut Self {
+        self.font_family = String::from(family);
+        self
+    }
+
+    /// Sets the preferred fonts of the generic family (in order of preference)
+    ///
+    /// Example: `typography.set_font_family("serif").set_font_names(&["Times New Roman", "Times"])`
+    ///
+    /// **Note:** The names are ignored if the font family is not a generic family.
+    pub fn set_font_names(&mut self, names: &[&str]) -> &mut Self {
+        self.font_names = names.iter().map(|n| n.to_string()).collect();
+        self
+    }
+
+    /// Sets the default font size
+    pub fn set_font_size(&mut self, size: f64) -> &mut Self {
+        self.font_size = size;
+        self
+    }
+
+    /// Sets the font set used by mathtext (when not using LaTeX)
+    ///
+    /// Options: "dejavusans", "dejavuserif", "cm", "stix", "stixsans", "custom"
+    pub fn set_math_font_set(&mut self, font_set: &str) -> &mut Self {
+        self.math_font_set = String::from(font_set);
+        self
+    }
+
+    /// Registers a font file (e.g., `.ttf` or `.otf`) such that its name can be used as font family
+    ///
+    /// Returns an error if the file does not exist.
+    pub fn set_font_file<S>(&mut self, path: &S) -> Result<&mut Self, StrError>
+    where
+        S: AsRef<OsStr> + ?Sized,
+    {
+        let path = Path::new(path);
+        if !path.is_file() {
+            return Err("cannot find the font file");
+        }
+        self.font_files.push(path.to_string_lossy().to_string());
+        Ok(self)
+    }
+
+    /// Checks whether the TeX tools required to save the figure in the given format (e.g., "png") are available
+    ///
+    /// Does nothing if `usetex` is false.
+    pub(crate) fn check_tex(&self, extension: &str) -> Result<(), StrError> {
+        if !self.usetex {
+            return Ok(());
+        }
+        if !command_exists("latex") {
+            return Err("usetex requires a TeX installation, but the latex command cannot be found");
+        }
+        let ext = extension.to_lowercase();
+        if ext == "png" && !command_exists("dvipng") {
+            return Err("usetex with PNG files requires dvipng, but the dvipng command cannot be found");
+        }
+        if (ext == "ps" || ext == "eps") && !command_exists("gs") {
+            return Err("usetex with PS files requires Ghostscript, but the gs command cannot be found");
+        }
+        Ok(())
+    }
+
+    /// Returns the Python commands that apply this typography
+    pub(crate) fn to_python(&self) -> String {
+        let mut buffer = String::new();
+        if !self.font_files.is_empty() {
+            buffer.push_str("import matplotlib.font_manager as fmg\n");
+            for file in &self.font_files {
+                writeln!(&mut buffer, "fmg.fontManager.addfont(r'{}')", file).unwrap();
+            }
+        }
+        let mut params = String::new();
+        if self.usetex {
+            params.push_str("'text.usetex':True,");
+            if !self.preamble.is_empty() {
+                write!(&mut params, "'text.latex.preamble':r'{}',", self.preamble.join("")).unwrap();
+            }
+        }
+        if !self.font_family.is_empty() {
+            write!(&mut params, "'font.family':'{}',", self.font_family).unwrap();
+            let generic = ["serif", "sans-serif", "monospace", "cursive", "fantasy"];
+            if !self.font_names.is_empty() && generic.contains(&self.font_family.as_str()) {
+                write!(&mut params, "'font.{}':[", self.font_family).unwrap();
+                for name in &self.font_names {
+                    write!(&mut params, "'{}',", name).unwrap();
+                }
+                params.push_str("],");
+            }
+        }
+        if self.font_size > 0.0 {
+            write!(&mut params, "'font.size':{},", self.font_size).unwrap();
+        }
+        if !self.math_font_set.is_empty() {
+            write!(&mut params, "'mathtext.fontset':'{}',", self.math_font_set).unwrap();
+        }
+        if !params.is_empty() {
+            writeln!(&mut buffer, "plt.rcParams.update({{{}}})", params).unwrap();
+        }
+        buffer
+    }
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Typography;
+
+    #[test]
+    fn new_works() {
+        let typography = Typography::new();
+        assert!(!typography.usetex);
+        assert_eq!(typography.preamble.len(), 0);
+        assert_eq!(typography.font_family.len(), 0);
+        assert_eq!(typography.font_names.len(), 0);
+        assert_eq!(typography.font_size, 0.0);
+        assert_eq!(typography.math_font_set.len(), 0);
+        assert_eq!(typography.font_files.len(), 0);
+        assert_eq!(typography.to_python(), "");
+    }
+
+    #[test]
+    fn to_python_works() {
+        let mut typography = Typography::new();
+        typography
+            .set_usetex(true)
+            .set_latex_packages(&["amsmath", "siunitx"])
+            .set_preamble(r"\newcommand{\bm}[1]{\boldsymbol{#1}}")
+            .set_font_family("serif")
+            .set_font_names(&["Times"])
+            .set_font_size(10.0)
+            .set_math_font_set("stix");
+        assert_eq!(
+            typography.to_python(),
+            "plt.rcParams.update({\
+             'text.usetex':True,\
+             'text.latex.preamble':r'\\usepackage{amsmath}\\usepackage{siunitx}\\newcommand{\\bm}[1]{\\boldsymbol{#1}}',\
+             'font.family':'serif',\
+             'font.serif':['Times',],\
+             'font.size':10,\
+             'mathtext.fontset':'stix',\
+             })\n"
+        );
+        typography.set_usetex(false).set_font_family("DejaVu Sans");
+        assert_eq!(
+            typography.to_python(),
+            "plt.rcParams.update({'font.family':'DejaVu Sans','font.size':10,'mathtext.fontset':'stix',})\n"
+        );
+    }
+
+    #[test]
+    fn set_font_file_works() {
+        let mut typography = Typography::new();
+        assert_eq!(
+            typography.set_font_file("/tmp/not-found.ttf").err(),
+            Some("cannot find the font file")
+        );
+        typography.set_font_file("Cargo.toml").unwrap();
+        assert_eq!(
+            typography.to_python(),
+            "import matplotlib.font_manager as fmg\n\
+             fmg.fontManager.addfont(r'Cargo.toml')\n"
+        );
+    }
+
+    #[test]
+    fn check_tex_works() {
+        let mut typography = Typography::new();
+        assert_eq!(typography.check_tex("png"), Ok(()));
+        typography.set_usetex(true);
+        if !super::command_exists("latex") {
+            assert_eq!(
+                typography.check_tex("svg").err(),
+                Some("usetex requires a TeX installation, but the latex command cannot be found")
+            );
+        }
+    }
+}
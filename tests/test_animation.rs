@@ -0,0 +1,54 @@
+use plotpy::{Animation, Canvas, Curve, StrError};
+use std::fs;
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_animation_gif() -> Result<(), StrError> {
+    // frames
+    let x: Vec<f64> = (0..21).map(|i| i as f64 / 20.0).collect();
+    let mut animation = Animation::new();
+    animation
+        .set_fps(5.0)
+        .set_loop(false)
+        .set_figure_size_inches(4.0, 3.0)
+        .add_frames(5, |step, plot| {
+            let y: Vec<f64> = x.iter().map(|x| x * (step + 1) as f64).collect();
+            let mut curve = Curve::new();
+            curve.draw(&x, &y);
+            plot.add(&curve).set_range(0.0, 1.0, 0.0, 5.0);
+            Ok(())
+        })?;
+
+    // save animated GIF
+    let path = Path::new(OUT_DIR).join("integ_animation.gif");
+    animation.save(&path)?;
+
+    // check file
+    let data = fs::read(&path).map_err(|_| "cannot read file")?;
+    assert_eq!(&data[0..3], b"GIF");
+    Ok(())
+}
+
+#[test]
+fn test_animation_png_frames() -> Result<(), StrError> {
+    // frames
+    let mut animation = Animation::new();
+    for i in 0..3 {
+        let mut canvas = Canvas::new();
+        canvas.draw_circle(0.0, 0.0, 0.5 * (i + 1) as f64);
+        animation.add_frame(&[&canvas]);
+    }
+
+    // save numbered PNG files
+    let path = Path::new(OUT_DIR).join("integ_animation_frames.png");
+    animation.set_dpi(50.0).save(&path)?;
+
+    // check files
+    for i in 0..3 {
+        let frame = Path::new(OUT_DIR).join(format!("integ_animation_frames_{:04}.png", i));
+        assert!(frame.exists());
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use plotpy::{Annotation, ArrowStyle, ConnectionStyle, CoordSystem, Curve, Plot, StrError};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_annotation() -> Result<(), StrError> {
+    // curve
+    let x: Vec<f64> = (0..41).map(|i| i as f64 / 10.0).collect();
+    let y: Vec<f64> = x.iter().map(|x| x * f64::exp(-x)).collect();
+    let mut curve = Curve::new();
+    curve.draw(&x, &y);
+
+    // annotations
+    let mut annotation = Annotation::new();
+    annotation
+        .set_text_coords(CoordSystem::OffsetPoints)
+        .set_arrow_style(ArrowStyle::CurveFilledB(0.6, 0.3))
+        .set_connection_style(ConnectionStyle::Arc3(0.3))
+        .set_bbox(true)
+        .set_bbox_facecolor("#f5f5f5")
+        .set_bbox_style("round,pad=0.3")
+        .draw(1.0, f64::exp(-1.0), 40.0, -30.0, "maximum");
+    annotation
+        .set_text_coords(CoordSystem::Axes)
+        .set_arrow_style(ArrowStyle::Fancy(0.4, 0.4, 0.4))
+        .set_arrow_color("#cd0000")
+        .set_connection_style(ConnectionStyle::Angle(90.0, 0.0, 5.0))
+        .set_bbox(false)
+        .draw(3.0, 3.0 * f64::exp(-3.0), 0.7, 0.8, "tail");
+    annotation
+        .set_xy_coords(CoordSystem::Axes)
+        .set_text_coords(CoordSystem::Axes)
+        .set_connection_style(ConnectionStyle::Arc(0.0, 180.0, 20.0, 20.0, 5.0))
+        .set_arrow_style(ArrowStyle::Curve)
+        .draw(0.05, 0.05, 0.2, 0.2, "origin");
+
+    // add to plot
+    let mut plot = Plot::new();
+    plot.add(&curve).add(&annotation);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_annotation.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 700);
+    Ok(())
+}
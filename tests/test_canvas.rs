@@ -1,4 +1,4 @@
-use plotpy::{Canvas, Curve, Plot, PolyCode, StrError};
+use plotpy::{ArrowStyle, Canvas, Curve, LineStyle, Marker, Plot, PolyCode, Polygon, StrError};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -15,7 +15,9 @@ fn test_canvas() -> Result<(), StrError> {
     canvas.draw_arc(0.5, 0.5, 0.4, 195.0, -15.0);
 
     // draw arrow
-    canvas.set_arrow_scale(50.0).set_arrow_style("fancy");
+    canvas
+        .set_arrow_scale(50.0)
+        .set_arrow_style(ArrowStyle::Fancy(0.4, 0.4, 0.4));
     canvas.draw_arrow(0.0, 0.0, 1.0, 1.0);
 
     // draw circle
@@ -220,7 +222,7 @@ fn test_canvas_polycurve_quadratic() -> Result<(), StrError> {
     let mut curve = Curve::new();
     curve
         .set_marker_color("red")
-        .set_marker_style("o")
+        .set_marker_style(Marker::Circle)
         .draw(&[0.75], &[0.25]);
 
     // add canvas to plot
@@ -270,7 +272,7 @@ fn test_canvas_polycurve_cubic() -> Result<(), StrError> {
     curve
         .set_line_color("green")
         .set_marker_color("red")
-        .set_marker_style("o")
+        .set_marker_style(Marker::Circle)
         .draw(x, y);
 
     // add canvas to plot
@@ -323,7 +325,7 @@ fn test_canvas_polycurve_methods() -> Result<(), StrError> {
     curve
         .set_line_color("orange")
         .set_marker_color("red")
-        .set_marker_style("o")
+        .set_marker_style(Marker::Circle)
         .draw(x, y);
 
     // add canvas to plot
@@ -343,3 +345,296 @@ fn test_canvas_polycurve_methods() -> Result<(), StrError> {
     assert!(lines_iter.count() > 355);
     Ok(())
 }
+
+#[test]
+fn test_canvas_shapes() -> Result<(), StrError> {
+    // canvas object and common options
+    let mut canvas = Canvas::new();
+    canvas.set_edge_color("#cd0000").set_face_color("#1862ab");
+
+    // draw rectangles
+    canvas.draw_rectangle(0.0, 0.0, 1.0, 0.5, 0.0, 0.0);
+    canvas.draw_rectangle(1.5, 0.0, 1.0, 0.5, 30.0, 0.1);
+
+    // draw ellipse, wedge, and annulus
+    canvas.set_face_color("None");
+    canvas.draw_ellipse(0.5, 1.5, 1.0, 0.5, 15.0);
+    canvas.draw_wedge(2.0, 1.5, 0.5, 30.0, 120.0);
+    canvas.draw_annulus(3.5, 1.5, 0.3, 0.5)?;
+
+    // draw regular polygon and fancy box
+    canvas.set_edge_color("blue");
+    canvas.draw_regular_polygon(0.5, 3.0, 0.5, 6, 0.0);
+    canvas.draw_fancy_box(1.5, 2.75, 1.0, 0.5, "round4,pad=0.1");
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_shapes.svg");
+    plot.set_range(-0.5, 4.5, -0.5, 4.0)
+        .set_equal_axes(true)
+        .set_show_errors(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 500);
+    Ok(())
+}
+
+#[test]
+fn test_canvas_shapes_3d() -> Result<(), StrError> {
+    // canvas object and common options
+    let mut canvas = Canvas::new();
+    canvas.set_edge_color("#cd0000").set_face_color("#1862ab");
+
+    // draw shapes on planes perpendicular to x, y, and z
+    canvas.draw_rectangle_3d(0.0, 0.0, 1.0, 1.0, 0, 0.0)?;
+    canvas.draw_ellipse_3d(0.5, 0.5, 1.0, 0.5, 1, 1.0)?;
+    canvas.draw_regular_polygon_3d(0.5, 0.5, 0.5, 5, 2, 0.0)?;
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_shapes_3d.svg");
+    plot.set_range_3d(0.0, 1.0, 0.0, 1.0, 0.0, 1.0).set_show_errors(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 700);
+    Ok(())
+}
+
+#[test]
+fn test_canvas_hatch_alpha_zorder() -> Result<(), StrError> {
+    // canvas object and common options
+    let mut canvas = Canvas::new();
+    canvas
+        .set_edge_color("black")
+        .set_face_color("white")
+        .set_line_style(LineStyle::Dashed)
+        .set_hatch("x")
+        .set_zorder(2.0);
+    canvas.draw_rectangle(0.0, 0.0, 1.0, 1.0, 0.0, 0.0);
+
+    // overlapping circle below the rectangle
+    canvas
+        .set_face_color("#1862ab")
+        .set_line_style(LineStyle::Solid)
+        .set_hatch("")
+        .set_alpha(0.5)
+        .set_zorder(1.0);
+    canvas.draw_circle(1.0, 1.0, 0.5);
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_hatch_alpha_zorder.svg");
+    plot.set_range(-0.5, 2.0, -0.5, 2.0)
+        .set_equal_axes(true)
+        .set_show_errors(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 450);
+    Ok(())
+}
+
+#[test]
+fn test_canvas_polygon_with_holes() -> Result<(), StrError> {
+    // circle points
+    let circle = |xc: f64, yc: f64, r: f64| -> Vec<Vec<f64>> {
+        (0..36)
+            .map(|i| {
+                let a = (i as f64) * std::f64::consts::PI / 18.0;
+                vec![xc + r * f64::cos(a), yc + r * f64::sin(a)]
+            })
+            .collect()
+    };
+
+    // plate with two circular holes
+    let mut canvas = Canvas::new();
+    canvas.set_edge_color("black").set_face_color("#eeea83");
+    let outer = vec![vec![0.0, 0.0], vec![4.0, 0.0], vec![4.0, 2.0], vec![0.0, 2.0]];
+    let holes = [circle(1.0, 1.0, 0.5), circle(3.0, 1.0, 0.5)];
+    canvas.draw_polygon_with_holes(&outer, &holes)?;
+
+    // boolean operations
+    let a = Polygon::new(&[[5.0, 0.0], [7.0, 0.0], [7.0, 2.0], [5.0, 2.0]])?;
+    let b = Polygon::new(&circle(7.0, 2.0, 1.0))?;
+    for (i, region) in [a.union(&b), a.intersection(&b), a.difference(&b)].iter().enumerate() {
+        let (mut points, codes) = region.get_polycurve();
+        for p in &mut points {
+            p[1] -= 4.0 * (i as f64);
+        }
+        canvas.set_face_color("#1862ab").set_alpha(0.5);
+        canvas.draw_polycurve(&points, &codes, true)?;
+    }
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_polygon_with_holes.svg");
+    plot.set_range(-0.5, 8.5, -9.0, 3.5)
+        .set_equal_axes(true)
+        .set_show_errors(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 450);
+    Ok(())
+}
+
+#[test]
+fn test_canvas_dimensions() -> Result<(), StrError> {
+    // triangle
+    let mut canvas = Canvas::new();
+    canvas.set_edge_color("black").set_face_color("#eeea83");
+    canvas.draw_polyline(&[[0.0, 0.0], [4.0, 0.0], [0.0, 3.0]], true);
+
+    // dimensions
+    canvas
+        .set_face_color("black")
+        .set_edge_color("#cd0000")
+        .set_arrow_scale(10.0);
+    canvas.draw_dimension(0.0, 0.0, 4.0, 0.0, -0.5, "4 m");
+    canvas.draw_dimension(0.0, 3.0, 0.0, 0.0, 0.5, "3 m");
+    canvas.draw_dimension(4.0, 0.0, 0.0, 3.0, 0.5, "5 m");
+    canvas.draw_dimension_angular(&[4.0, 0.0], &[[0.0, 3.0], [0.0, 0.0]], 1.2, "$\\alpha$");
+
+    // scale bar
+    canvas.set_edge_color("black");
+    let length = canvas.draw_scale_bar(2.5, 2.5, 1.7, "m")?;
+    assert_eq!(length, 1.0);
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_dimensions.svg");
+    plot.set_range(-1.5, 5.0, -1.5, 4.0)
+        .set_equal_axes(true)
+        .set_show_errors(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
+
+#[test]
+fn test_canvas_3d_features() -> Result<(), StrError> {
+    let mut canvas = Canvas::new();
+
+    // filled square on the z = 0 plane
+    canvas.set_face_color("#eeea83").set_edge_color("black").set_alpha(0.7);
+    canvas.draw_polygon_3d(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]])?;
+
+    // disk and circle on inclined planes
+    canvas.set_face_color("#1862ab").set_alpha(0.5);
+    canvas.draw_disk_3d(&[0.5, 0.5, 0.5], &[1.0, 1.0, 1.0], 0.3)?;
+    canvas.set_edge_color("#cd0000").set_line_style(LineStyle::Dashed);
+    canvas.draw_circle_3d(&[0.5, 0.5, 0.5], &[1.0, 0.0, 1.0], 0.4)?;
+
+    // arrows (e.g., loads)
+    canvas
+        .set_edge_color("#cd0000")
+        .set_line_style(LineStyle::Solid)
+        .set_alpha(0.0)
+        .set_line_width(2.0);
+    canvas.draw_arrow_3d(&[1.0, 1.0, 1.0], &[1.0, 1.0, 0.0])?;
+    canvas.set_arrow_head_3d(0.15, 0.05);
+    canvas.draw_arrow_3d(&[0.0, 0.0, 1.0], &[0.5, 0.5, 0.5])?;
+
+    // text
+    canvas.set_text_rotation(0.0).set_text_fontsize(12.0);
+    canvas.draw_text_3d(1.0, 1.0, 1.05, "P");
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_3d_features.svg");
+    plot.set_range_3d(0.0, 1.0, 0.0, 1.0, 0.0, 1.0)
+        .set_equal_axes(true)
+        .set_show_errors(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 900);
+    Ok(())
+}
+
+#[test]
+fn test_canvas_structural_symbols() -> Result<(), StrError> {
+    // beam
+    let mut canvas = Canvas::new();
+    canvas.set_edge_color("black").set_line_width(2.0);
+    canvas.draw_polyline(&[[0.0, 0.0], [4.0, 0.0], [4.0, 2.0]], false);
+
+    // supports and hinge
+    canvas.set_line_width(1.0).set_face_color("#eeea83");
+    canvas.draw_support_pinned(0.0, 0.0, 0.4, 0.0);
+    canvas.draw_support_roller(4.0, 0.0, 0.4, 0.0);
+    canvas.draw_support_fixed(4.0, 2.0, 0.4, 180.0);
+    canvas.draw_hinge(2.0, 0.0, 0.12);
+
+    // loads
+    canvas.set_edge_color("#cd0000").set_arrow_scale(10.0);
+    canvas.draw_point_load(1.0, 0.0, 0.8, -90.0);
+    canvas.draw_distributed_load(&[0.0, 0.0], &[2.0, 0.0], &[0.3, 0.3], 5)?;
+    canvas.draw_distributed_load(&[2.0, 0.0], &[4.0, 0.0], &[0.0, 0.6], 6)?;
+    canvas.draw_distributed_load(&[4.0, 0.5], &[4.0, 1.5], &[-0.2, -0.5], 4)?;
+    canvas.draw_moment(3.0, 0.0, 0.25, 90.0, false);
+    canvas.draw_moment(1.0, 1.0, 0.25, 0.0, true);
+
+    // spring
+    canvas.set_edge_color("#1862ab");
+    canvas.draw_spring(0.0, -1.2, 0.0, -0.6, 4, 0.15)?;
+    canvas.draw_support_fixed(0.0, -1.2, 0.4, 0.0);
+
+    // add canvas to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_canvas_structural_symbols.svg");
+    plot.set_range(-1.0, 5.0, -1.6, 2.4)
+        .set_equal_axes(true)
+        .set_show_errors(true);
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 700);
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use plotpy::{Colorbar, Colormap, Contour, GraphMaker, Plot, StrError, Surface};
+use russell_lab::generate3d;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_colorbar_shared() -> Result<(), StrError> {
+    // contour with shared limits
+    let colormap = Colormap::from_name("viridis");
+    let mut contour = Contour::new();
+    contour
+        .set_colormap(&colormap)
+        .set_color_limits(0.0, 8.0)
+        .set_no_colorbar(true);
+
+    // add contours to subplots
+    let mut plot = Plot::new();
+    let n = 9;
+    for i in 0..4 {
+        let a = (i + 1) as f64;
+        let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| a * (x * x + y * y) / 4.0);
+        contour.draw(&x, &y, &z);
+        plot.set_subplot(2, 2, i + 1).add(&contour);
+        contour.clear_buffer();
+    }
+
+    // colorbar shared by all subplots
+    let mut colorbar = Colorbar::new();
+    colorbar
+        .set_colormap(&colormap)
+        .set_limits(0.0, 8.0)
+        .set_extend("max")
+        .set_ticks(&[0.0, 2.0, 4.0, 6.0, 8.0])
+        .set_number_format("%.1f")
+        .set_label("temperature");
+    colorbar.draw();
+    plot.add(&colorbar);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_colorbar_shared.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 1000);
+    Ok(())
+}
+
+#[test]
+fn test_colorbar_horizontal() -> Result<(), StrError> {
+    // surface with limits
+    let colormap = Colormap::from_index(4);
+    let mut surface = Surface::new();
+    surface.set_colormap(&colormap).set_color_limits(-4.0, 4.0);
+
+    // draw surface
+    let n = 9;
+    let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x - y * y);
+    surface.draw(&x, &y, &z);
+
+    // horizontal colorbar below the axes
+    let mut colorbar = Colorbar::new();
+    colorbar
+        .set_colormap(&colormap)
+        .set_limits(-4.0, 4.0)
+        .set_axes(&[0])
+        .set_location("bottom")
+        .set_extend("both")
+        .set_shrink(0.8)
+        .set_pad(0.1)
+        .set_label("$z$");
+    colorbar.draw();
+
+    // add surface and colorbar to plot
+    let mut plot = Plot::new();
+    plot.add(&surface).add(&colorbar);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_colorbar_horizontal.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 1000);
+    Ok(())
+}
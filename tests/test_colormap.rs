@@ -0,0 +1,71 @@
+use plotpy::{Colormap, Contour, Norm, Plot, StrError, Surface};
+use russell_lab::generate3d;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_colormap_contour() -> Result<(), StrError> {
+    // colormap and norm
+    let mut colormap = Colormap::from_colors(&["#2166ac", "#f7f7f7", "#b2182b"])?;
+    colormap.set_bins(6);
+    let norm = Norm::TwoSlope(0.0, None, None);
+
+    // contour object and options
+    let mut contour = Contour::new();
+    contour.set_colormap(&colormap).set_norm(&norm);
+
+    // draw contour
+    let n = 9;
+    let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x - y * y);
+    contour.draw(&x, &y, &z);
+
+    // add contour to plot
+    let mut plot = Plot::new();
+    plot.add(&contour);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_colormap_contour.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 800);
+    Ok(())
+}
+
+#[test]
+fn test_colormap_surface() -> Result<(), StrError> {
+    // colormap and norm
+    let mut colormap = Colormap::from_name("terrain");
+    colormap.set_truncated(0.2, 0.8)?.set_reversed(true);
+    let norm = Norm::Linear(Some(0.0), Some(4.0));
+
+    // surface object and options
+    let mut surface = Surface::new();
+    surface.set_colormap(&colormap).set_norm(&norm);
+
+    // draw surface
+    let n = 9;
+    let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x + y * y);
+    surface.draw(&x, &y, &z);
+
+    // add surface to plot
+    let mut plot = Plot::new();
+    plot.add(&surface);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_colormap_surface.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 800);
+    Ok(())
+}
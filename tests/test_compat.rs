@@ -0,0 +1,94 @@
+use plotpy::{Contour, Curve, Plot, StrError, Surface, TargetVersions};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+// declared (matplotlib, numpy) versions
+const VERSIONS: [(&str, &str); 4] = [
+    ("3.3.4", "1.19.5"),
+    ("3.5.3", "1.21.6"),
+    ("3.7.5", "1.24.4"),
+    ("3.9.2", "2.0.2"),
+];
+
+/// Generates a fixture plot using colormaps (contour and surface) and NaN values (curve)
+fn fixture_plot() -> Plot {
+    let x = vec![vec![0.0, 1.0], vec![0.0, 1.0]];
+    let y = vec![vec![0.0, 0.0], vec![1.0, 1.0]];
+    let z = vec![vec![0.0, 1.0], vec![1.0, 2.0]];
+    let mut contour = Contour::new();
+    contour.set_colormap_name("terrain").draw(&x, &y, &z);
+    let mut curve = Curve::new();
+    curve.draw(&[0.0, 0.5, 1.0], &[0.0, f64::NAN, 1.0]);
+    let mut surface = Surface::new();
+    surface.set_colormap_index(3).draw(&x, &y, &z);
+    let mut plot = Plot::new();
+    plot.set_subplot(1, 2, 1)
+        .add(&contour)
+        .add(&curve)
+        .set_subplot(1, 2, 2)
+        .add(&surface);
+    plot
+}
+
+/// Checks the scripts generated for each declared version
+///
+/// This is a static API check: the text of each script is searched for APIs that were removed or deprecated
+/// in the declared versions (or are not yet available in them). The scripts are not run against the
+/// corresponding Matplotlib and NumPy installations; see `test_compat_detected_versions` instead.
+#[test]
+fn test_compat_declared_versions() -> Result<(), StrError> {
+    for (mpl, np) in VERSIONS {
+        let versions = TargetVersions::new(mpl, np)?;
+        let mut plot = fixture_plot();
+        plot.set_target_versions(&versions);
+
+        // write fixture script
+        let path = Path::new(OUT_DIR).join(format!("integ_compat_mpl{}_np{}.svg", mpl, np));
+        plot.write_script(&path)?;
+        let script = plot.get_script(&path);
+
+        // removed or deprecated APIs must not be used
+        assert!(!script.contains("np.NaN"), "np.NaN with numpy {}", np);
+        assert!(
+            !script.contains("plt.cm.get_cmap"),
+            "plt.cm.get_cmap with matplotlib {}",
+            mpl
+        );
+        assert!(!script.contains(".collections"), ".collections with matplotlib {}", mpl);
+        if versions.get_matplotlib() >= (3, 5) {
+            assert!(!script.contains("plt.get_cmap"), "plt.get_cmap with matplotlib {}", mpl);
+        } else {
+            assert!(
+                !script.contains("mpl.colormaps"),
+                "mpl.colormaps with matplotlib {}",
+                mpl
+            );
+        }
+        assert!(script.contains("get_cmap('terrain')"));
+    }
+    Ok(())
+}
+
+/// Runs the script generated for the versions installed in the local Python environment (if any)
+#[test]
+fn test_compat_detected_versions() -> Result<(), StrError> {
+    let versions = match TargetVersions::detect() {
+        Ok(v) => v,
+        Err(_) => return Ok(()), // python3, Matplotlib, or NumPy is not available
+    };
+    let mut plot = fixture_plot();
+    plot.set_target_versions(&versions);
+    let path = Path::new(OUT_DIR).join("integ_compat_detected.svg");
+    plot.save(&path)?;
+    assert!(path.exists());
+    Ok(())
+}
+
+#[test]
+fn test_compat_runtime_detection() {
+    // without target versions, the header must work with all versions
+    let script = fixture_plot().get_script("/tmp/plotpy/integ_tests/integ_compat_runtime.svg");
+    assert!(!script.contains("np.NaN"));
+    assert!(script.contains("if hasattr(mpl, 'colormaps'): return mpl.colormaps[name]"));
+}
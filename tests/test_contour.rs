@@ -1,4 +1,4 @@
-use plotpy::{Contour, Plot, StrError};
+use plotpy::{Contour, LineStyle, Plot, StrError};
 use russell_lab::generate3d;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
@@ -15,7 +15,7 @@ fn test_contour() -> Result<(), StrError> {
         .set_levels(&vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
         .set_colorbar_label("temperature")
         .set_line_color("yellow")
-        .set_line_style(":")
+        .set_line_style(LineStyle::Dotted)
         .set_line_width(2.5)
         .set_selected_line_color("#69e699")
         .set_selected_line_width(5.0)
@@ -144,3 +144,36 @@ fn test_contour_colormap_name() -> Result<(), StrError> {
     }
     Ok(())
 }
+
+#[test]
+fn test_contour_hatches() -> Result<(), StrError> {
+    // contour object and options
+    let mut contour = Contour::new();
+    contour
+        .set_colors(&["white", "#dddddd", "#bbbbbb"])
+        .set_levels(&[0.0, 2.0, 4.0, 8.0])
+        .set_hatches(&["", "//", "\\\\"])
+        .set_fill_alpha(0.9)
+        .set_zorder(2.0)
+        .set_no_colorbar(true);
+
+    // draw contour
+    let n = 9;
+    let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x + y * y);
+    contour.draw(&x, &y, &z);
+
+    // add contour to plot
+    let mut plot = Plot::new();
+    plot.add(&contour).set_show_errors(true);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_contour_hatches.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 800);
+    Ok(())
+}
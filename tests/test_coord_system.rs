@@ -0,0 +1,66 @@
+use plotpy::{Canvas, CoordSystem, Curve, Legend, LegendLocation, Plot, StrError, Text};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_coord_system() -> Result<(), StrError> {
+    // curve
+    let mut curve = Curve::new();
+    curve.set_label("data").draw(&[0.0, 10.0, 20.0], &[0.0, 100.0, 400.0]);
+
+    // panel letter and watermark
+    let mut text = Text::new();
+    text.set_coords(CoordSystem::Axes)
+        .set_fontsize(14.0)
+        .draw(0.02, 0.92, "(a)");
+    text.set_coords(CoordSystem::Figure)
+        .set_color("#cccccc")
+        .set_rotation(30.0)
+        .draw(0.4, 0.4, "DRAFT");
+    text.set_coords(CoordSystem::XDataYAxes)
+        .set_color("black")
+        .set_rotation(0.0)
+        .draw(12.0, 0.5, "band");
+
+    // vertical band, frame, and marker in points
+    let mut canvas = Canvas::new();
+    canvas
+        .set_coords(CoordSystem::XDataYAxes)
+        .set_face_color("#fde7e7")
+        .set_edge_color("none")
+        .draw_polyline(&[[10.0, 0.0], [14.0, 0.0], [14.0, 1.0], [10.0, 1.0]], true);
+    canvas
+        .set_coords(CoordSystem::Axes)
+        .set_face_color("none")
+        .set_edge_color("red")
+        .draw_polyline(&[[0.01, 0.01], [0.99, 0.01], [0.99, 0.99], [0.01, 0.99]], true);
+    canvas
+        .set_coords(CoordSystem::OffsetPoints)
+        .draw_circle(20.0, 20.0, 5.0);
+
+    // legend anchored in data coordinates
+    let mut legend = Legend::new();
+    legend
+        .set_location(LegendLocation::UpperLeft)
+        .set_anchor(2.0, 350.0)
+        .set_anchor_coords(CoordSystem::Data)
+        .draw();
+
+    // add to plot
+    let mut plot = Plot::new();
+    plot.add(&canvas).add(&curve).add(&text).add(&legend);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_coord_system.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 700);
+    Ok(())
+}
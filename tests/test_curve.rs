@@ -1,4 +1,4 @@
-use plotpy::{Curve, Plot, RayEndpoint, StrError};
+use plotpy::{Curve, LineStyle, Marker, Plot, RayEndpoint, StrError};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -12,7 +12,7 @@ fn test_curve() -> Result<(), StrError> {
     curve1
         .set_line_alpha(0.7)
         .set_line_color("#cd0000")
-        .set_line_style("--")
+        .set_line_style(LineStyle::Dashed)
         .set_line_width(2.0)
         .set_marker_color("#1862ab")
         .set_marker_every(2)
@@ -20,14 +20,14 @@ fn test_curve() -> Result<(), StrError> {
         .set_marker_line_color("#cda500")
         .set_marker_line_width(3.0)
         .set_marker_size(8.0)
-        .set_marker_style("p");
+        .set_marker_style(Marker::Pentagon);
 
     // another curve
     let mut curve2 = Curve::new();
     curve2
-        .set_line_style("None")
+        .set_line_style(LineStyle::None)
         .set_marker_line_color("#1862ab")
-        .set_marker_style("s")
+        .set_marker_style(Marker::Square)
         .set_marker_void(true);
 
     // draw curves
@@ -136,7 +136,7 @@ fn test_curve_3d() -> Result<(), StrError> {
     curve
         .set_line_alpha(0.7)
         .set_line_color("#cd0000")
-        .set_line_style("--")
+        .set_line_style(LineStyle::Dashed)
         .set_line_width(2.0)
         .set_marker_color("#1862ab")
         .set_marker_every(2)
@@ -144,7 +144,7 @@ fn test_curve_3d() -> Result<(), StrError> {
         .set_marker_line_color("#cda500")
         .set_marker_line_width(3.0)
         .set_marker_size(8.0)
-        .set_marker_style("p");
+        .set_marker_style(Marker::Pentagon);
 
     // draw curves
     let x = &[1.0, 2.0, 3.0, 4.0, 5.0];
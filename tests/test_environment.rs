@@ -0,0 +1,45 @@
+use plotpy::{check_environment, StrError};
+
+#[test]
+fn test_check_environment() -> Result<(), StrError> {
+    let env = check_environment()?;
+    assert!(env.get_python_version().starts_with("3."));
+    assert!(!env.get_python_path().is_empty());
+
+    // report
+    let report = format!("{}", env);
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines.len(), 9);
+    assert_eq!(lines[0], format!("python3 path       : {}", env.get_python_path()));
+    assert_eq!(lines[1], format!("python3 version    : {}", env.get_python_version()));
+    let numpy = match env.get_numpy_version() {
+        "" => "not installed",
+        version => version,
+    };
+    assert_eq!(lines[2], format!("numpy version      : {}", numpy));
+    let matplotlib = match env.get_matplotlib_version() {
+        "" => "not installed",
+        version => version,
+    };
+    assert_eq!(lines[3], format!("matplotlib version : {}", matplotlib));
+    assert_eq!(
+        lines[5],
+        format!("backends           : {}", env.get_backends().join(", "))
+    );
+    let labels = ["mplot3d", "pillow version", "latex", "dvipng"];
+    for (line, label) in [lines[4], lines[6], lines[7], lines[8]].iter().zip(labels) {
+        assert!(line.starts_with(&format!("{:19}: ", label)), "{}", line);
+    }
+
+    // warnings
+    let warnings = env.get_warnings();
+    assert_eq!(
+        env.get_numpy_version().is_empty(),
+        warnings.iter().any(|w| w.starts_with("NumPy is not installed"))
+    );
+    assert_eq!(
+        env.get_matplotlib_version().is_empty(),
+        warnings.iter().any(|w| w.starts_with("Matplotlib is not installed"))
+    );
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use plotpy::{Histogram, Plot, StrError};
+use plotpy::{Histogram, LineStyle, Plot, StrError};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -66,3 +66,35 @@ fn test_histogram_2() -> Result<(), StrError> {
     assert!(lines_iter.count() > 810);
     Ok(())
 }
+
+#[test]
+fn test_histogram_hatch() -> Result<(), StrError> {
+    let mut histogram = Histogram::new();
+    histogram
+        .set_colors(&["white"])
+        .set_edge_color("black")
+        .set_line_style(LineStyle::Dashed)
+        .set_hatch("//")
+        .set_alpha(0.8)
+        .set_zorder(3.0);
+
+    // draw histogram
+    let values = vec![vec![1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 4, 5, 6]];
+    let labels = ["first"];
+    histogram.draw(&values, &labels);
+
+    // add histogram to plot
+    let mut plot = Plot::new();
+    plot.add(&histogram).set_show_errors(true);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_histogram_hatch.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use plotpy::{Canvas, Contour, CoordSystem, Curve, Plot, StrError};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_inset() -> Result<(), StrError> {
+    // curve with a peak at x = 0
+    let x: Vec<f64> = (0..201).map(|i| -1.0 + 2.0 * i as f64 / 200.0).collect();
+    let y: Vec<f64> = x.iter().map(|x| f64::exp(-50.0 * x * x)).collect();
+    let mut curve = Curve::new();
+    curve.draw(&x, &y);
+
+    // canvas
+    let mut canvas = Canvas::new();
+    canvas.set_edge_color("red").draw_circle(0.0, 1.0, 0.02);
+
+    // add curve to plot and inset with bounds in axes coordinates
+    let mut plot = Plot::new();
+    plot.add(&curve);
+    plot.add_inset(0.6, 0.55, 0.35, 0.4, CoordSystem::Axes)?
+        .add(&curve)
+        .add(&canvas)
+        .set_range(-0.1, 0.1, 0.9, 1.05)
+        .set_hide_ticks()
+        .set_zoom_edge_color("gray")
+        .indicate_inset_zoom();
+    plot.set_range(-1.0, 1.0, -0.1, 1.6).set_equal_axes(false);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_inset.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 900);
+    Ok(())
+}
+
+#[test]
+fn test_inset_data_coords() -> Result<(), StrError> {
+    // contour
+    let n = 21;
+    let mut x = vec![vec![0.0; n]; n];
+    let mut y = vec![vec![0.0; n]; n];
+    let mut z = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            x[i][j] = -1.0 + 2.0 * j as f64 / (n - 1) as f64;
+            y[i][j] = -1.0 + 2.0 * i as f64 / (n - 1) as f64;
+            z[i][j] = x[i][j] * x[i][j] + y[i][j] * y[i][j];
+        }
+    }
+    let mut contour = Contour::new();
+    contour.set_no_colorbar(true).draw(&x, &y, &z);
+
+    // add contour to plot and inset with bounds in data coordinates
+    let mut plot = Plot::new();
+    plot.add(&contour).set_range(-1.0, 1.0, -1.0, 1.0);
+    plot.add_inset(1.1, 0.0, 0.8, 0.8, CoordSystem::Data)?
+        .add(&contour)
+        .set_range(-0.2, 0.2, -0.2, 0.2)
+        .set_title("center")
+        .indicate_inset_zoom();
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_inset_data_coords.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 1000);
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use plotpy::{Curve, Legend, Plot, StrError};
+use plotpy::{Curve, Legend, LegendLocation, Plot, StrError};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -72,7 +72,7 @@ fn test_legend_2() -> Result<(), StrError> {
 
     // legend and options
     let mut legend = Legend::new();
-    legend.set_location("lower right");
+    legend.set_location(LegendLocation::LowerRight);
 
     // draw legend
     legend.draw();
@@ -0,0 +1,62 @@
+use plotpy::{Contour, Curve, Histogram, Palette, Plot, StrError, Theme};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_palette() -> Result<(), StrError> {
+    // curves using the property cycle
+    let mut curve = Curve::new();
+    for i in 0..8 {
+        let y = i as f64;
+        curve.draw(&[0.0, 1.0, 2.0], &[y, y + 0.5, y]);
+    }
+
+    // histogram using colors from a palette
+    let palette = Palette::tol_bright();
+    let mut histogram = Histogram::new();
+    histogram.set_colors(&palette.get_cycled_colors(3)).draw(
+        &vec![vec![1, 2, 2, 3], vec![2, 3, 3, 4], vec![1, 4, 4, 4]],
+        &["a", "b", "c"],
+    );
+
+    // contour using colors from a palette
+    let x = vec![vec![-1.0, 0.0, 1.0], vec![-1.0, 0.0, 1.0], vec![-1.0, 0.0, 1.0]];
+    let y = vec![vec![-1.0, -1.0, -1.0], vec![0.0, 0.0, 0.0], vec![1.0, 1.0, 1.0]];
+    let z = vec![vec![2.0, 1.0, 2.0], vec![1.0, 0.0, 1.0], vec![2.0, 1.0, 2.0]];
+    let mut contour = Contour::new();
+    contour
+        .set_colors(Palette::viridis(5).get_colors())
+        .set_levels(&[0.0, 0.5, 1.0, 1.5, 2.0]);
+    contour.draw(&x, &y, &z);
+
+    // grayscale curves
+    let mut gray = Curve::new();
+    for i in 0..4 {
+        let y = i as f64;
+        gray.draw(&[0.0, 1.0, 2.0], &[y, y + 0.5, y]);
+    }
+
+    // add graphs to plot
+    let mut theme = Theme::new();
+    theme.set_palette(&Palette::okabe_ito());
+    let mut plot = Plot::new();
+    plot.set_theme(&theme).set_figure_size_points(600.0, 400.0);
+    plot.set_subplot(2, 2, 1).add(&curve);
+    plot.set_subplot(2, 2, 2).add(&histogram);
+    plot.set_subplot(2, 2, 3).add(&contour);
+    plot.set_subplot(2, 2, 4).set_palette(&Palette::grayscale()).add(&gray);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_palette.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 1000);
+    Ok(())
+}
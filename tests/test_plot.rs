@@ -1,4 +1,4 @@
-use plotpy::{Curve, Plot, StrError};
+use plotpy::{Curve, Plot, SaveOptions, StrError};
 use russell_lab::Vector;
 use std::f64::consts::PI;
 use std::fs::File;
@@ -72,6 +72,74 @@ fn test_plot_error() {
     assert_eq!(plot.save(&path).err(), Some("python3 failed; please see the log file"));
 }
 
+#[test]
+fn test_plot_render_to_bytes() -> Result<(), StrError> {
+    // curve
+    let mut curve = Curve::new();
+    curve.draw(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0]);
+
+    // add curve to plot
+    let mut plot = Plot::new();
+    plot.add(&curve).grid_and_labels("x", "y");
+
+    // png
+    let png = plot.render_to_bytes("png", 100.0)?;
+    assert_eq!(&png[0..8], b"\x89PNG\r\n\x1a\n");
+
+    // pdf
+    let pdf = plot.render_to_bytes("pdf", 0.0)?;
+    assert_eq!(&pdf[0..5], b"%PDF-");
+
+    // svg
+    let svg = plot.render_to_svg_string()?;
+    assert!(svg.contains("<svg"));
+    assert!(svg.lines().count() > 400);
+    Ok(())
+}
+
+#[test]
+fn test_plot_save_formats() -> Result<(), StrError> {
+    // curve
+    let mut curve = Curve::new();
+    curve.draw(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0]);
+
+    // save options
+    let mut options = SaveOptions::new();
+    options
+        .set_dpi(120.0)
+        .set_face_color("#f0f0f0")
+        .set_pad_inches(0.2)
+        .set_title("Save formats")
+        .set_author("Plotpy")
+        .set_date("2024-01-01T00:00:00")
+        .set_svg_fonts_as_text(true);
+
+    // add curve to plot
+    let mut plot = Plot::new();
+    plot.set_save_options(&options).add(&curve).grid_and_labels("x", "y");
+
+    // save figures
+    let path = Path::new(OUT_DIR).join("integ_plot_save_formats");
+    plot.save_formats(&path, &["png", "pdf", "svg"])?;
+
+    // check files
+    for ext in ["png", "pdf", "svg"] {
+        assert!(path.with_extension(ext).exists());
+    }
+    let svg = std::fs::read_to_string(path.with_extension("svg")).map_err(|_| "cannot read file")?;
+    assert!(svg.contains("<dc:title>Save formats</dc:title>"));
+    Ok(())
+}
+
+#[test]
+fn test_plot_render_to_bytes_error() {
+    let plot = Plot::new();
+    assert_eq!(
+        plot.render_to_bytes("xyz", 0.0).err(),
+        Some("python3 failed to render the figure")
+    );
+}
+
 #[test]
 fn test_plot_subplots() -> Result<(), StrError> {
     // curve object and options
@@ -208,3 +276,30 @@ fn test_plot_multiple_of_pi() -> Result<(), StrError> {
     assert!(lines_iter.count() > 1060);
     Ok(())
 }
+
+#[test]
+fn test_plot_deterministic() -> Result<(), StrError> {
+    // curve
+    let mut curve = Curve::new();
+    curve.draw(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0]);
+
+    // add curve to plot
+    let mut options = SaveOptions::new();
+    options.set_deterministic(true);
+    let mut plot = Plot::new();
+    plot.set_save_options(&options).add(&curve).grid_and_labels("x", "y");
+
+    // save figures twice
+    let first = Path::new(OUT_DIR).join("integ_plot_deterministic_1");
+    let second = Path::new(OUT_DIR).join("integ_plot_deterministic_2");
+    plot.save_formats(&first, &["svg", "pdf"])?;
+    plot.save_formats(&second, &["svg", "pdf"])?;
+
+    // check that the files are identical
+    for ext in ["svg", "pdf"] {
+        let a = std::fs::read(first.with_extension(ext)).map_err(|_| "cannot read file")?;
+        let b = std::fs::read(second.with_extension(ext)).map_err(|_| "cannot read file")?;
+        assert_eq!(a, b);
+    }
+    Ok(())
+}
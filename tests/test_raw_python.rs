@@ -0,0 +1,43 @@
+use plotpy::{Curve, Plot, RawPython, StrError};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_raw_python() -> Result<(), StrError> {
+    // curve
+    let mut curve = Curve::new();
+    curve.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+
+    // raw commands
+    let mut raw = RawPython::new();
+    raw.add_import("from matplotlib.patches import Ellipse")
+        .add_line("ax.add_patch(Ellipse((1.5, 4.5), 1.0, 2.0, color='#cd0000', alpha=0.5))")
+        .add_code(
+            "
+            for y in [2.0, 6.0]:
+                ax.axhline(y, color='gray', linestyle=':')
+            add_to_ea(ax.text(3.2, 9.0, 'outside'))
+            ",
+        );
+
+    // add curve and raw commands to plot
+    let mut plot = Plot::new();
+    plot.add(&curve)
+        .add(&raw)
+        .add_python_line("ax.set_facecolor('#f5f5f5')")
+        .set_labels("x", "y");
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_raw_python.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 450);
+    Ok(())
+}
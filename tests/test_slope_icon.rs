@@ -1,4 +1,4 @@
-use plotpy::{Curve, Plot, SlopeIcon, StrError};
+use plotpy::{Curve, LineStyle, Marker, Plot, SlopeIcon, StrError};
 use russell_lab::Vector;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
@@ -29,7 +29,7 @@ fn test_slope_icon_below() -> Result<(), StrError> {
     icon1
         .set_precision(3)
         .set_offset_v(1.0)
-        .set_line_style("--")
+        .set_line_style(LineStyle::Dashed)
         .set_face_color("gold")
         .set_length(0.25)
         .set_no_text(true);
@@ -231,8 +231,8 @@ fn test_slope_icon_logx_liny() -> Result<(), StrError> {
     // curves
     let mut curve1 = Curve::new();
     let mut curve2 = Curve::new();
-    curve1.set_marker_style("o");
-    curve2.set_marker_style("*");
+    curve1.set_marker_style(Marker::Circle);
+    curve2.set_marker_style(Marker::StarFilled);
     let x = Vector::linspace(x0, xmax, 5)?;
     let y1 = x.get_mapped(f1);
     let y2 = x.get_mapped(f2);
@@ -313,8 +313,8 @@ fn test_slope_icon_linx_logy() -> Result<(), StrError> {
     // curves
     let mut curve1 = Curve::new();
     let mut curve2 = Curve::new();
-    curve1.set_marker_style("o");
-    curve2.set_marker_style("*");
+    curve1.set_marker_style(Marker::Circle);
+    curve2.set_marker_style(Marker::StarFilled);
     let x = Vector::linspace(x0, xmax, 5)?;
     let y1 = x.get_mapped(f1);
     let y2 = x.get_mapped(f2);
@@ -395,8 +395,8 @@ fn test_slope_icon_logx_logy() -> Result<(), StrError> {
     // curves
     let mut curve1 = Curve::new();
     let mut curve2 = Curve::new();
-    curve1.set_marker_style("o");
-    curve2.set_marker_style("*");
+    curve1.set_marker_style(Marker::Circle);
+    curve2.set_marker_style(Marker::StarFilled);
     let x = Vector::linspace(x0, xmax, 5)?;
     let y1 = x.get_mapped(f1);
     let y2 = x.get_mapped(f2);
@@ -477,8 +477,8 @@ fn test_slope_icon_example() -> Result<(), StrError> {
     let x1 = Vector::linspace(x1i, x1f, 3)?;
     let y1a = x1.get_mapped(f1a);
     let y1b = x1.get_mapped(f1b);
-    curve1a.set_marker_style("o").draw(&x1, &y1a);
-    curve1b.set_marker_style("*").draw(&x1, &y1b);
+    curve1a.set_marker_style(Marker::Circle).draw(&x1, &y1a);
+    curve1b.set_marker_style(Marker::StarFilled).draw(&x1, &y1b);
 
     // icons
     let mut icon1a = SlopeIcon::new();
@@ -513,8 +513,8 @@ fn test_slope_icon_example() -> Result<(), StrError> {
     let x2 = Vector::linspace(x2i, x2f, 3)?;
     let y2a = x2.get_mapped(f2a);
     let y2b = x2.get_mapped(f2b);
-    curve2a.set_marker_style("o").draw(&x2, &y2a);
-    curve2b.set_marker_style("*").draw(&x2, &y2b);
+    curve2a.set_marker_style(Marker::Circle).draw(&x2, &y2a);
+    curve2b.set_marker_style(Marker::StarFilled).draw(&x2, &y2b);
 
     // icons
     let mut icon2a = SlopeIcon::new();
@@ -549,8 +549,8 @@ fn test_slope_icon_example() -> Result<(), StrError> {
     let x3 = Vector::linspace(x3i, x3f, 3)?;
     let y3a = x3.get_mapped(f3a);
     let y3b = x3.get_mapped(f3b);
-    curve3a.set_marker_style("o").draw(&x3, &y3a);
-    curve3b.set_marker_style("*").draw(&x3, &y3b);
+    curve3a.set_marker_style(Marker::Circle).draw(&x3, &y3a);
+    curve3b.set_marker_style(Marker::StarFilled).draw(&x3, &y3b);
 
     // icons
     let mut icon3a = SlopeIcon::new();
@@ -585,8 +585,8 @@ fn test_slope_icon_example() -> Result<(), StrError> {
     let x4 = Vector::linspace(x4i, x4f, 4)?;
     let y4a = x4.get_mapped(f4a);
     let y4b = x4.get_mapped(f4b);
-    curve4a.set_marker_style("o").draw(&x4, &y4a);
-    curve4b.set_marker_style("*").draw(&x4, &y4b);
+    curve4a.set_marker_style(Marker::Circle).draw(&x4, &y4a);
+    curve4b.set_marker_style(Marker::StarFilled).draw(&x4, &y4b);
 
     // icons
     let mut icon4a = SlopeIcon::new();
@@ -1,4 +1,4 @@
-use plotpy::{Plot, StrError, Surface};
+use plotpy::{Contour, LineStyle, Plot, StrError, Surface};
 use russell_lab::generate3d;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
@@ -18,7 +18,7 @@ fn test_surface() -> Result<(), StrError> {
         .set_colorbar_label("temperature")
         .set_number_format_cb("%.1f")
         .set_line_color("#1862ab")
-        .set_line_style(":")
+        .set_line_style(LineStyle::Dotted)
         .set_line_width(0.75);
 
     // draw surface
@@ -67,3 +67,39 @@ fn test_surface_wireframe() -> Result<(), StrError> {
     assert!(lines_iter.count() > 890);
     Ok(())
 }
+
+#[test]
+fn test_surface_project_contours() -> Result<(), StrError> {
+    let mut contour = Contour::new();
+    contour.set_colormap_name("terrain").set_line_width(1.5);
+
+    let mut surface = Surface::new();
+    surface
+        .set_colormap_name("terrain")
+        .set_with_wireframe(true)
+        .set_line_width(0.3)
+        .set_project_contours_style(&contour)
+        .set_project_contours(2, -1.0, &[0.5, 1.0, 2.0, 4.0, 6.0])?
+        .set_project_contours(0, -3.0, &[])?
+        .set_project_contours(1, 3.0, &[])?;
+
+    // draw surface
+    let n = 9;
+    let (x, y, z) = generate3d(-2.0, 2.0, -2.0, 2.0, n, n, |x, y| x * x + y * y);
+    surface.draw(&x, &y, &z);
+
+    // add surface to plot
+    let mut plot = Plot::new();
+    plot.add(&surface).set_range_3d(-3.0, 2.0, -2.0, 3.0, -1.0, 8.0);
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_surface_project_contours.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 1000);
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use plotpy::{Plot, StrError, Text};
+use plotpy::{HAlign, Plot, StrError, Text, VAlign};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -10,8 +10,8 @@ fn test_text() -> Result<(), StrError> {
     // text object and options
     let mut text = Text::new();
     text.set_color("blue")
-        .set_align_horizontal("center")
-        .set_align_vertical("center")
+        .set_align_horizontal(HAlign::Center)
+        .set_align_vertical(VAlign::Center)
         .set_fontsize(50.0)
         .set_rotation(45.0)
         .set_bbox(true)
@@ -52,8 +52,8 @@ fn test_text_3d() -> Result<(), StrError> {
     // text object and options
     let mut text = Text::new();
     text.set_color("blue")
-        .set_align_horizontal("center")
-        .set_align_vertical("center")
+        .set_align_horizontal(HAlign::Center)
+        .set_align_vertical(VAlign::Center)
         .set_fontsize(50.0)
         .set_rotation(45.0)
         .set_bbox(true)
@@ -0,0 +1,48 @@
+use plotpy::{Curve, Legend, LineStyle, Marker, Plot, StrError, Theme};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_theme_presets() -> Result<(), StrError> {
+    let presets = [
+        ("journal_single_column", Theme::journal_single_column()),
+        ("journal_double_column", Theme::journal_double_column()),
+        ("presentation", Theme::presentation()),
+        ("dark", Theme::dark()),
+    ];
+    for (name, theme) in presets {
+        // curves
+        let mut curve1 = Curve::new();
+        let mut curve2 = Curve::new();
+        curve1.set_label("linear").set_marker_style(Marker::Circle);
+        curve2.set_label("quadratic").set_line_style(LineStyle::Dashed);
+        curve1.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 2.0, 3.0]);
+        curve2.draw(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+
+        // legend
+        let mut legend = Legend::new();
+        legend.draw();
+
+        // add curves to plot
+        let mut plot = Plot::new();
+        plot.set_theme(&theme)
+            .add(&curve1)
+            .add(&curve2)
+            .add(&legend)
+            .set_labels("x", "y");
+
+        // save figure
+        let path = Path::new(OUT_DIR).join(format!("integ_theme_{}.svg", name));
+        plot.save(&path)?;
+
+        // check number of lines
+        let file = File::open(path).map_err(|_| "cannot open file")?;
+        let buffered = BufReader::new(file);
+        let lines_iter = buffered.lines();
+        assert!(lines_iter.count() > 400);
+    }
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use plotpy::{Curve, Plot, StrError, Typography};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+const OUT_DIR: &str = "/tmp/plotpy/integ_tests";
+
+#[test]
+fn test_typography() -> Result<(), StrError> {
+    // typography
+    let mut typography = Typography::new();
+    typography
+        .set_font_family("serif")
+        .set_font_names(&["DejaVu Serif"])
+        .set_font_size(12.0)
+        .set_math_font_set("cm");
+
+    // curve
+    let mut curve = Curve::new();
+    curve.set_label("$y = \\sqrt{x}$");
+    curve.draw(&[0.0, 1.0, 4.0, 9.0], &[0.0, 1.0, 2.0, 3.0]);
+
+    // add curve to plot
+    let mut plot = Plot::new();
+    plot.set_typography(&typography)
+        .add(&curve)
+        .set_title("$\\alpha + \\beta = \\gamma$")
+        .grid_labels_legend("$x$", "$y$");
+
+    // save figure
+    let path = Path::new(OUT_DIR).join("integ_typography.svg");
+    plot.save(&path)?;
+
+    // check number of lines
+    let file = File::open(path).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(file);
+    let lines_iter = buffered.lines();
+    assert!(lines_iter.count() > 600);
+    Ok(())
+}